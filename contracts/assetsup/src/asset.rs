@@ -8,6 +8,12 @@ pub enum DataKey {
     Asset(BytesN<32>),
     OwnerRegistry(Address),
     AssetCounter,
+    Depreciation(BytesN<32>),
+    Liens(BytesN<32>),
+    Children(BytesN<32>),
+    Parent(BytesN<32>),
+    LocationHistory(BytesN<32>),
+    Maintenance(BytesN<32>),
 }
 
 #[contracttype]
@@ -24,6 +30,8 @@ pub struct Asset {
     pub metadata_uri: String,
     pub purchase_value: i128,
     pub custom_attributes: Vec<CustomAttribute>,
+    /// Where the asset currently is; empty when untracked
+    pub location: String,
 }
 
 #[contracttype]
@@ -36,4 +44,51 @@ pub struct AssetInfo {
     pub status: AssetStatus,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DepreciationMethod {
+    /// Value falls by the same amount every second of the useful life
+    StraightLine,
+}
+
+/// How an asset's book value declines from its purchase value, measured from
+/// `registration_timestamp`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepreciationSchedule {
+    pub method: DepreciationMethod,
+    pub useful_life_secs: u64,
+    /// Book value never drops below this
+    pub salvage_value: i128,
+}
+
+/// A lender's claim on an asset held as collateral; blocks ownership transfers
+/// until released
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Lien {
+    pub lienholder: Address,
+    pub amount: i128,
+    pub placed_at: u64,
+}
+
+/// One recorded move of an asset
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LocationEntry {
+    pub location: String,
+    pub moved_by: Address,
+    pub moved_at: u64,
+}
+
+/// A servicing event recorded against an asset
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceRecord {
+    pub timestamp: u64,
+    pub performed_by: Address,
+    pub description: String,
+    pub cost: i128,
+}
+
 // Note: Contract methods implemented in lib.rs
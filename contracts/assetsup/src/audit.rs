@@ -1,9 +1,14 @@
-use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
+use crate::error::Error;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, String, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
-    AuditLog(BytesN<32>), // Key for asset-specific audit log
+    AuditEntry(BytesN<32>, u32), // One entry of an asset's audit log, by position
+    AuditCount(BytesN<32>),      // Number of entries in an asset's audit log
+    AuditHead(BytesN<32>),       // Hash of the latest entry in an asset's audit log
+    ActorAuditIndex(BytesN<32>, Address), // Positions of an actor's entries in an asset's log
 }
 
 #[contracttype]
@@ -13,6 +18,45 @@ pub struct AuditEntry {
     pub action: String,
     pub actor: Address,
     pub details: String,
+    /// Chain hash of the entry before this one (all zeroes for the first entry)
+    pub prev_hash: BytesN<32>,
+}
+
+/// Hash linking `entry` onto a chain whose head is `prev_hash`:
+/// sha256(prev_hash || xdr(entry))
+fn chain_hash(env: &Env, prev_hash: &BytesN<32>, entry: &AuditEntry) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &prev_hash.to_array());
+    data.append(&entry.clone().to_xdr(env));
+    env.crypto().sha256(&data).into()
+}
+
+/// Current head of an asset's audit chain
+fn chain_head(env: &Env, asset_id: &BytesN<32>) -> BytesN<32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AuditHead(asset_id.clone()))
+        .unwrap_or_else(|| BytesN::from_array(env, &[0u8; 32]))
+}
+
+/// Link `entry` onto the chain headed by `head`, store it at position `index` and
+/// index it under its actor, returning the new head
+fn push_linked(
+    env: &Env,
+    asset_id: &BytesN<32>,
+    index: u32,
+    head: BytesN<32>,
+    mut entry: AuditEntry,
+) -> BytesN<32> {
+    let store = env.storage().persistent();
+    let index_key = DataKey::ActorAuditIndex(asset_id.clone(), entry.actor.clone());
+    let mut actor_index: Vec<u32> = store.get(&index_key).unwrap_or_else(|| Vec::new(env));
+    actor_index.push_back(index);
+    store.set(&index_key, &actor_index);
+
+    entry.prev_hash = head.clone();
+    let next = chain_hash(env, &head, &entry);
+    store.set(&DataKey::AuditEntry(asset_id.clone(), index), &entry);
+    next
 }
 
 /// Internal function to append an audit log entry for an asset
@@ -24,30 +68,174 @@ pub(crate) fn append_audit_log(
     actor: Address,
     details: String,
 ) {
-    let key = DataKey::AuditLog(asset_id.clone());
-    let mut log: Vec<AuditEntry> = env
-        .storage()
-        .persistent()
-        .get(&key)
-        .unwrap_or_else(|| Vec::new(env));
-
+    let count = get_asset_log_count(env, asset_id);
     let entry = AuditEntry {
         timestamp: env.ledger().timestamp(),
         action,
         actor,
         details,
+        prev_hash: BytesN::from_array(env, &[0u8; 32]),
     };
 
-    log.push_back(entry);
-    env.storage().persistent().set(&key, &log);
+    let head = push_linked(env, asset_id, count, chain_head(env, asset_id), entry);
+    let store = env.storage().persistent();
+    store.set(&DataKey::AuditCount(asset_id.clone()), &(count + 1));
+    store.set(&DataKey::AuditHead(asset_id.clone()), &head);
+}
+
+/// Recompute an asset's audit hash chain from the first entry and check every
+/// link, including the stored head. Returns false if any entry was altered,
+/// removed or reordered after it was appended.
+pub fn verify_audit_chain(env: &Env, asset_id: &BytesN<32>) -> bool {
+    let mut head = BytesN::from_array(env, &[0u8; 32]);
+    for entry in get_asset_log(env, asset_id).iter() {
+        if entry.prev_hash != head {
+            return false;
+        }
+        head = chain_hash(env, &head, &entry);
+    }
+    head == chain_head(env, asset_id)
 }
 
 /// Public function to retrieve the audit log for an asset
-/// Returns an empty vector if no history exists
+/// Returns an empty vector if no history exists. Reads every entry; use
+/// `get_asset_log_paged` for long logs.
 pub fn get_asset_log(env: &Env, asset_id: &BytesN<32>) -> Vec<AuditEntry> {
-    let key = DataKey::AuditLog(asset_id.clone());
+    read_entries(env, asset_id, 0, get_asset_log_count(env, asset_id))
+}
+
+/// Read the stored entries at positions `[start, end)` of an asset's log
+fn read_entries(env: &Env, asset_id: &BytesN<32>, start: u32, end: u32) -> Vec<AuditEntry> {
+    let store = env.storage().persistent();
+    let mut entries = Vec::new(env);
+    for index in start..end {
+        if let Some(entry) = store.get(&DataKey::AuditEntry(asset_id.clone(), index)) {
+            entries.push_back(entry);
+        }
+    }
+    entries
+}
+
+/// Maximum number of entries returned by a single `get_asset_log_paged` call
+pub const MAX_AUDIT_PAGE: u32 = 50;
+
+/// Get entries `[start, start + limit)` of an asset's audit log (limit capped at
+/// `MAX_AUDIT_PAGE`); empty once `start` is past the end
+pub fn get_asset_log_paged(
+    env: &Env,
+    asset_id: &BytesN<32>,
+    start: u32,
+    limit: u32,
+) -> Vec<AuditEntry> {
+    let count = get_asset_log_count(env, asset_id);
+    if start >= count {
+        return Vec::new(env);
+    }
+    let end = start.saturating_add(limit.min(MAX_AUDIT_PAGE)).min(count);
+    read_entries(env, asset_id, start, end)
+}
+
+/// Retrieve every audit entry an actor recorded on an asset, in log order
+pub fn get_actor_audit_log(env: &Env, asset_id: &BytesN<32>, actor: &Address) -> Vec<AuditEntry> {
+    let store = env.storage().persistent();
+    let actor_index: Vec<u32> = store
+        .get(&DataKey::ActorAuditIndex(asset_id.clone(), actor.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+
+    let mut entries = Vec::new(env);
+    for index in actor_index.iter() {
+        if let Some(entry) = store.get(&DataKey::AuditEntry(asset_id.clone(), index)) {
+            entries.push_back(entry);
+        }
+    }
+    entries
+}
+
+/// Number of entries in an asset's audit log
+pub fn get_asset_log_count(env: &Env, asset_id: &BytesN<32>) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AuditCount(asset_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Retrieve the audit log entries for an asset that match an action and fall within
+/// an inclusive time range; each filter left as None matches every entry
+pub fn get_asset_log_filtered(
+    env: &Env,
+    asset_id: &BytesN<32>,
+    action: Option<String>,
+    from_ts: Option<u64>,
+    to_ts: Option<u64>,
+) -> Vec<AuditEntry> {
+    let mut filtered = Vec::new(env);
+    for entry in get_asset_log(env, asset_id).iter() {
+        if action
+            .as_ref()
+            .is_some_and(|action| *action != entry.action)
+            || from_ts.is_some_and(|from| entry.timestamp < from)
+            || to_ts.is_some_and(|to| entry.timestamp > to)
+        {
+            continue;
+        }
+        filtered.push_back(entry);
+    }
+    filtered
+}
+
+/// Retrieve a single audit entry by its position in the asset's log
+/// Returns None if the index is out of range
+pub fn get_audit_entry(env: &Env, asset_id: &BytesN<32>, index: u32) -> Option<AuditEntry> {
     env.storage()
         .persistent()
-        .get(&key)
-        .unwrap_or_else(|| Vec::new(env))
+        .get(&DataKey::AuditEntry(asset_id.clone(), index))
+}
+
+/// Append back-dated entries migrated from another system, preserving their timestamps.
+/// A sentinel AUDIT_IMPORT entry is written first so the provenance of the entries that
+/// follow it is clear. Imported entries must be in chronological order and not in the future;
+/// their `prev_hash` is ignored and rewritten as they are linked onto the chain.
+pub(crate) fn import_audit_entries(
+    env: &Env,
+    asset_id: &BytesN<32>,
+    entries: Vec<AuditEntry>,
+    admin: Address,
+) -> Result<(), Error> {
+    let now = env.ledger().timestamp();
+    let mut previous: u64 = 0;
+    for entry in entries.iter() {
+        if entry.timestamp < previous || entry.timestamp > now {
+            return Err(Error::InvalidTimestamps);
+        }
+        previous = entry.timestamp;
+    }
+
+    let mut count = get_asset_log_count(env, asset_id);
+    let mut head = push_linked(
+        env,
+        asset_id,
+        count,
+        chain_head(env, asset_id),
+        AuditEntry {
+            timestamp: now,
+            action: String::from_str(env, "AUDIT_IMPORT"),
+            actor: admin.clone(),
+            details: String::from_str(env, "Historical entries imported below"),
+            prev_hash: BytesN::from_array(env, &[0u8; 32]),
+        },
+    );
+    for entry in entries.iter() {
+        count += 1;
+        head = push_linked(env, asset_id, count, head, entry);
+    }
+    let store = env.storage().persistent();
+    store.set(&DataKey::AuditCount(asset_id.clone()), &(count + 1));
+    store.set(&DataKey::AuditHead(asset_id.clone()), &head);
+
+    env.events().publish(
+        ("audit", "entries_imported"),
+        (asset_id.clone(), entries.len(), admin),
+    );
+
+    Ok(())
 }
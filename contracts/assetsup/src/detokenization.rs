@@ -1,10 +1,13 @@
+use crate::audit;
 use crate::error::Error;
+use crate::escrow;
+use crate::tokenization;
 use crate::types::{
-    ActiveProposal, DetokenizationProposal, ExecutedProposal, RejectedProposal, TokenDataKey,
-    TokenizedAsset,
+    ActiveProposal, AssetStatus, DetokenizationProposal, ExecutedProposal, RejectedProposal,
+    TokenDataKey, TokenMetadata, TokenizedAsset,
 };
 use crate::voting;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, String};
 
 /// Propose detokenization (requires voting)
 pub fn propose_detokenization(env: &Env, asset_id: u64, proposer: Address) -> Result<u64, Error> {
@@ -36,11 +39,36 @@ pub fn propose_detokenization(env: &Env, asset_id: u64, proposer: Address) -> Re
 
     store.set(&proposal_key, &proposal);
 
+    // Votes are weighted by balances as of the proposal
+    voting::snapshot_proposal(env, asset_id, proposal_id)?;
+
     Ok(proposal_id)
 }
 
+/// Vote for or against the asset's active detokenization proposal. Approval
+/// needs supporting votes from holders of `detokenize_threshold` percent of the
+/// tokens in circulation.
+pub fn vote_detokenization(
+    env: &Env,
+    asset_id: u64,
+    voter: Address,
+    support: bool,
+) -> Result<(), Error> {
+    let proposal_id = match env
+        .storage()
+        .persistent()
+        .get::<_, DetokenizationProposal>(&TokenDataKey::DetokenizationProposal(asset_id))
+    {
+        Some(DetokenizationProposal::Active(ActiveProposal { proposal_id, .. })) => proposal_id,
+        _ => return Err(Error::ProposalNotFound),
+    };
+
+    voting::cast_vote(env, asset_id, proposal_id, voter, support)
+}
+
 /// Execute detokenization if vote passed
-/// This will remove all tokens from circulation and clear tokenization records
+/// This will remove all tokens from circulation and clear tokenization records.
+/// Refused while any of the asset's tokens sit in pending escrows.
 pub fn execute_detokenization(env: &Env, asset_id: u64, proposal_id: u64) -> Result<(), Error> {
     let store = env.storage().persistent();
 
@@ -55,7 +83,7 @@ pub fn execute_detokenization(env: &Env, asset_id: u64, proposal_id: u64) -> Res
             // Continue
         }
         _ => {
-            return Err(Error::InvalidProposal);
+            return Err(Error::ProposalNotFound);
         }
     }
 
@@ -65,6 +93,11 @@ pub fn execute_detokenization(env: &Env, asset_id: u64, proposal_id: u64) -> Res
         return Err(Error::DetokenizationNotApproved);
     }
 
+    // Tokens held in pending escrows must be claimed or cancelled first
+    if escrow::escrowed_amount(env, asset_id) > 0 {
+        return Err(Error::AssetEncumbered);
+    }
+
     // Save total supply for event before clearing
     let total_supply = tokenized_asset.total_supply;
 
@@ -89,12 +122,17 @@ pub fn execute_detokenization(env: &Env, asset_id: u64, proposal_id: u64) -> Res
         if store.has(&lock_key) {
             store.remove(&lock_key);
         }
+        store.remove(&TokenDataKey::SelfLock(asset_id, holder.clone()));
+        store.remove(&TokenDataKey::Vesting(asset_id, holder.clone()));
+        store.remove(&TokenDataKey::TransferLimit(asset_id, holder.clone()));
+        voting::clear_delegations(env, asset_id, &holder);
 
         // Remove unclaimed dividends
-        let dividend_key = TokenDataKey::UnclaimedDividend(asset_id, holder);
+        let dividend_key = TokenDataKey::UnclaimedDividend(asset_id, holder.clone());
         if store.has(&dividend_key) {
             store.remove(&dividend_key);
         }
+        store.remove(&TokenDataKey::EpochUnclaimed(asset_id, holder));
     }
 
     // Remove token holders list
@@ -108,11 +146,12 @@ pub fn execute_detokenization(env: &Env, asset_id: u64, proposal_id: u64) -> Res
         store.remove(&restriction_key);
     }
 
-    // Remove whitelist
+    // Remove whitelist and pending whitelist requests
     let whitelist_key = TokenDataKey::Whitelist(asset_id);
     if store.has(&whitelist_key) {
         store.remove(&whitelist_key);
     }
+    store.remove(&TokenDataKey::WhitelistRequests(asset_id));
 
     // Remove token metadata
     let metadata_key = TokenDataKey::TokenMetadata(asset_id);
@@ -120,10 +159,32 @@ pub fn execute_detokenization(env: &Env, asset_id: u64, proposal_id: u64) -> Res
         store.remove(&metadata_key);
     }
 
+    // Free the symbol for future tokenizations
+    tokenization::release_symbol(env, asset_id, tokenized_asset.symbol.clone());
+
     // Remove the tokenized asset record (this eliminates all tokens from circulation)
     if store.has(&key) {
         store.remove(&key);
     }
+    tokenization::adjust_total_value_locked(env, -tokenized_asset.valuation)?;
+
+    // Remember the issuer so only they can retokenize later
+    store.set(
+        &TokenDataKey::FormerTokenizer(asset_id),
+        &tokenized_asset.tokenizer,
+    );
+
+    // Start a new round so allowances granted in this one lapse
+    store.set(
+        &TokenDataKey::TokenizationRound(asset_id),
+        &(tokenization::tokenization_round(env, asset_id) + 1),
+    );
+
+    // Return the registry asset to the status it had before tokenization
+    let prior_status_key = TokenDataKey::PreTokenizationStatus(asset_id);
+    let prior_status: AssetStatus = store.get(&prior_status_key).unwrap_or(AssetStatus::Active);
+    tokenization::set_registry_status(env, asset_id, prior_status);
+    store.remove(&prior_status_key);
 
     // Update proposal to executed
     let timestamp = env.ledger().timestamp();
@@ -142,6 +203,102 @@ pub fn execute_detokenization(env: &Env, asset_id: u64, proposal_id: u64) -> Res
     Ok(())
 }
 
+/// Tokenize a previously detokenized asset again with fresh parameters (only the
+/// former tokenizer can call). Residual per-asset configuration from the earlier
+/// round is cleared; the audit log is kept and gains a `RETOKENIZED` entry. The new
+/// round has no supply cap.
+#[allow(clippy::too_many_arguments)]
+pub fn retokenize_asset(
+    env: &Env,
+    asset_id: u64,
+    symbol: String,
+    total_supply: i128,
+    decimals: u32,
+    min_voting_threshold: i128,
+    caller: Address,
+    metadata: TokenMetadata,
+) -> Result<TokenizedAsset, Error> {
+    let store = env.storage().persistent();
+
+    if store.has(&TokenDataKey::TokenizedAsset(asset_id)) {
+        return Err(Error::AssetAlreadyTokenized);
+    }
+    match store.get::<_, DetokenizationProposal>(&TokenDataKey::DetokenizationProposal(asset_id)) {
+        Some(DetokenizationProposal::Executed(_)) => {}
+        _ => return Err(Error::AssetNotTokenized),
+    }
+    let former_tokenizer: Address = store
+        .get(&TokenDataKey::FormerTokenizer(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+    if caller != former_tokenizer {
+        return Err(Error::Unauthorized);
+    }
+
+    clear_residual_state(env, asset_id);
+
+    let tokenized_asset = tokenization::tokenize_asset(
+        env,
+        asset_id,
+        symbol.clone(),
+        total_supply,
+        decimals,
+        min_voting_threshold,
+        caller.clone(),
+        metadata,
+        None,
+    )?;
+
+    audit::append_audit_log(
+        env,
+        &tokenization::asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "RETOKENIZED"),
+        caller.clone(),
+        String::from_str(env, "Detokenized asset tokenized again"),
+    );
+
+    // Emit event: (asset_id, supply, symbol, tokenizer)
+    env.events().publish(
+        ("detokenization", "asset_retokenized"),
+        (asset_id, total_supply, symbol, caller),
+    );
+
+    Ok(tokenized_asset)
+}
+
+/// Remove per-asset configuration left behind by an earlier tokenization round
+fn clear_residual_state(env: &Env, asset_id: u64) {
+    let store = env.storage().persistent();
+
+    let epoch_count: u32 = store
+        .get(&TokenDataKey::DividendEpochCount(asset_id))
+        .unwrap_or(0);
+    for epoch in 1..=epoch_count {
+        store.remove(&TokenDataKey::DividendEpoch(asset_id, epoch));
+    }
+
+    for key in [
+        TokenDataKey::DividendEpochCount(asset_id),
+        TokenDataKey::DividendDeclaration(asset_id),
+        TokenDataKey::MintRateLimit(asset_id),
+        TokenDataKey::Appraiser(asset_id),
+        TokenDataKey::LockAuthority(asset_id),
+        TokenDataKey::Treasury(asset_id),
+        TokenDataKey::ExitOnly(asset_id),
+        TokenDataKey::MinVotingBalance(asset_id),
+        TokenDataKey::ReflectionTax(asset_id),
+        TokenDataKey::ReflectionPool(asset_id),
+        TokenDataKey::BurnAddresses(asset_id),
+        TokenDataKey::BurnedTotal(asset_id),
+        TokenDataKey::MintingPaused(asset_id),
+        TokenDataKey::LockVoteMultiplier(asset_id),
+        TokenDataKey::DetokenizationProposal(asset_id),
+        TokenDataKey::FormerTokenizer(asset_id),
+    ] {
+        store.remove(&key);
+    }
+    store.remove(&escrow::DataKey::AssetEscrowed(asset_id));
+}
+
 /// Reject detokenization proposal
 #[allow(dead_code)]
 pub fn reject_detokenization(env: &Env, asset_id: u64) -> Result<(), Error> {
@@ -154,7 +311,7 @@ pub fn reject_detokenization(env: &Env, asset_id: u64) -> Result<(), Error> {
     // Get proposal
     let proposal_key = TokenDataKey::DetokenizationProposal(asset_id);
     let proposal: DetokenizationProposal =
-        store.get(&proposal_key).ok_or(Error::InvalidProposal)?;
+        store.get(&proposal_key).ok_or(Error::ProposalNotFound)?;
 
     match proposal {
         DetokenizationProposal::Active(ActiveProposal { proposal_id, .. }) => {
@@ -171,7 +328,7 @@ pub fn reject_detokenization(env: &Env, asset_id: u64) -> Result<(), Error> {
 
             Ok(())
         }
-        _ => Err(Error::InvalidProposal),
+        _ => Err(Error::ProposalNotFound),
     }
 }
 
@@ -183,7 +340,7 @@ pub fn get_detokenization_proposal(
     let store = env.storage().persistent();
 
     let key = TokenDataKey::DetokenizationProposal(asset_id);
-    store.get(&key).ok_or(Error::InvalidProposal)
+    store.get(&key).ok_or(Error::ProposalNotFound)
 }
 
 /// Check if detokenization is in progress
@@ -1,11 +1,23 @@
+use crate::audit;
 use crate::error::Error;
-use crate::types::{OwnershipRecord, TokenDataKey, TokenizedAsset};
-use soroban_sdk::{Address, Env, Vec};
+use crate::tokenization;
+use crate::types::{
+    DividendDeclaration, DividendEpoch, OwnershipRecord, TokenDataKey, TokenizedAsset,
+};
+use soroban_sdk::{Address, Env, Map, String, Vec};
 
-/// Distribute dividends proportionally to all token holders
-pub fn distribute_dividends(env: &Env, asset_id: u64, total_amount: i128) -> Result<(), Error> {
+/// Distribute dividends proportionally to all token holders (tokenizer only).
+/// Each holder is credited `total_amount * balance / eligible`, where `eligible`
+/// is the summed balance of every holder except burn addresses; the rounding
+/// dust left over is credited to the tokenizer.
+pub fn distribute_dividends(
+    env: &Env,
+    asset_id: u64,
+    total_amount: i128,
+    caller: Address,
+) -> Result<(), Error> {
     if total_amount <= 0 {
-        return Err(Error::InvalidDividendAmount);
+        return Err(Error::InvalidAmount);
     }
 
     let store = env.storage().persistent();
@@ -14,59 +26,134 @@ pub fn distribute_dividends(env: &Env, asset_id: u64, total_amount: i128) -> Res
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
 
+    if tokenized_asset.tokenizer != caller {
+        return Err(Error::Unauthorized);
+    }
+
     if !tokenized_asset.revenue_sharing_enabled {
-        return Err(Error::InvalidDividendAmount);
+        return Err(Error::RevenueSharingDisabled);
     }
 
-    // Get all token holders
+    if tokenized_asset.tokens_in_circulation <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    // Burn addresses still hold their burned balance but earn nothing
     let holders_key = TokenDataKey::TokenHoldersList(asset_id);
-    let holders: Vec<Address> = store.get(&holders_key).ok_or(Error::AssetNotTokenized)?;
+    let all_holders: Vec<Address> = store.get(&holders_key).ok_or(Error::AssetNotTokenized)?;
+    let mut holders: Vec<(Address, OwnershipRecord)> = Vec::new(env);
+    let mut eligible: i128 = 0;
+    for holder in all_holders.iter() {
+        if tokenization::is_burn_address(env, asset_id, &holder) {
+            continue;
+        }
+        let holder_key = TokenDataKey::TokenHolder(asset_id, holder.clone());
+        let ownership: OwnershipRecord = store.get(&holder_key).ok_or(Error::HolderNotFound)?;
+        eligible = eligible
+            .checked_add(ownership.balance)
+            .ok_or(Error::ArithmeticOverflow)?;
+        holders.push_back((holder, ownership));
+    }
+    if eligible <= 0 {
+        return Err(Error::InvalidAmount);
+    }
 
     // Distribute proportionally to each holder
-    for holder in holders.iter() {
+    let mut distributed: i128 = 0;
+    for (holder, mut ownership) in holders.iter() {
         let holder_key = TokenDataKey::TokenHolder(asset_id, holder.clone());
-        let mut ownership: OwnershipRecord = store.get(&holder_key).ok_or(Error::HolderNotFound)?;
 
-        // Calculate proportional dividend: (balance / total_supply) * total_amount
-        let proportion = (ownership.balance * total_amount) / tokenized_asset.total_supply;
+        // Calculate proportional dividend: (balance / eligible) * total_amount
+        let proportion = ownership
+            .balance
+            .checked_mul(total_amount)
+            .ok_or(Error::ArithmeticOverflow)?
+            / eligible;
 
         // Add to unclaimed dividends
-        ownership.unclaimed_dividends += proportion;
+        ownership.unclaimed_dividends = ownership
+            .unclaimed_dividends
+            .checked_add(proportion)
+            .ok_or(Error::ArithmeticOverflow)?;
+        distributed += proportion;
 
         store.set(&holder_key, &ownership);
     }
 
-    // Emit event: (asset_id, total_amount, holder_count)
+    // Rounding dust goes to the tokenizer, whose record is kept even after selling out
+    let dust = total_amount - distributed;
+    if dust > 0 {
+        let tokenizer_key = TokenDataKey::TokenHolder(asset_id, caller.clone());
+        let mut ownership: OwnershipRecord = store.get(&tokenizer_key).unwrap_or(OwnershipRecord {
+            owner: caller.clone(),
+            balance: 0,
+            acquisition_timestamp: env.ledger().timestamp(),
+            average_purchase_price: 0,
+            voting_power: 0,
+            dividend_entitlement: 0,
+            unclaimed_dividends: 0,
+            ownership_percentage: 0,
+        });
+        ownership.unclaimed_dividends = ownership
+            .unclaimed_dividends
+            .checked_add(dust)
+            .ok_or(Error::ArithmeticOverflow)?;
+        store.set(&tokenizer_key, &ownership);
+    }
+
+    // Emit event: (asset_id, total_amount, holder_count, dust)
     env.events().publish(
         ("dividend", "distributed"),
-        (asset_id, total_amount, holders.len()),
+        (asset_id, total_amount, holders.len(), dust),
     );
 
     Ok(())
 }
 
-/// Claim unclaimed dividends
-pub fn claim_dividends(env: &Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
+/// Checks shared by dividend claims and `preview_claim`; returns the holder's record.
+/// Claims wait while a declaration's freeze is in effect so they include it.
+fn check_claimable(env: &Env, asset_id: u64, holder: &Address) -> Result<OwnershipRecord, Error> {
     let store = env.storage().persistent();
 
     // Get tokenized asset
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let _: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
 
+    if is_distribution_frozen(env, asset_id) {
+        return Err(Error::DistributionInProgress);
+    }
+
     // Get holder's ownership record
     let holder_key = TokenDataKey::TokenHolder(asset_id, holder.clone());
-    let mut ownership: OwnershipRecord = store.get(&holder_key).ok_or(Error::HolderNotFound)?;
+    store.get(&holder_key).ok_or(Error::HolderNotFound)
+}
+
+/// Claim unclaimed dividends; returns the amount claimed, which is 0 when nothing
+/// has accrued
+pub fn claim_dividends(env: &Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
+    let store = env.storage().persistent();
+    let mut ownership = check_claimable(env, asset_id, &holder)?;
+    let holder_key = TokenDataKey::TokenHolder(asset_id, holder.clone());
 
     // Get unclaimed amount
     let unclaimed = ownership.unclaimed_dividends;
 
     if unclaimed <= 0 {
-        return Err(Error::NoDividendsToClaim);
+        return Ok(0);
     }
 
     // Clear unclaimed dividends
     ownership.unclaimed_dividends = 0;
     store.set(&holder_key, &ownership);
+    store.remove(&TokenDataKey::EpochUnclaimed(asset_id, holder.clone()));
+
+    audit::append_audit_log(
+        env,
+        &tokenization::asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "DIVIDENDS_CLAIMED"),
+        holder.clone(),
+        String::from_str(env, "Accrued dividends claimed"),
+    );
 
     // Emit event: (asset_id, holder, amount)
     env.events()
@@ -75,6 +162,65 @@ pub fn claim_dividends(env: &Env, asset_id: u64, holder: Address) -> Result<i128
     Ok(unclaimed)
 }
 
+/// Claim only the unclaimed dividends from a single epoch
+pub fn claim_epoch_dividends(
+    env: &Env,
+    asset_id: u64,
+    holder: Address,
+    epoch: u32,
+) -> Result<i128, Error> {
+    let store = env.storage().persistent();
+    let mut ownership = check_claimable(env, asset_id, &holder)?;
+
+    let unclaimed_key = TokenDataKey::EpochUnclaimed(asset_id, holder.clone());
+    let mut by_epoch: Map<u32, i128> =
+        store.get(&unclaimed_key).ok_or(Error::NoDividendsToClaim)?;
+    let amount = by_epoch.get(epoch).ok_or(Error::NoDividendsToClaim)?;
+
+    let holder_key = TokenDataKey::TokenHolder(asset_id, holder.clone());
+    ownership.unclaimed_dividends -= amount;
+    store.set(&holder_key, &ownership);
+
+    by_epoch.remove(epoch);
+    if by_epoch.is_empty() {
+        store.remove(&unclaimed_key);
+    } else {
+        store.set(&unclaimed_key, &by_epoch);
+    }
+
+    // Emit event: (asset_id, holder, epoch, amount)
+    env.events().publish(
+        ("dividend", "epoch_claimed"),
+        (asset_id, holder, epoch, amount),
+    );
+
+    Ok(amount)
+}
+
+/// Simulate `claim_dividends` without changing state: the amount it would pay, zero
+/// if there is nothing to claim, or the error that would block the claim
+pub fn preview_claim(env: &Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
+    let ownership = check_claimable(env, asset_id, &holder)?;
+    Ok(ownership.unclaimed_dividends.max(0))
+}
+
+/// Get a holder's unclaimed dividends per epoch, in epoch order. Amounts from
+/// `distribute_dividends` are not epoch-based and only appear in the aggregate
+/// `unclaimed_dividends`.
+pub fn get_unclaimed_by_epoch(env: &Env, asset_id: u64, holder: Address) -> Vec<(u32, i128)> {
+    let by_epoch: Map<u32, i128> = env
+        .storage()
+        .persistent()
+        .get(&TokenDataKey::EpochUnclaimed(asset_id, holder))
+        .unwrap_or_else(|| Map::new(env));
+
+    let mut breakdown = Vec::new(env);
+    for (epoch, amount) in by_epoch.iter() {
+        breakdown.push_back((epoch, amount));
+    }
+    breakdown
+}
+
 /// Get unclaimed dividends for a holder
 pub fn get_unclaimed_dividends(env: &Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
     let store = env.storage().persistent();
@@ -91,28 +237,315 @@ pub fn get_unclaimed_dividends(env: &Env, asset_id: u64, holder: Address) -> Res
     }
 }
 
-/// Enable revenue sharing for an asset
-pub fn enable_revenue_sharing(env: &Env, asset_id: u64) -> Result<(), Error> {
+/// Upper bound on the transfer freeze a dividend declaration may impose
+pub const MAX_DECLARATION_FREEZE_SECS: u64 = 24 * 60 * 60;
+
+/// Declare a dividend and freeze transfers for up to `freeze_secs` while it is in
+/// progress (only tokenizer can call). An alternative to snapshot epochs: the freeze
+/// stops holders moving tokens to a second address before the distribution runs.
+/// It lifts on its own once `freeze_secs` elapse, or when the declarer finalizes;
+/// a lapsed declaration counts as cleared and can no longer be finalized.
+pub fn declare_dividend(
+    env: &Env,
+    asset_id: u64,
+    total_amount: i128,
+    freeze_secs: u64,
+    declarer: Address,
+) -> Result<(), Error> {
+    if total_amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if freeze_secs == 0 || freeze_secs > MAX_DECLARATION_FREEZE_SECS {
+        return Err(Error::InvalidTimestamps);
+    }
+
     let store = env.storage().persistent();
 
     let key = TokenDataKey::TokenizedAsset(asset_id);
-    let mut tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
 
-    tokenized_asset.revenue_sharing_enabled = true;
-    store.set(&key, &tokenized_asset);
+    if tokenized_asset.tokenizer != declarer {
+        return Err(Error::Unauthorized);
+    }
+    if !tokenized_asset.revenue_sharing_enabled {
+        return Err(Error::RevenueSharingDisabled);
+    }
+
+    let declaration_key = TokenDataKey::DividendDeclaration(asset_id);
+    if is_distribution_frozen(env, asset_id) {
+        return Err(Error::DistributionInProgress);
+    }
+
+    let now = env.ledger().timestamp();
+    store.set(
+        &declaration_key,
+        &DividendDeclaration {
+            declarer: declarer.clone(),
+            total_amount,
+            declared_at: now,
+            freeze_until: now + freeze_secs,
+        },
+    );
+
+    env.events().publish(
+        ("dividend", "declared"),
+        (asset_id, total_amount, now + freeze_secs),
+    );
+
+    Ok(())
+}
+
+/// Distribute a declared dividend to current holders and lift the transfer freeze.
+/// Fails with `NoDividendsToClaim` once the freeze has lapsed, since transfers have
+/// resumed and current balances no longer match those at declaration.
+pub fn finalize_dividend(env: &Env, asset_id: u64, declarer: Address) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let declaration_key = TokenDataKey::DividendDeclaration(asset_id);
+    let declaration: DividendDeclaration = store
+        .get(&declaration_key)
+        .ok_or(Error::NoDividendsToClaim)?;
+    if env.ledger().timestamp() >= declaration.freeze_until {
+        return Err(Error::NoDividendsToClaim);
+    }
+
+    if declaration.declarer != declarer {
+        return Err(Error::Unauthorized);
+    }
+
+    store.remove(&declaration_key);
+    distribute_dividends(env, asset_id, declaration.total_amount, declarer)?;
+
+    env.events().publish(
+        ("dividend", "finalized"),
+        (asset_id, declaration.total_amount),
+    );
 
     Ok(())
 }
 
-/// Disable revenue sharing for an asset
-pub fn disable_revenue_sharing(env: &Env, asset_id: u64) -> Result<(), Error> {
+/// Get the pending dividend declaration for an asset, if any
+pub fn get_dividend_declaration(env: &Env, asset_id: u64) -> Option<DividendDeclaration> {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::DividendDeclaration(asset_id))
+}
+
+/// Returns true while a dividend declaration's transfer freeze is in effect
+pub fn is_distribution_frozen(env: &Env, asset_id: u64) -> bool {
+    match get_dividend_declaration(env, asset_id) {
+        Some(declaration) => env.ledger().timestamp() < declaration.freeze_until,
+        None => false,
+    }
+}
+
+/// Open a dividend epoch, snapshotting current holder balances (only tokenizer can call).
+/// Burn addresses are left out of the snapshot and its supply, so burned tokens
+/// earn nothing. Only one epoch may be open at a time; returns the new epoch number.
+pub fn open_dividend_epoch(env: &Env, asset_id: u64, caller: Address) -> Result<u32, Error> {
+    let store = env.storage().persistent();
+
+    let key = TokenDataKey::TokenizedAsset(asset_id);
+    let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+
+    if tokenized_asset.tokenizer != caller {
+        return Err(Error::Unauthorized);
+    }
+
+    let count_key = TokenDataKey::DividendEpochCount(asset_id);
+    let count: u32 = store.get(&count_key).unwrap_or(0);
+    if count > 0 {
+        let previous: DividendEpoch = store
+            .get(&TokenDataKey::DividendEpoch(asset_id, count))
+            .ok_or(Error::InvalidDividendEpoch)?;
+        if !previous.closed {
+            return Err(Error::InvalidDividendEpoch);
+        }
+    }
+
+    let holders: Vec<Address> = store
+        .get(&TokenDataKey::TokenHoldersList(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    let mut snapshot: Map<Address, i128> = Map::new(env);
+    let mut snapshot_supply: i128 = 0;
+    for holder in holders.iter() {
+        if tokenization::is_burn_address(env, asset_id, &holder) {
+            continue;
+        }
+        let holder_key = TokenDataKey::TokenHolder(asset_id, holder.clone());
+        if let Some(ownership) = store.get::<_, OwnershipRecord>(&holder_key) {
+            if ownership.balance > 0 {
+                snapshot.set(holder, ownership.balance);
+                snapshot_supply = snapshot_supply
+                    .checked_add(ownership.balance)
+                    .ok_or(Error::ArithmeticOverflow)?;
+            }
+        }
+    }
+
+    let epoch = count + 1;
+    store.set(
+        &TokenDataKey::DividendEpoch(asset_id, epoch),
+        &DividendEpoch {
+            epoch,
+            opened_at: env.ledger().timestamp(),
+            snapshot,
+            snapshot_supply,
+            funded: 0,
+            closed: false,
+        },
+    );
+    store.set(&count_key, &epoch);
+
+    env.events()
+        .publish(("dividend", "epoch_opened"), (asset_id, epoch));
+
+    Ok(epoch)
+}
+
+/// Add funds to an open dividend epoch (only tokenizer can call)
+pub fn fund_epoch(env: &Env, asset_id: u64, epoch: u32, amount: i128) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let store = env.storage().persistent();
+
+    let key = TokenDataKey::TokenizedAsset(asset_id);
+    let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    tokenized_asset.tokenizer.require_auth();
+
+    let epoch_key = TokenDataKey::DividendEpoch(asset_id, epoch);
+    let mut dividend_epoch: DividendEpoch =
+        store.get(&epoch_key).ok_or(Error::InvalidDividendEpoch)?;
+    if dividend_epoch.closed {
+        return Err(Error::InvalidDividendEpoch);
+    }
+
+    dividend_epoch.funded = dividend_epoch
+        .funded
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticOverflow)?;
+    store.set(&epoch_key, &dividend_epoch);
+
+    env.events()
+        .publish(("dividend", "epoch_funded"), (asset_id, epoch, amount));
+
+    Ok(())
+}
+
+/// Close a dividend epoch, crediting its funds pro-rata against the epoch's snapshot
+/// to each holder's unclaimed dividends (only tokenizer can call)
+pub fn close_dividend_epoch(env: &Env, asset_id: u64, epoch: u32) -> Result<(), Error> {
+    let store = env.storage().persistent();
+
+    let key = TokenDataKey::TokenizedAsset(asset_id);
+    let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    tokenized_asset.tokenizer.require_auth();
+
+    if !tokenized_asset.revenue_sharing_enabled {
+        return Err(Error::RevenueSharingDisabled);
+    }
+
+    let epoch_key = TokenDataKey::DividendEpoch(asset_id, epoch);
+    let mut dividend_epoch: DividendEpoch =
+        store.get(&epoch_key).ok_or(Error::InvalidDividendEpoch)?;
+    if dividend_epoch.closed {
+        return Err(Error::InvalidDividendEpoch);
+    }
+
+    for (holder, _) in dividend_epoch.snapshot.iter() {
+        let share = epoch_entitlement(&dividend_epoch, &holder)?;
+        if share == 0 {
+            continue;
+        }
+        // Holders who drained after the snapshot no longer have a record; give
+        // them a zero-balance one that carries the dividends until claimed
+        let holder_key = TokenDataKey::TokenHolder(asset_id, holder.clone());
+        let mut ownership = store
+            .get::<_, OwnershipRecord>(&holder_key)
+            .unwrap_or_else(|| OwnershipRecord {
+                owner: holder.clone(),
+                balance: 0,
+                acquisition_timestamp: env.ledger().timestamp(),
+                average_purchase_price: 1,
+                voting_power: 0,
+                dividend_entitlement: 0,
+                unclaimed_dividends: 0,
+                ownership_percentage: 0,
+            });
+        ownership.unclaimed_dividends = ownership
+            .unclaimed_dividends
+            .checked_add(share)
+            .ok_or(Error::ArithmeticOverflow)?;
+        store.set(&holder_key, &ownership);
+
+        let unclaimed_key = TokenDataKey::EpochUnclaimed(asset_id, holder);
+        let mut by_epoch: Map<u32, i128> =
+            store.get(&unclaimed_key).unwrap_or_else(|| Map::new(env));
+        by_epoch.set(epoch, share);
+        store.set(&unclaimed_key, &by_epoch);
+    }
+
+    dividend_epoch.closed = true;
+    store.set(&epoch_key, &dividend_epoch);
+
+    env.events().publish(
+        ("dividend", "epoch_closed"),
+        (asset_id, epoch, dividend_epoch.funded),
+    );
+
+    Ok(())
+}
+
+/// Get a dividend epoch
+pub fn get_dividend_epoch(env: &Env, asset_id: u64, epoch: u32) -> Result<DividendEpoch, Error> {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::DividendEpoch(asset_id, epoch))
+        .ok_or(Error::InvalidDividendEpoch)
+}
+
+/// Get a holder's share of a dividend epoch's funds
+pub fn get_epoch_entitlement(
+    env: &Env,
+    asset_id: u64,
+    epoch: u32,
+    holder: Address,
+) -> Result<i128, Error> {
+    let dividend_epoch = get_dividend_epoch(env, asset_id, epoch)?;
+    epoch_entitlement(&dividend_epoch, &holder)
+}
+
+fn epoch_entitlement(dividend_epoch: &DividendEpoch, holder: &Address) -> Result<i128, Error> {
+    if dividend_epoch.snapshot_supply == 0 {
+        return Ok(0);
+    }
+    let balance = dividend_epoch.snapshot.get(holder.clone()).unwrap_or(0);
+    Ok(balance
+        .checked_mul(dividend_epoch.funded)
+        .ok_or(Error::ArithmeticOverflow)?
+        / dividend_epoch.snapshot_supply)
+}
+
+/// Enable or disable revenue sharing for an asset (tokenizer only)
+pub fn set_revenue_sharing(
+    env: &Env,
+    asset_id: u64,
+    enabled: bool,
+    caller: Address,
+) -> Result<(), Error> {
+    tokenization::require_tokenizer(env, asset_id, &caller)?;
     let store = env.storage().persistent();
 
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let mut tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
 
-    tokenized_asset.revenue_sharing_enabled = false;
+    tokenized_asset.revenue_sharing_enabled = enabled;
     store.set(&key, &tokenized_asset);
 
+    env.events()
+        .publish(("dividend", "revenue_sharing_toggled"), (asset_id, enabled));
+
     Ok(())
 }
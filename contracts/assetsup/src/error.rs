@@ -5,35 +5,36 @@ use soroban_sdk::{contracterror, panic_with_error, Env};
 #[repr(u32)]
 pub enum Error {
     AlreadyInitialized = 1,
-    AdminNotFound = 2,
     AssetAlreadyExists = 3,
     AssetNotFound = 4,
-    BranchAlreadyExists = 5,
-    BranchNotFound = 6,
-    SubscriptionAlreadyExists = 7,
     Unauthorized = 8,
-    InvalidPayment = 9,
     // Tokenization errors
     AssetAlreadyTokenized = 10,
     AssetNotTokenized = 11,
-    InvalidTokenSupply = 12,
-    InvalidTokenDecimals = 13,
     InsufficientBalance = 14,
-    InsufficientLockedTokens = 15,
     TokensAreLocked = 16,
+    ExitOnlyMode = 52,
+    SymbolTaken = 56,
+    InsufficientAllowance = 61,
+    // Transfer restriction errors
     TransferRestrictionFailed = 17,
-    NotWhitelisted = 18,
-    AccreditedInvestorRequired = 19,
-    GeographicRestriction = 20,
+    WhitelistRequestNotFound = 59,
+    AddressBlacklisted = 66,
+    HolderFrozen = 74,
+    // Minting, holder-count and transfer caps
+    LimitExceeded = 48,
+    MintingPaused = 60,
     // Voting errors
     InsufficientVotingPower = 21,
     AlreadyVoted = 22,
     ProposalNotFound = 23,
-    InvalidProposal = 24,
     VotingPeriodEnded = 25,
+    VotesDelegated = 65,
     // Dividend errors
     NoDividendsToClaim = 26,
-    InvalidDividendAmount = 27,
+    InvalidDividendEpoch = 50,
+    DistributionInProgress = 53,
+    RevenueSharingDisabled = 64,
     // Detokenization errors
     DetokenizationNotApproved = 28,
     DetokenizationAlreadyProposed = 29,
@@ -41,25 +42,32 @@ pub enum Error {
     InvalidValuation = 30,
     // Holder enumeration errors
     HolderNotFound = 31,
-    // Math errors
-    MathOverflow = 32,
-    MathUnderflow = 33,
+    // Math errors; checked arithmetic in every module reports ArithmeticOverflow
+    ArithmeticOverflow = 32,
     // Contract state errors
     ContractPaused = 34,
     ContractNotInitialized = 35,
+    Reentrancy = 55,
     // Validation errors
     InvalidAssetName = 36,
     InvalidPurchaseValue = 37,
     InvalidMetadataUri = 38,
     InvalidOwnerAddress = 39,
-
+    InvalidAmount = 51,
+    InvalidTimestamps = 46,
+    // Lifecycle errors, shared by assets, leases and claims
+    InvalidStatusTransition = 69,
+    AssetEncumbered = 70,
+    InvalidHierarchy = 71,
+    PeriodNotElapsed = 45,
+    // Lease errors
     LeaseNotFound = 40,
     LeaseAlreadyExists = 41,
-    AssetAlreadyLeased = 42,
-    InvalidLeaseStatus = 43,
-    LeaseAlreadyStarted = 44,
-    LeaseNotExpired = 45,
-    InvalidTimestamps = 46,
+    // Escrow errors
+    EscrowNotFound = 54,
+    // Insurance errors
+    PerilExcluded = 47,
+    CoverageExceedsValuation = 63,
 }
 
 pub fn handle_error(env: &Env, error: Error) -> ! {
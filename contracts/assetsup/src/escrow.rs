@@ -0,0 +1,226 @@
+use crate::audit;
+use crate::dividends;
+use crate::error::Error;
+use crate::tokenization;
+use crate::transfer_restrictions;
+use crate::types::{TokenDataKey, TokenizedAsset};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Escrow(u64),
+    EscrowCount,
+    /// Tokens of an asset currently held in pending escrows
+    AssetEscrowed(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EscrowStatus {
+    Pending,
+    Released,
+    Cancelled,
+}
+
+/// Tokens held by the contract on behalf of a sender until released to recipients
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Escrow {
+    pub escrow_id: u64,
+    pub asset_id: u64,
+    pub sender: Address,
+    /// Total tokens escrowed; always equals the sum of recipient amounts
+    pub amount: i128,
+    pub recipients: Vec<(Address, i128)>,
+    /// Timestamp from which the escrow can be released
+    pub release_after: u64,
+    pub status: EscrowStatus,
+}
+
+/// Move `amount` of the sender's tokens into escrow for the given recipients.
+/// Recipient amounts must be positive and sum to `amount`; each recipient must
+/// pass the asset's transfer restrictions.
+pub fn escrow_transfer(
+    env: &Env,
+    asset_id: u64,
+    sender: Address,
+    amount: i128,
+    recipients: Vec<(Address, i128)>,
+    release_after: u64,
+) -> Result<u64, Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let mut total: i128 = 0;
+    for (recipient, share) in recipients.iter() {
+        if share <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        total = total.checked_add(share).ok_or(Error::ArithmeticOverflow)?;
+        transfer_restrictions::validate_transfer(env, asset_id, sender.clone(), recipient.clone())?;
+        tokenization::check_exit_only(env, asset_id, &recipient)?;
+    }
+    if total != amount {
+        return Err(Error::InvalidAmount);
+    }
+
+    let store = env.storage().persistent();
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    tokenization::check_not_burn_address(env, asset_id, &sender)?;
+    if dividends::is_distribution_frozen(env, asset_id) {
+        return Err(Error::DistributionInProgress);
+    }
+    tokenization::check_unlocked(env, asset_id, &sender, amount)?;
+    tokenization::consume_transfer_allowance(env, asset_id, &sender, amount)?;
+
+    tokenization::debit_holder(env, asset_id, &sender, amount, tokenized_asset.total_supply)?;
+
+    adjust_escrowed(env, asset_id, amount)?;
+
+    let escrow_id: u64 = store.get(&DataKey::EscrowCount).unwrap_or(0) + 1;
+    store.set(&DataKey::EscrowCount, &escrow_id);
+    store.set(
+        &DataKey::Escrow(escrow_id),
+        &Escrow {
+            escrow_id,
+            asset_id,
+            sender: sender.clone(),
+            amount,
+            recipients,
+            release_after,
+            status: EscrowStatus::Pending,
+        },
+    );
+
+    audit::append_audit_log(
+        env,
+        &tokenization::asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "TOKENS_ESCROWED"),
+        sender.clone(),
+        String::from_str(env, "Tokens placed in escrow"),
+    );
+
+    env.events()
+        .publish(("escrow", "created"), (escrow_id, asset_id, sender, amount));
+
+    Ok(escrow_id)
+}
+
+/// Release a pending escrow to all of its recipients at once. Anyone may trigger
+/// the release once `release_after` has passed, since tokens only go to recipients.
+pub fn claim_escrow(env: &Env, escrow_id: u64) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let key = DataKey::Escrow(escrow_id);
+    let mut escrow: Escrow = store.get(&key).ok_or(Error::EscrowNotFound)?;
+
+    if escrow.status != EscrowStatus::Pending {
+        return Err(Error::Unauthorized);
+    }
+    if env.ledger().timestamp() < escrow.release_after {
+        return Err(Error::TokensAreLocked);
+    }
+
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(escrow.asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    for (recipient, share) in escrow.recipients.iter() {
+        tokenization::credit_holder(
+            env,
+            escrow.asset_id,
+            &recipient,
+            share,
+            tokenized_asset.total_supply,
+        )?;
+    }
+
+    escrow.status = EscrowStatus::Released;
+    store.set(&key, &escrow);
+    adjust_escrowed(env, escrow.asset_id, -escrow.amount)?;
+
+    audit::append_audit_log(
+        env,
+        &tokenization::asset_id_to_bytes(env, escrow.asset_id),
+        String::from_str(env, "ESCROW_RELEASED"),
+        escrow.sender.clone(),
+        String::from_str(env, "Escrowed tokens released to recipients"),
+    );
+
+    env.events()
+        .publish(("escrow", "released"), (escrow_id, escrow.recipients.len()));
+
+    Ok(())
+}
+
+/// Cancel a pending escrow and refund the full amount to the sender (sender only)
+pub fn cancel_escrow(env: &Env, escrow_id: u64, sender: Address) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let key = DataKey::Escrow(escrow_id);
+    let mut escrow: Escrow = store.get(&key).ok_or(Error::EscrowNotFound)?;
+
+    if escrow.sender != sender {
+        return Err(Error::Unauthorized);
+    }
+    if escrow.status != EscrowStatus::Pending {
+        return Err(Error::Unauthorized);
+    }
+
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(escrow.asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    tokenization::credit_holder(
+        env,
+        escrow.asset_id,
+        &sender,
+        escrow.amount,
+        tokenized_asset.total_supply,
+    )?;
+
+    escrow.status = EscrowStatus::Cancelled;
+    store.set(&key, &escrow);
+    adjust_escrowed(env, escrow.asset_id, -escrow.amount)?;
+
+    audit::append_audit_log(
+        env,
+        &tokenization::asset_id_to_bytes(env, escrow.asset_id),
+        String::from_str(env, "ESCROW_CANCELLED"),
+        sender.clone(),
+        String::from_str(env, "Escrow cancelled and refunded"),
+    );
+
+    env.events()
+        .publish(("escrow", "cancelled"), (escrow_id, sender, escrow.amount));
+
+    Ok(())
+}
+
+fn adjust_escrowed(env: &Env, asset_id: u64, delta: i128) -> Result<(), Error> {
+    let key = DataKey::AssetEscrowed(asset_id);
+    let escrowed = escrowed_amount(env, asset_id)
+        .checked_add(delta)
+        .ok_or(Error::ArithmeticOverflow)?;
+    env.storage().persistent().set(&key, &escrowed);
+    Ok(())
+}
+
+/// Total tokens of an asset held in pending escrows, outside any holder's balance
+pub(crate) fn escrowed_amount(env: &Env, asset_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AssetEscrowed(asset_id))
+        .unwrap_or(0)
+}
+
+/// Get an escrow by ID
+pub fn get_escrow(env: &Env, escrow_id: u64) -> Result<Escrow, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Escrow(escrow_id))
+        .ok_or(Error::EscrowNotFound)
+}
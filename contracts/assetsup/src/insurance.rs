@@ -1,8 +1,11 @@
 #![allow(dead_code)]
 
 use crate::audit;
+use crate::roles;
+use crate::tokenization;
+use crate::types::{TokenDataKey, TokenizedAsset};
 use crate::Error;
-use soroban_sdk::{contracttype, log, Address, BytesN, Env, String, Vec};
+use soroban_sdk::{contracttype, log, token, Address, BytesN, Env, String, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -43,6 +46,19 @@ pub enum ClaimType {
     Other,
 }
 
+/// What a policyholder should do next
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PolicyAction {
+    /// Premium of the given amount is due by the given timestamp
+    PremiumDue(i128, u64),
+    /// Policy ends at the given timestamp and should be renewed
+    RenewalDue(u64),
+    Expired,
+    Cancelled,
+    Current,
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct InsurancePolicy {
@@ -59,6 +75,21 @@ pub struct InsurancePolicy {
     pub status: PolicyStatus,
     pub auto_renew: bool,
     pub last_payment: u64,
+    /// Claim types this policy does not cover
+    pub excluded_claim_types: Vec<ClaimType>,
+    /// Token the premium is denominated in; claims are paid out in the same token
+    pub premium_token: Address,
+    /// Seconds between recurring premium payments (0 = premium paid once up front)
+    pub premium_interval: u64,
+    /// Seconds after `start_date` before claims can be filed (0 = no waiting period)
+    pub claim_waiting_period_secs: u64,
+    /// Seconds a recurring premium may be overdue before the policy can be auto-suspended
+    pub premium_grace_secs: u64,
+    /// Share of every loss borne by the holder, in basis points, applied after the deductible
+    pub coinsurance_bps: u32,
+    /// Insurers sharing the risk with their share in basis points, summing to 10000.
+    /// Empty when `insurer` carries the whole policy.
+    pub co_insurers: Vec<(Address, u32)>,
 }
 
 #[contracttype]
@@ -73,6 +104,24 @@ pub struct InsuranceClaim {
     pub status: ClaimStatus,
     pub filed_at: u64,
     pub approved_amount: i128,
+    /// Amount actually paid to the claimant, net of deductible and co-insurance
+    pub paid_amount: i128,
+    /// Insurer's reason, set when the claim is rejected
+    pub reason: Option<String>,
+    /// Hash of the supporting documentation submitted with the claim
+    pub evidence_hash: BytesN<32>,
+}
+
+/// Installment plan for paying out an approved claim
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimPayoutSchedule {
+    pub num_installments: u32,
+    pub interval_secs: u64,
+    pub installments_paid: u32,
+    pub amount_paid: i128,
+    /// Timestamp from which the next installment may be paid
+    pub next_due: u64,
 }
 
 #[contracttype]
@@ -82,63 +131,328 @@ pub enum DataKey {
     Claim(BytesN<32>),
     AssetPolicies(BytesN<32>),
     AssetClaims(BytesN<32>),
+    PolicyClaims(BytesN<32>),
+    InsurerPolicies(Address),
+    InsurerClaims(Address),
+    InsurerPaidTotal(Address),
+    PoolBalance(Address),
+    PayoutSchedule(BytesN<32>),
+    TotalActiveCoverage,
+    TotalApprovedUnpaid,
+    ClaimTimeline(BytesN<32>),
+    LapseSuspended(BytesN<32>),
+    StatusHistory(BytesN<32>),
+    MaxCoverageRatio,
+    ClaimPayoutLock(BytesN<32>),
+    ClaimInsurerShares(BytesN<32>),
+    ClaimEvidence(BytesN<32>),
+    PolicyTerm(BytesN<32>),
 }
 
-/// Create a new insurance policy with date validation and asset indexing
-pub fn create_policy(env: Env, policy: InsurancePolicy) -> Result<(), Error> {
-    // Validate coverage and deductible
-    if policy.coverage_amount <= 0 || policy.deductible >= policy.coverage_amount {
-        return Err(Error::InvalidPayment);
+/// Upper bound on the number of index entries scanned by insurer-level reads
+pub const MAX_INSURER_SCAN: u32 = 200;
+
+/// Default cap on coverage relative to a tokenized asset's valuation, in basis points (1x)
+pub const DEFAULT_MAX_COVERAGE_RATIO_BPS: u32 = 10000;
+
+/// How long before a premium due date `policy_next_action` starts reporting it
+pub const PREMIUM_NOTICE_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// How long before `end_date` `policy_next_action` starts reporting a renewal
+pub const RENEWAL_NOTICE_SECS: u64 = 30 * 24 * 60 * 60;
+
+/// True if `caller` is the policy's insurer or holds the `INSURER` role the
+/// insurer granted
+fn acts_for_insurer(env: &Env, policy: &InsurancePolicy, caller: &Address) -> bool {
+    *caller == policy.insurer
+        || roles::has_scoped_role(
+            env,
+            roles::INSURER,
+            roles::RoleScope::Insurer(policy.insurer.clone()),
+            caller.clone(),
+        )
+}
+
+/// Validate a policy's terms and that its ID is not already in use
+fn validate_policy(env: &Env, policy: &InsurancePolicy) -> Result<(), Error> {
+    if policy.coverage_amount <= 0 || policy.premium <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    // Validate deductible and co-insurance
+    if policy.deductible >= policy.coverage_amount || policy.coinsurance_bps > 10000 {
+        return Err(Error::InvalidAmount);
     }
 
-    // Validate premium
-    if policy.premium <= 0 {
-        return Err(Error::InvalidPayment);
+    // Co-insurer shares must each be positive and together cover the whole policy
+    if !policy.co_insurers.is_empty() {
+        let mut total_bps: u32 = 0;
+        for (_, share_bps) in policy.co_insurers.iter() {
+            if share_bps == 0 {
+                return Err(Error::InvalidAmount);
+            }
+            total_bps = total_bps.saturating_add(share_bps);
+        }
+        if total_bps != 10000 {
+            return Err(Error::InvalidAmount);
+        }
+    }
+
+    // Coverage on a tokenized asset must stay within the allowed multiple of its valuation
+    if let Some(max_coverage) = max_coverage_for(env, &policy.asset_id) {
+        if policy.coverage_amount > max_coverage {
+            return Err(Error::CoverageExceedsValuation);
+        }
     }
 
     // Validate dates: start_date must be before end_date
     if policy.start_date >= policy.end_date {
-        return Err(Error::InvalidPayment);
+        return Err(Error::InvalidAmount);
     }
 
     // Validate that start_date is not in the past (allow current timestamp)
     let current_time = env.ledger().timestamp();
     if policy.start_date < current_time {
-        return Err(Error::InvalidPayment);
+        return Err(Error::InvalidAmount);
     }
 
-    let key = DataKey::Policy(policy.policy_id.clone());
-    let store = env.storage().persistent();
+    // Validate the premium token is a token contract
+    if token::TokenClient::new(env, &policy.premium_token)
+        .try_decimals()
+        .is_err()
+    {
+        return Err(Error::InvalidAmount);
+    }
 
     // Check if policy already exists
-    if store.has(&key) {
+    let key = DataKey::Policy(policy.policy_id.clone());
+    if env.storage().persistent().has(&key) {
         return Err(Error::AssetAlreadyExists);
     }
 
+    Ok(())
+}
+
+/// Most coverage allowed on an asset, or None when it is not tokenized and so has
+/// no on-chain valuation to check against
+fn max_coverage_for(env: &Env, asset_id: &BytesN<32>) -> Option<i128> {
+    let tokenized: TokenizedAsset =
+        env.storage()
+            .persistent()
+            .get(&TokenDataKey::TokenizedAsset(
+                tokenization::bytes_to_asset_id(asset_id)?,
+            ))?;
+    Some(
+        tokenized
+            .valuation
+            .saturating_mul(get_max_coverage_ratio(env) as i128)
+            / 10000,
+    )
+}
+
+/// Set the cap on coverage relative to a tokenized asset's valuation, in basis points
+pub fn set_max_coverage_ratio(env: &Env, ratio_bps: u32) -> Result<(), Error> {
+    if ratio_bps == 0 {
+        return Err(Error::InvalidAmount);
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::MaxCoverageRatio, &ratio_bps);
+    Ok(())
+}
+
+/// Get the cap on coverage relative to a tokenized asset's valuation, in basis points
+pub fn get_max_coverage_ratio(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MaxCoverageRatio)
+        .unwrap_or(DEFAULT_MAX_COVERAGE_RATIO_BPS)
+}
+
+/// Persist a validated policy, maintain its indexes and record it in the audit log
+fn store_policy(env: &Env, policy: &InsurancePolicy) -> Result<(), Error> {
+    let store = env.storage().persistent();
+
     // Store the policy
-    store.set(&key, &policy);
+    store.set(&DataKey::Policy(policy.policy_id.clone()), policy);
 
     // Maintain asset index: add policy to asset's policy list
     let mut list: Vec<BytesN<32>> = store
         .get(&DataKey::AssetPolicies(policy.asset_id.clone()))
-        .unwrap_or_else(|| Vec::new(&env));
+        .unwrap_or_else(|| Vec::new(env));
 
     list.push_back(policy.policy_id.clone());
     store.set(&DataKey::AssetPolicies(policy.asset_id.clone()), &list);
 
+    // Maintain insurer index
+    let mut insurer_list: Vec<BytesN<32>> = store
+        .get(&DataKey::InsurerPolicies(policy.insurer.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+    insurer_list.push_back(policy.policy_id.clone());
+    store.set(
+        &DataKey::InsurerPolicies(policy.insurer.clone()),
+        &insurer_list,
+    );
+
+    if policy.status == PolicyStatus::Active {
+        adjust_total(env, DataKey::TotalActiveCoverage, policy.coverage_amount)?;
+    }
+    record_status(env, &policy.policy_id, policy.status.clone());
+
     // Append audit log
     audit::append_audit_log(
-        &env,
+        env,
         &policy.asset_id,
-        String::from_str(&env, "INSURANCE_POLICY_CREATED"),
+        String::from_str(env, "INSURANCE_POLICY_CREATED"),
         policy.insurer.clone(),
-        String::from_str(&env, "Insurance policy created"),
+        String::from_str(env, "Insurance policy created"),
     );
+    Ok(())
+}
+
+/// Add `delta` to one of the global running totals
+fn adjust_total(env: &Env, key: DataKey, delta: i128) -> Result<(), Error> {
+    let total: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    let total = total.checked_add(delta).ok_or(Error::ArithmeticOverflow)?;
+    env.storage().persistent().set(&key, &total);
+    Ok(())
+}
+
+/// Change a policy's status, keeping the global active coverage total in step
+fn set_policy_status(
+    env: &Env,
+    policy: &mut InsurancePolicy,
+    status: PolicyStatus,
+) -> Result<(), Error> {
+    let was_active = policy.status == PolicyStatus::Active;
+    let is_active = status == PolicyStatus::Active;
+    if was_active && !is_active {
+        adjust_total(env, DataKey::TotalActiveCoverage, -policy.coverage_amount)?;
+    } else if !was_active && is_active {
+        adjust_total(env, DataKey::TotalActiveCoverage, policy.coverage_amount)?;
+    }
+    record_status(env, &policy.policy_id, status.clone());
+    policy.status = status;
+    Ok(())
+}
+
+/// Append a `(timestamp, status)` entry to a policy's status history
+fn record_status(env: &Env, policy_id: &BytesN<32>, status: PolicyStatus) {
+    let store = env.storage().persistent();
+    let key = DataKey::StatusHistory(policy_id.clone());
+    let mut history: Vec<(u64, PolicyStatus)> = store.get(&key).unwrap_or_else(|| Vec::new(env));
+    history.push_back((env.ledger().timestamp(), status));
+    store.set(&key, &history);
+}
+
+/// Whether a loss at `loss_timestamp` falls inside the policy's coverage: within
+/// `[start_date, end_date]`, past the claim waiting period, and while the policy was
+/// Active according to its status history
+pub fn is_covered_at(env: Env, policy_id: BytesN<32>, loss_timestamp: u64) -> bool {
+    let store = env.storage().persistent();
+    let policy: InsurancePolicy = match store.get(&DataKey::Policy(policy_id.clone())) {
+        Some(p) => p,
+        None => return false,
+    };
+
+    let covered_from = policy
+        .start_date
+        .saturating_add(policy.claim_waiting_period_secs);
+    if loss_timestamp < covered_from || loss_timestamp > policy.end_date {
+        return false;
+    }
+
+    // Status in force at the loss date; policies without history use their current status
+    let history: Vec<(u64, PolicyStatus)> = store
+        .get(&DataKey::StatusHistory(policy_id))
+        .unwrap_or_else(|| Vec::new(&env));
+    let mut status = match history.first() {
+        Some((_, first)) => first,
+        None => policy.status,
+    };
+    for (changed_at, entry) in history.iter() {
+        if changed_at > loss_timestamp {
+            break;
+        }
+        status = entry;
+    }
+
+    status == PolicyStatus::Active
+}
 
+/// Create a new insurance policy with date validation and asset indexing
+pub fn create_policy(env: Env, policy: InsurancePolicy) -> Result<(), Error> {
+    validate_policy(&env, &policy)?;
+    store_policy(&env, &policy)?;
+
+    env.events().publish(
+        ("insurance", "policy_created"),
+        (
+            policy.policy_id.clone(),
+            policy.asset_id.clone(),
+            policy.holder.clone(),
+            policy.insurer.clone(),
+        ),
+    );
     log!(&env, "PolicyCreated: {:?}", policy.policy_id);
     Ok(())
 }
 
+/// Find the first policy in a batch that would be rejected, with the reason.
+/// Checks each policy's terms, that `insurer` wrote it, and that its ID is unique
+/// both in storage and within the batch.
+pub fn find_invalid_policy(
+    env: &Env,
+    policies: &Vec<InsurancePolicy>,
+    insurer: &Address,
+) -> Option<(BytesN<32>, Error)> {
+    let mut seen: Vec<BytesN<32>> = Vec::new(env);
+    for policy in policies.iter() {
+        if policy.insurer != *insurer {
+            return Some((policy.policy_id, Error::Unauthorized));
+        }
+        if seen.contains(&policy.policy_id) {
+            return Some((policy.policy_id, Error::AssetAlreadyExists));
+        }
+        if let Err(err) = validate_policy(env, &policy) {
+            return Some((policy.policy_id, err));
+        }
+        seen.push_back(policy.policy_id);
+    }
+    None
+}
+
+/// Create several policies for one insurer atomically.
+/// Every policy is validated before anything is written; if any fails, the whole
+/// batch is rejected with that policy's error. The log line naming the policy is
+/// only emitted in debug builds; call `check_policies_batch` for the offending ID.
+pub fn create_policies_batch(
+    env: Env,
+    policies: Vec<InsurancePolicy>,
+    insurer: Address,
+) -> Result<(), Error> {
+    if policies.is_empty() {
+        return Err(Error::InvalidAmount);
+    }
+
+    if let Some((policy_id, err)) = find_invalid_policy(&env, &policies, &insurer) {
+        log!(&env, "PolicyBatchRejected: {:?}", policy_id);
+        return Err(err);
+    }
+
+    for policy in policies.iter() {
+        store_policy(&env, &policy)?;
+    }
+
+    // Emit event: (insurer, count)
+    env.events().publish(
+        ("insurance", "policies_batch_created"),
+        (insurer, policies.len()),
+    );
+
+    Ok(())
+}
+
 /// Cancel a policy (authorized by holder or insurer)
 pub fn cancel_policy(env: Env, policy_id: BytesN<32>, caller: Address) -> Result<(), Error> {
     let store = env.storage().persistent();
@@ -156,7 +470,7 @@ pub fn cancel_policy(env: Env, policy_id: BytesN<32>, caller: Address) -> Result
         return Err(Error::Unauthorized);
     }
 
-    policy.status = PolicyStatus::Cancelled;
+    set_policy_status(&env, &mut policy, PolicyStatus::Cancelled)?;
     store.set(&key, &policy);
 
     // Append audit log
@@ -164,14 +478,57 @@ pub fn cancel_policy(env: Env, policy_id: BytesN<32>, caller: Address) -> Result
         &env,
         &policy.asset_id,
         String::from_str(&env, "INSURANCE_POLICY_CANCELLED"),
-        caller,
+        caller.clone(),
         String::from_str(&env, "Insurance policy cancelled"),
     );
 
+    env.events().publish(
+        ("insurance", "policy_cancelled"),
+        (policy_id.clone(), caller),
+    );
     log!(&env, "PolicyCancelled: {:?}", policy_id);
     Ok(())
 }
 
+/// Hand a policy to a new holder (current holder only), e.g. when the insured
+/// asset is sold. Cancelled and expired policies cannot be transferred.
+pub fn transfer_policy(
+    env: Env,
+    policy_id: BytesN<32>,
+    new_holder: Address,
+    caller: Address,
+) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let key = DataKey::Policy(policy_id.clone());
+
+    let mut policy: InsurancePolicy = store.get(&key).ok_or(Error::AssetNotFound)?;
+
+    if caller != policy.holder {
+        return Err(Error::Unauthorized);
+    }
+    if policy.status == PolicyStatus::Cancelled || policy.status == PolicyStatus::Expired {
+        return Err(Error::Unauthorized);
+    }
+
+    policy.holder = new_holder.clone();
+    store.set(&key, &policy);
+
+    audit::append_audit_log(
+        &env,
+        &policy.asset_id,
+        String::from_str(&env, "INSURANCE_POLICY_TRANSFERRED"),
+        caller.clone(),
+        String::from_str(&env, "Insurance policy transferred to new holder"),
+    );
+
+    env.events().publish(
+        ("insurance", "policy_transferred"),
+        (policy_id.clone(), caller, new_holder),
+    );
+    log!(&env, "PolicyTransferred: {:?}", policy_id);
+    Ok(())
+}
+
 /// Suspend a policy (insurer only)
 pub fn suspend_policy(env: Env, policy_id: BytesN<32>, insurer: Address) -> Result<(), Error> {
     let store = env.storage().persistent();
@@ -180,7 +537,7 @@ pub fn suspend_policy(env: Env, policy_id: BytesN<32>, insurer: Address) -> Resu
     let mut policy: InsurancePolicy = store.get(&key).ok_or(Error::AssetNotFound)?;
 
     // Only insurer can suspend
-    if insurer != policy.insurer {
+    if !acts_for_insurer(&env, &policy, &insurer) {
         return Err(Error::Unauthorized);
     }
 
@@ -189,9 +546,13 @@ pub fn suspend_policy(env: Env, policy_id: BytesN<32>, insurer: Address) -> Resu
         return Err(Error::Unauthorized);
     }
 
-    policy.status = PolicyStatus::Suspended;
+    set_policy_status(&env, &mut policy, PolicyStatus::Suspended)?;
     store.set(&key, &policy);
 
+    env.events().publish(
+        ("insurance", "policy_suspended"),
+        (policy_id.clone(), insurer),
+    );
     log!(&env, "PolicySuspended: {:?}", policy_id);
     Ok(())
 }
@@ -215,9 +576,13 @@ pub fn expire_policy(env: Env, policy_id: BytesN<32>) -> Result<(), Error> {
         return Err(Error::Unauthorized);
     }
 
-    policy.status = PolicyStatus::Expired;
+    set_policy_status(&env, &mut policy, PolicyStatus::Expired)?;
     store.set(&key, &policy);
 
+    env.events().publish(
+        ("insurance", "policy_expired"),
+        (policy_id.clone(), policy.holder),
+    );
     log!(&env, "PolicyExpired: {:?}", policy_id);
     Ok(())
 }
@@ -236,7 +601,7 @@ pub fn renew_policy(
     let mut policy: InsurancePolicy = store.get(&key).ok_or(Error::AssetNotFound)?;
 
     // Only insurer can renew
-    if insurer != policy.insurer {
+    if !acts_for_insurer(&env, &policy, &insurer) {
         return Err(Error::Unauthorized);
     }
 
@@ -249,18 +614,18 @@ pub fn renew_policy(
 
     // Validate new end date is in the future
     if new_end_date <= current_time {
-        return Err(Error::InvalidPayment);
+        return Err(Error::InvalidAmount);
     }
 
     // Validate new premium is positive
     if new_premium <= 0 {
-        return Err(Error::InvalidPayment);
+        return Err(Error::InvalidAmount);
     }
 
     // Update policy
     policy.end_date = new_end_date;
     policy.premium = new_premium;
-    policy.status = PolicyStatus::Active;
+    set_policy_status(&env, &mut policy, PolicyStatus::Active)?;
     policy.last_payment = current_time;
 
     store.set(&key, &policy);
@@ -270,14 +635,256 @@ pub fn renew_policy(
         &env,
         &policy.asset_id,
         String::from_str(&env, "INSURANCE_POLICY_RENEWED"),
-        insurer,
+        insurer.clone(),
         String::from_str(&env, "Insurance policy renewed"),
     );
 
+    env.events().publish(
+        ("insurance", "policy_renewed"),
+        (policy_id.clone(), insurer, new_end_date),
+    );
     log!(&env, "PolicyRenewed: {:?}", policy_id);
     Ok(())
 }
 
+/// Replace the excluded claim types of a policy via endorsement (insurer only)
+pub fn endorse_policy_exclusions(
+    env: Env,
+    policy_id: BytesN<32>,
+    excluded_claim_types: Vec<ClaimType>,
+    insurer: Address,
+) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let key = DataKey::Policy(policy_id.clone());
+
+    let mut policy: InsurancePolicy = store.get(&key).ok_or(Error::AssetNotFound)?;
+
+    // Only insurer can endorse
+    if !acts_for_insurer(&env, &policy, &insurer) {
+        return Err(Error::Unauthorized);
+    }
+
+    // Only Active or Suspended policies can be endorsed
+    if policy.status != PolicyStatus::Active && policy.status != PolicyStatus::Suspended {
+        return Err(Error::Unauthorized);
+    }
+
+    policy.excluded_claim_types = excluded_claim_types;
+    store.set(&key, &policy);
+
+    // Append audit log
+    audit::append_audit_log(
+        &env,
+        &policy.asset_id,
+        String::from_str(&env, "INSURANCE_POLICY_ENDORSED"),
+        insurer,
+        String::from_str(&env, "Insurance policy exclusions amended"),
+    );
+
+    log!(&env, "PolicyEndorsed: {:?}", policy_id);
+    Ok(())
+}
+
+/// Pay a policy premium in the policy's premium token; funds go to that token's pool
+pub fn pay_premium(
+    env: Env,
+    policy_id: BytesN<32>,
+    payer: Address,
+    amount: i128,
+) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let key = DataKey::Policy(policy_id.clone());
+
+    let mut policy: InsurancePolicy = store.get(&key).ok_or(Error::AssetNotFound)?;
+
+    // Only Active or Suspended policies accept premiums
+    if policy.status != PolicyStatus::Active && policy.status != PolicyStatus::Suspended {
+        return Err(Error::Unauthorized);
+    }
+
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    // Payment must cover the premium
+    if amount < policy.premium {
+        return Err(Error::InvalidAmount);
+    }
+
+    // A policy suspended for a missed premium is reactivated by paying it
+    let lapse_key = DataKey::LapseSuspended(policy_id.clone());
+    if store.has(&lapse_key) {
+        store.remove(&lapse_key);
+        set_policy_status(&env, &mut policy, PolicyStatus::Active)?;
+    }
+
+    token::TokenClient::new(&env, &policy.premium_token).transfer(
+        &payer,
+        &env.current_contract_address(),
+        &amount,
+    );
+    credit_pool(&env, &policy.premium_token, amount)?;
+
+    policy.last_payment = env.ledger().timestamp();
+    store.set(&key, &policy);
+
+    audit::append_audit_log(
+        &env,
+        &policy.asset_id,
+        String::from_str(&env, "INSURANCE_PREMIUM_PAID"),
+        payer,
+        String::from_str(&env, "Policy premium paid"),
+    );
+
+    log!(&env, "PremiumPaid: {:?}", policy_id);
+    Ok(())
+}
+
+/// Deposit funds into the claim payout pool for a token
+pub fn fund_claim_pool(
+    env: Env,
+    premium_token: Address,
+    funder: Address,
+    amount: i128,
+) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    token::TokenClient::new(&env, &premium_token).transfer(
+        &funder,
+        &env.current_contract_address(),
+        &amount,
+    );
+    credit_pool(&env, &premium_token, amount)?;
+
+    log!(&env, "ClaimPoolFunded: {:?}", amount);
+    Ok(())
+}
+
+/// Get the claim payout pool balance held for a token
+pub fn get_pool_balance(env: Env, premium_token: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PoolBalance(premium_token))
+        .unwrap_or(0)
+}
+
+fn credit_pool(env: &Env, premium_token: &Address, amount: i128) -> Result<(), Error> {
+    let key = DataKey::PoolBalance(premium_token.clone());
+    let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    let balance = balance
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticOverflow)?;
+    env.storage().persistent().set(&key, &balance);
+    tokenization::adjust_total_value_locked(env, amount)
+}
+
+fn debit_pool(env: &Env, premium_token: &Address, amount: i128) -> Result<(), Error> {
+    let key = DataKey::PoolBalance(premium_token.clone());
+    let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    if balance < amount {
+        return Err(Error::InsufficientBalance);
+    }
+    env.storage().persistent().set(&key, &(balance - amount));
+    tokenization::adjust_total_value_locked(env, -amount)
+}
+
+/// Work out the next action for a policyholder from the policy's dates and payments.
+/// Expiry takes precedence over an upcoming premium, which takes precedence over renewal.
+pub fn policy_next_action(env: Env, policy_id: BytesN<32>) -> Result<PolicyAction, Error> {
+    let policy: InsurancePolicy = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Policy(policy_id))
+        .ok_or(Error::AssetNotFound)?;
+
+    let now = env.ledger().timestamp();
+
+    match policy.status {
+        PolicyStatus::Cancelled => return Ok(PolicyAction::Cancelled),
+        PolicyStatus::Expired => return Ok(PolicyAction::Expired),
+        PolicyStatus::Active | PolicyStatus::Suspended => {}
+    }
+
+    if now > policy.end_date {
+        return Ok(PolicyAction::Expired);
+    }
+
+    if policy.premium_interval > 0 {
+        let next_due = policy.last_payment.saturating_add(policy.premium_interval);
+        if next_due < policy.end_date && now.saturating_add(PREMIUM_NOTICE_SECS) >= next_due {
+            return Ok(PolicyAction::PremiumDue(policy.premium, next_due));
+        }
+    }
+
+    if now.saturating_add(RENEWAL_NOTICE_SECS) >= policy.end_date {
+        return Ok(PolicyAction::RenewalDue(policy.end_date));
+    }
+
+    Ok(PolicyAction::Current)
+}
+
+/// Whether a recurring premium is unpaid more than `grace_period` seconds past its
+/// due date. Policies paid once up front never lapse.
+fn premium_overdue(env: &Env, policy: &InsurancePolicy, grace_period: u64) -> bool {
+    if policy.premium_interval == 0 {
+        return false;
+    }
+    let overdue_after = policy
+        .last_payment
+        .saturating_add(policy.premium_interval)
+        .saturating_add(grace_period);
+    env.ledger().timestamp() > overdue_after
+}
+
+/// Check whether a policy's recurring premium has gone unpaid for longer than
+/// its interval plus `grace_period`. Read-only; see `check_premium_status`.
+pub fn is_policy_lapsed(env: Env, policy_id: BytesN<32>, grace_period: u64) -> bool {
+    match env
+        .storage()
+        .persistent()
+        .get::<_, InsurancePolicy>(&DataKey::Policy(policy_id))
+    {
+        Some(policy) => premium_overdue(&env, &policy, grace_period),
+        None => false,
+    }
+}
+
+/// Suspend an Active policy whose recurring premium is overdue past its grace
+/// period. Permissionless so a keeper can enforce it; returns the policy's status
+/// after the check. Paying the premium reactivates a policy suspended this way.
+pub fn check_premium_status(env: Env, policy_id: BytesN<32>) -> Result<PolicyStatus, Error> {
+    let store = env.storage().persistent();
+    let key = DataKey::Policy(policy_id.clone());
+    let mut policy: InsurancePolicy = store.get(&key).ok_or(Error::AssetNotFound)?;
+
+    if policy.status != PolicyStatus::Active
+        || !premium_overdue(&env, &policy, policy.premium_grace_secs)
+    {
+        return Ok(policy.status);
+    }
+
+    set_policy_status(&env, &mut policy, PolicyStatus::Suspended)?;
+    store.set(&key, &policy);
+    store.set(&DataKey::LapseSuspended(policy_id.clone()), &true);
+
+    audit::append_audit_log(
+        &env,
+        &policy.asset_id,
+        String::from_str(&env, "INSURANCE_POLICY_LAPSE_SUSPENDED"),
+        env.current_contract_address(),
+        String::from_str(&env, "Policy suspended for overdue premium"),
+    );
+
+    env.events().publish(
+        ("insurance", "policy_auto_suspended"),
+        (policy_id, policy.last_payment),
+    );
+
+    Ok(policy.status)
+}
+
 /// Get all policies for a specific asset
 pub fn get_asset_policies(env: Env, asset_id: BytesN<32>) -> Vec<BytesN<32>> {
     env.storage()
@@ -300,9 +907,27 @@ pub fn file_insurance_claim(env: Env, claim: InsuranceClaim) -> Result<(), Error
         return Err(Error::Unauthorized);
     }
 
+    // A policy whose premium lapsed is not in force, even before it is suspended
+    if premium_overdue(&env, &policy, policy.premium_grace_secs) {
+        return Err(Error::Unauthorized);
+    }
+
+    // Verify the waiting period after inception has elapsed
+    let claimable_from = policy
+        .start_date
+        .saturating_add(policy.claim_waiting_period_secs);
+    if env.ledger().timestamp() < claimable_from {
+        return Err(Error::PeriodNotElapsed);
+    }
+
+    // Verify the peril is covered by the policy
+    if policy.excluded_claim_types.contains(&claim.claim_type) {
+        return Err(Error::PerilExcluded);
+    }
+
     // Verify claim amount is positive
     if claim.amount <= 0 {
-        return Err(Error::InvalidPayment);
+        return Err(Error::InvalidAmount);
     }
 
     // Verify claim doesn't already exist
@@ -316,8 +941,12 @@ pub fn file_insurance_claim(env: Env, claim: InsuranceClaim) -> Result<(), Error
         return Err(Error::Unauthorized);
     }
 
-    // Store the claim
+    // Store the claim; its initial evidence opens the evidence list
     store.set(&claim_key, &claim);
+    store.set(
+        &DataKey::ClaimEvidence(claim.claim_id.clone()),
+        &Vec::from_array(&env, [claim.evidence_hash.clone()]),
+    );
 
     // Index claim by asset_id
     let mut asset_claims: Vec<BytesN<32>> = store
@@ -326,20 +955,120 @@ pub fn file_insurance_claim(env: Env, claim: InsuranceClaim) -> Result<(), Error
     asset_claims.push_back(claim.claim_id.clone());
     store.set(&DataKey::AssetClaims(claim.asset_id.clone()), &asset_claims);
 
+    // Index claim by policy_id
+    let mut policy_claims: Vec<BytesN<32>> = store
+        .get(&DataKey::PolicyClaims(claim.policy_id.clone()))
+        .unwrap_or_else(|| Vec::new(&env));
+    policy_claims.push_back(claim.claim_id.clone());
+    store.set(
+        &DataKey::PolicyClaims(claim.policy_id.clone()),
+        &policy_claims,
+    );
+
+    // Index claim by the policy's insurer
+    let mut insurer_claims: Vec<BytesN<32>> = store
+        .get(&DataKey::InsurerClaims(policy.insurer.clone()))
+        .unwrap_or_else(|| Vec::new(&env));
+    insurer_claims.push_back(claim.claim_id.clone());
+    store.set(
+        &DataKey::InsurerClaims(policy.insurer.clone()),
+        &insurer_claims,
+    );
+
+    record_claim_status_change(
+        &env,
+        &claim.claim_id,
+        None,
+        ClaimStatus::Submitted,
+        &claim.claimant,
+    );
+
+    env.events().publish(
+        ("insurance", "claim_filed"),
+        (
+            claim.claim_id.clone(),
+            claim.policy_id.clone(),
+            claim.claimant.clone(),
+        ),
+    );
     log!(&env, "ClaimFiled: {:?}", claim.claim_id);
     Ok(())
 }
 
-/// Move a claim from Submitted to UnderReview status
-pub fn mark_insurance_claim_under_review(
+/// Attach another piece of supporting evidence to a claim (claimant only) while it
+/// is still Submitted or UnderReview
+pub fn add_claim_evidence(
     env: Env,
     claim_id: BytesN<32>,
-    insurer: Address,
+    evidence_hash: BytesN<32>,
+    caller: Address,
 ) -> Result<(), Error> {
-    insurer.require_auth();
-
     let store = env.storage().persistent();
-    let claim_key = DataKey::Claim(claim_id.clone());
+    let claim: InsuranceClaim = store
+        .get(&DataKey::Claim(claim_id.clone()))
+        .ok_or(Error::AssetNotFound)?;
+
+    if caller != claim.claimant {
+        return Err(Error::Unauthorized);
+    }
+    if claim.status != ClaimStatus::Submitted && claim.status != ClaimStatus::UnderReview {
+        return Err(Error::Unauthorized);
+    }
+
+    let mut evidence = get_claim_evidence(env.clone(), claim_id.clone());
+    if evidence.contains(&evidence_hash) {
+        return Err(Error::AssetAlreadyExists);
+    }
+    evidence.push_back(evidence_hash.clone());
+    store.set(&DataKey::ClaimEvidence(claim_id.clone()), &evidence);
+
+    env.events().publish(
+        ("insurance", "claim_evidence_added"),
+        (claim_id.clone(), evidence_hash),
+    );
+    log!(&env, "ClaimEvidenceAdded: {:?}", claim_id);
+    Ok(())
+}
+
+/// Get every evidence hash attached to a claim, starting with the one it was filed with
+pub fn get_claim_evidence(env: Env, claim_id: BytesN<32>) -> Vec<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ClaimEvidence(claim_id))
+        .unwrap_or_else(|| Vec::new(&env))
+}
+
+/// Append a claim transition to its timeline and publish the unified transition
+/// event; `old_status` is None for a newly filed claim
+fn record_claim_status_change(
+    env: &Env,
+    claim_id: &BytesN<32>,
+    old_status: Option<ClaimStatus>,
+    new_status: ClaimStatus,
+    actor: &Address,
+) {
+    let timeline_key = DataKey::ClaimTimeline(claim_id.clone());
+    let mut timeline: Vec<(u64, ClaimStatus, Address)> = env
+        .storage()
+        .persistent()
+        .get(&timeline_key)
+        .unwrap_or_else(|| Vec::new(env));
+    timeline.push_back((env.ledger().timestamp(), new_status.clone(), actor.clone()));
+    env.storage().persistent().set(&timeline_key, &timeline);
+
+    env.events().publish(
+        ("insurance", "claim_status_changed"),
+        (claim_id.clone(), old_status, new_status, actor.clone()),
+    );
+}
+
+/// Move a claim from Submitted to UnderReview status (the policy's insurer reviews);
+/// claims must be reviewed before they can be approved or rejected
+pub fn start_claim_review(env: Env, claim_id: BytesN<32>, reviewer: Address) -> Result<(), Error> {
+    reviewer.require_auth();
+
+    let store = env.storage().persistent();
+    let claim_key = DataKey::Claim(claim_id.clone());
 
     let mut claim: InsuranceClaim = store.get(&claim_key).ok_or(Error::AssetNotFound)?;
 
@@ -347,17 +1076,24 @@ pub fn mark_insurance_claim_under_review(
     let policy: InsurancePolicy = store
         .get(&DataKey::Policy(claim.policy_id.clone()))
         .ok_or(Error::AssetNotFound)?;
-    if insurer != policy.insurer {
+    if !acts_for_insurer(&env, &policy, &reviewer) {
         return Err(Error::Unauthorized);
     }
 
     // Validate status transition: only Submitted claims can move to UnderReview
     if claim.status != ClaimStatus::Submitted {
-        return Err(Error::Unauthorized);
+        return Err(Error::InvalidStatusTransition);
     }
 
     claim.status = ClaimStatus::UnderReview;
     store.set(&claim_key, &claim);
+    record_claim_status_change(
+        &env,
+        &claim_id,
+        Some(ClaimStatus::Submitted),
+        ClaimStatus::UnderReview,
+        &reviewer,
+    );
 
     log!(&env, "ClaimUnderReview: {:?}", claim_id);
     Ok(())
@@ -381,38 +1117,167 @@ pub fn approve_insurance_claim(
     let policy: InsurancePolicy = store
         .get(&DataKey::Policy(claim.policy_id.clone()))
         .ok_or(Error::AssetNotFound)?;
-    if insurer != policy.insurer {
+    if !acts_for_insurer(&env, &policy, &insurer) {
         return Err(Error::Unauthorized);
     }
 
     // Validate status transition: only UnderReview claims can be approved
     if claim.status != ClaimStatus::UnderReview {
-        return Err(Error::Unauthorized);
+        return Err(Error::InvalidStatusTransition);
     }
 
     // Validate approved amount
     if approved_amount <= 0 {
-        return Err(Error::InvalidPayment);
+        return Err(Error::InvalidAmount);
     }
 
-    // Approved amount cannot exceed coverage amount
-    if approved_amount > policy.coverage_amount {
-        return Err(Error::InvalidPayment);
+    // Approved amount cannot exceed the covered part of the loss; the deductible
+    // is taken off when the claim is paid
+    if approved_amount > covered_loss(&policy, claim.amount) {
+        return Err(Error::InvalidAmount);
     }
 
     claim.status = ClaimStatus::Approved;
     claim.approved_amount = approved_amount;
     store.set(&claim_key, &claim);
+    adjust_total(
+        &env,
+        DataKey::TotalApprovedUnpaid,
+        claim_payout(&policy, approved_amount),
+    )?;
+    record_claim_status_change(
+        &env,
+        &claim_id,
+        Some(ClaimStatus::UnderReview),
+        ClaimStatus::Approved,
+        &insurer,
+    );
 
+    env.events().publish(
+        ("insurance", "claim_approved"),
+        (claim_id.clone(), insurer, approved_amount),
+    );
     log!(&env, "ClaimApproved: {:?}", claim_id);
     Ok(())
 }
 
-/// Reject a claim (only Submitted or UnderReview claims can be rejected)
+/// Part of a loss the policy covers: the loss capped at coverage
+fn covered_loss(policy: &InsurancePolicy, loss: i128) -> i128 {
+    loss.min(policy.coverage_amount)
+}
+
+/// What the insurer pays on an approved amount: the approved amount less the
+/// deductible, less the holder's co-insurance share of what remains, i.e.
+/// `(approved - deductible) * (10000 - coinsurance_bps) / 10000`, clamped at
+/// zero. The deductible is taken first, so co-insurance applies only to the
+/// part above it.
+fn claim_payout(policy: &InsurancePolicy, approved: i128) -> i128 {
+    let net = approved - policy.deductible;
+    if net <= 0 {
+        return 0;
+    }
+    net * (10000 - policy.coinsurance_bps as i128) / 10000
+}
+
+/// Get the most the insurer can end up paying on a claim under its policy's terms
+pub fn get_max_claim_payout(env: Env, claim_id: BytesN<32>) -> Result<i128, Error> {
+    let store = env.storage().persistent();
+    let claim: InsuranceClaim = store
+        .get(&DataKey::Claim(claim_id))
+        .ok_or(Error::AssetNotFound)?;
+    let policy: InsurancePolicy = store
+        .get(&DataKey::Policy(claim.policy_id))
+        .ok_or(Error::AssetNotFound)?;
+    Ok(claim_payout(&policy, covered_loss(&policy, claim.amount)))
+}
+
+/// Coverage left on a policy after claims already paid against it
+fn remaining_coverage(env: &Env, policy: &InsurancePolicy) -> i128 {
+    let store = env.storage().persistent();
+    let claim_ids: Vec<BytesN<32>> = store
+        .get(&DataKey::PolicyClaims(policy.policy_id.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+
+    let mut paid: i128 = 0;
+    for claim_id in claim_ids.iter() {
+        if let Some(claim) = store.get::<_, InsuranceClaim>(&DataKey::Claim(claim_id)) {
+            if claim.status == ClaimStatus::Paid {
+                paid += claim.approved_amount;
+            }
+        }
+    }
+    policy.coverage_amount - paid
+}
+
+/// Revise the approved amount of a claim that has not been paid yet (insurer only).
+/// The new amount must fit within the remaining coverage and the covered loss.
+pub fn revise_approval(
+    env: Env,
+    claim_id: BytesN<32>,
+    new_amount: i128,
+    approver: Address,
+) -> Result<(), Error> {
+    approver.require_auth();
+
+    let store = env.storage().persistent();
+    let claim_key = DataKey::Claim(claim_id.clone());
+
+    let mut claim: InsuranceClaim = store.get(&claim_key).ok_or(Error::AssetNotFound)?;
+
+    let policy: InsurancePolicy = store
+        .get(&DataKey::Policy(claim.policy_id.clone()))
+        .ok_or(Error::AssetNotFound)?;
+    if !acts_for_insurer(&env, &policy, &approver) {
+        return Err(Error::Unauthorized);
+    }
+
+    // Only approved, unpaid claims can be revised
+    if claim.status != ClaimStatus::Approved {
+        return Err(Error::Unauthorized);
+    }
+    if let Some(schedule) =
+        store.get::<_, ClaimPayoutSchedule>(&DataKey::PayoutSchedule(claim_id.clone()))
+    {
+        if schedule.installments_paid > 0 {
+            return Err(Error::Unauthorized);
+        }
+    }
+
+    if new_amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if new_amount > remaining_coverage(&env, &policy)
+        || new_amount > covered_loss(&policy, claim.amount)
+    {
+        return Err(Error::InvalidAmount);
+    }
+
+    adjust_total(
+        &env,
+        DataKey::TotalApprovedUnpaid,
+        claim_payout(&policy, new_amount) - claim_payout(&policy, claim.approved_amount),
+    )?;
+    claim.approved_amount = new_amount;
+    store.set(&claim_key, &claim);
+
+    audit::append_audit_log(
+        &env,
+        &claim.asset_id,
+        String::from_str(&env, "INSURANCE_CLAIM_APPROVAL_REVISED"),
+        approver,
+        String::from_str(&env, "Approved claim amount revised"),
+    );
+
+    log!(&env, "ClaimApprovalRevised: {:?}", claim_id);
+    Ok(())
+}
+
+/// Reject a claim (only UnderReview claims can be rejected)
 pub fn reject_insurance_claim(
     env: Env,
     claim_id: BytesN<32>,
     insurer: Address,
+    reason: String,
 ) -> Result<(), Error> {
     insurer.require_auth();
 
@@ -425,23 +1290,39 @@ pub fn reject_insurance_claim(
     let policy: InsurancePolicy = store
         .get(&DataKey::Policy(claim.policy_id.clone()))
         .ok_or(Error::AssetNotFound)?;
-    if insurer != policy.insurer {
+    if !acts_for_insurer(&env, &policy, &insurer) {
         return Err(Error::Unauthorized);
     }
 
-    // Validate status transition: only Submitted or UnderReview claims can be rejected
-    if claim.status != ClaimStatus::Submitted && claim.status != ClaimStatus::UnderReview {
-        return Err(Error::Unauthorized);
+    // Validate status transition: only UnderReview claims can be rejected
+    if claim.status != ClaimStatus::UnderReview {
+        return Err(Error::InvalidStatusTransition);
     }
 
     claim.status = ClaimStatus::Rejected;
+    claim.reason = Some(reason.clone());
     store.set(&claim_key, &claim);
+    record_claim_status_change(
+        &env,
+        &claim_id,
+        Some(ClaimStatus::UnderReview),
+        ClaimStatus::Rejected,
+        &insurer,
+    );
+
+    audit::append_audit_log(
+        &env,
+        &claim.asset_id,
+        String::from_str(&env, "INSURANCE_CLAIM_REJECTED"),
+        insurer,
+        reason,
+    );
 
     log!(&env, "ClaimRejected: {:?}", claim_id);
     Ok(())
 }
 
-/// Allow claimant to dispute a rejected claim
+/// Allow claimant to dispute a rejected or unpaid approved claim
 pub fn dispute_insurance_claim(
     env: Env,
     claim_id: BytesN<32>,
@@ -459,22 +1340,164 @@ pub fn dispute_insurance_claim(
         return Err(Error::Unauthorized);
     }
 
-    // Validate status transition: only Rejected claims can be disputed
-    if claim.status != ClaimStatus::Rejected {
+    // Validate status transition: only Rejected or Approved claims can be disputed
+    if claim.status != ClaimStatus::Rejected && claim.status != ClaimStatus::Approved {
         return Err(Error::Unauthorized);
     }
 
+    // An approval already on an installment plan can no longer be disputed
+    if store.has(&DataKey::PayoutSchedule(claim_id.clone())) {
+        return Err(Error::Unauthorized);
+    }
+
+    let old_status = claim.status.clone();
+    if old_status == ClaimStatus::Approved {
+        let policy: InsurancePolicy = store
+            .get(&DataKey::Policy(claim.policy_id.clone()))
+            .ok_or(Error::AssetNotFound)?;
+        adjust_total(
+            &env,
+            DataKey::TotalApprovedUnpaid,
+            -claim_payout(&policy, claim.approved_amount),
+        )?;
+    }
+
     claim.status = ClaimStatus::Disputed;
     store.set(&claim_key, &claim);
+    record_claim_status_change(
+        &env,
+        &claim_id,
+        Some(old_status),
+        ClaimStatus::Disputed,
+        &claimant,
+    );
+
+    audit::append_audit_log(
+        &env,
+        &claim.asset_id,
+        String::from_str(&env, "INSURANCE_CLAIM_DISPUTED"),
+        claimant,
+        String::from_str(&env, "Claimant disputed the claim decision"),
+    );
 
     log!(&env, "ClaimDisputed: {:?}", claim_id);
     Ok(())
 }
 
-/// Mark an approved claim as paid
+/// Resolve a disputed claim (insurer only). Upholding restores the original
+/// decision: a rejection stays rejected and an approval keeps its amount.
+/// Otherwise the claim is approved for `final_amount`, which is bounded like
+/// any other approval.
+pub fn resolve_dispute(
+    env: Env,
+    claim_id: BytesN<32>,
+    insurer: Address,
+    uphold: bool,
+    final_amount: i128,
+) -> Result<ClaimStatus, Error> {
+    insurer.require_auth();
+
+    let store = env.storage().persistent();
+    let claim_key = DataKey::Claim(claim_id.clone());
+
+    let mut claim: InsuranceClaim = store.get(&claim_key).ok_or(Error::AssetNotFound)?;
+
+    let policy: InsurancePolicy = store
+        .get(&DataKey::Policy(claim.policy_id.clone()))
+        .ok_or(Error::AssetNotFound)?;
+    if !acts_for_insurer(&env, &policy, &insurer) {
+        return Err(Error::Unauthorized);
+    }
+
+    if claim.status != ClaimStatus::Disputed {
+        return Err(Error::Unauthorized);
+    }
+
+    if !uphold {
+        if final_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if final_amount > remaining_coverage(&env, &policy)
+            || final_amount > covered_loss(&policy, claim.amount)
+        {
+            return Err(Error::InvalidAmount);
+        }
+        claim.approved_amount = final_amount;
+    }
+
+    // Rejected claims never carry an approved amount, so a disputed claim with
+    // one came from an approval
+    let new_status = if claim.approved_amount > 0 {
+        ClaimStatus::Approved
+    } else {
+        ClaimStatus::Rejected
+    };
+    if new_status == ClaimStatus::Approved {
+        adjust_total(
+            &env,
+            DataKey::TotalApprovedUnpaid,
+            claim_payout(&policy, claim.approved_amount),
+        )?;
+    }
+
+    claim.status = new_status.clone();
+    store.set(&claim_key, &claim);
+    record_claim_status_change(
+        &env,
+        &claim_id,
+        Some(ClaimStatus::Disputed),
+        new_status.clone(),
+        &insurer,
+    );
+
+    audit::append_audit_log(
+        &env,
+        &claim.asset_id,
+        String::from_str(&env, "INSURANCE_CLAIM_DISPUTE_RESOLVED"),
+        insurer,
+        if uphold {
+            String::from_str(&env, "Original claim decision upheld")
+        } else {
+            String::from_str(&env, "Claim approved on dispute")
+        },
+    );
+
+    log!(&env, "ClaimDisputeResolved: {:?}", claim_id);
+    Ok(new_status)
+}
+
+/// Pay an approved claim, net of the policy's deductible and co-insurance, and
+/// mark it as paid. A claim whose deductible swallows the approved amount is
+/// still closed as Paid with a zero payout.
 pub fn pay_insurance_claim(env: Env, claim_id: BytesN<32>, insurer: Address) -> Result<(), Error> {
     insurer.require_auth();
 
+    with_claim_payout_lock(&env.clone(), &claim_id.clone(), || {
+        settle_insurance_claim(env, claim_id, insurer)
+    })
+}
+
+/// Run a payout for `claim_id` with that claim's payout lock held, so a nested
+/// payout of the same claim fails with `Error::Reentrancy` instead of
+/// paying twice
+fn with_claim_payout_lock<T>(
+    env: &Env,
+    claim_id: &BytesN<32>,
+    f: impl FnOnce() -> Result<T, Error>,
+) -> Result<T, Error> {
+    let store = env.storage().persistent();
+    let lock_key = DataKey::ClaimPayoutLock(claim_id.clone());
+    if store.has(&lock_key) {
+        return Err(Error::Reentrancy);
+    }
+
+    store.set(&lock_key, &true);
+    let result = f();
+    store.remove(&lock_key);
+    result
+}
+
+fn settle_insurance_claim(env: Env, claim_id: BytesN<32>, insurer: Address) -> Result<(), Error> {
     let store = env.storage().persistent();
     let claim_key = DataKey::Claim(claim_id.clone());
 
@@ -484,7 +1507,7 @@ pub fn pay_insurance_claim(env: Env, claim_id: BytesN<32>, insurer: Address) ->
     let policy: InsurancePolicy = store
         .get(&DataKey::Policy(claim.policy_id.clone()))
         .ok_or(Error::AssetNotFound)?;
-    if insurer != policy.insurer {
+    if !acts_for_insurer(&env, &policy, &insurer) {
         return Err(Error::Unauthorized);
     }
 
@@ -493,18 +1516,246 @@ pub fn pay_insurance_claim(env: Env, claim_id: BytesN<32>, insurer: Address) ->
         return Err(Error::Unauthorized);
     }
 
+    // Claims on an installment plan are paid through pay_claim_installment
+    if store.has(&DataKey::PayoutSchedule(claim_id.clone())) {
+        return Err(Error::Unauthorized);
+    }
+
+    let payout = claim_payout(&policy, claim.approved_amount);
     claim.status = ClaimStatus::Paid;
+    claim.paid_amount = payout;
     store.set(&claim_key, &claim);
+    record_claim_status_change(
+        &env,
+        &claim_id,
+        Some(ClaimStatus::Approved),
+        ClaimStatus::Paid,
+        &insurer,
+    );
+
+    pay_from_pool(&env, &claim_id, &policy, &claim.claimant, payout)?;
+    adjust_total(&env, DataKey::TotalApprovedUnpaid, -payout)?;
 
+    env.events()
+        .publish(("insurance", "claim_paid"), (claim_id.clone(), payout));
     log!(&env, "ClaimPaid: {:?}", claim_id);
     Ok(())
 }
 
+/// Transfer `amount` from the policy token's pool to `claimant`, split it across
+/// the policy's insurers and add each share to that insurer's running payout total
+/// and to the claim's per-insurer record
+fn pay_from_pool(
+    env: &Env,
+    claim_id: &BytesN<32>,
+    policy: &InsurancePolicy,
+    claimant: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    if amount == 0 {
+        return Ok(());
+    }
+    debit_pool(env, &policy.premium_token, amount)?;
+    token::TokenClient::new(env, &policy.premium_token).transfer(
+        &env.current_contract_address(),
+        claimant,
+        &amount,
+    );
+
+    let store = env.storage().persistent();
+    let shares_key = DataKey::ClaimInsurerShares(claim_id.clone());
+    let mut claim_shares = get_claim_insurer_shares(env.clone(), claim_id.clone());
+    for (insurer, share) in insurer_shares(env, policy, amount).iter() {
+        let paid_key = DataKey::InsurerPaidTotal(insurer.clone());
+        let paid_total: i128 = store.get(&paid_key).unwrap_or(0);
+        let paid_total = paid_total
+            .checked_add(share)
+            .ok_or(Error::ArithmeticOverflow)?;
+        store.set(&paid_key, &paid_total);
+
+        match claim_shares.iter().position(|(a, _)| a == insurer) {
+            Some(index) => {
+                let (_, paid) = claim_shares.get(index as u32).unwrap();
+                claim_shares.set(index as u32, (insurer, paid + share));
+            }
+            None => claim_shares.push_back((insurer, share)),
+        }
+    }
+    store.set(&shares_key, &claim_shares);
+    Ok(())
+}
+
+/// Split `amount` across a policy's insurers by share, with the rounding remainder
+/// borne by the last co-insurer
+fn insurer_shares(env: &Env, policy: &InsurancePolicy, amount: i128) -> Vec<(Address, i128)> {
+    let mut shares = Vec::new(env);
+    if policy.co_insurers.is_empty() {
+        shares.push_back((policy.insurer.clone(), amount));
+        return shares;
+    }
+
+    let mut allocated: i128 = 0;
+    let last = policy.co_insurers.len() - 1;
+    for (index, (insurer, share_bps)) in policy.co_insurers.iter().enumerate() {
+        let share = if index as u32 == last {
+            amount - allocated
+        } else {
+            amount * share_bps as i128 / 10000
+        };
+        allocated += share;
+        shares.push_back((insurer, share));
+    }
+    shares
+}
+
+/// Get how much of a claim's payout each insurer has borne so far
+pub fn get_claim_insurer_shares(env: Env, claim_id: BytesN<32>) -> Vec<(Address, i128)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ClaimInsurerShares(claim_id))
+        .unwrap_or_else(|| Vec::new(&env))
+}
+
+/// Split an approved claim's payout into installments, the first due immediately
+pub fn schedule_claim_payout(
+    env: Env,
+    claim_id: BytesN<32>,
+    num_installments: u32,
+    interval_secs: u64,
+    insurer: Address,
+) -> Result<(), Error> {
+    insurer.require_auth();
+
+    if num_installments == 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if num_installments > 1 && interval_secs == 0 {
+        return Err(Error::InvalidTimestamps);
+    }
+
+    let store = env.storage().persistent();
+    let claim: InsuranceClaim = store
+        .get(&DataKey::Claim(claim_id.clone()))
+        .ok_or(Error::AssetNotFound)?;
+
+    let policy: InsurancePolicy = store
+        .get(&DataKey::Policy(claim.policy_id.clone()))
+        .ok_or(Error::AssetNotFound)?;
+    if !acts_for_insurer(&env, &policy, &insurer) {
+        return Err(Error::Unauthorized);
+    }
+
+    if claim.status != ClaimStatus::Approved {
+        return Err(Error::Unauthorized);
+    }
+
+    let schedule_key = DataKey::PayoutSchedule(claim_id.clone());
+    if store.has(&schedule_key) {
+        return Err(Error::AssetAlreadyExists);
+    }
+
+    store.set(
+        &schedule_key,
+        &ClaimPayoutSchedule {
+            num_installments,
+            interval_secs,
+            installments_paid: 0,
+            amount_paid: 0,
+            next_due: env.ledger().timestamp(),
+        },
+    );
+
+    log!(&env, "ClaimPayoutScheduled: {:?}", claim_id);
+    Ok(())
+}
+
+/// Pay the next due installment of a scheduled claim payout. Anyone may trigger it
+/// since funds only go to the claimant; the claim is marked Paid after the last one.
+pub fn pay_claim_installment(env: Env, claim_id: BytesN<32>) -> Result<i128, Error> {
+    with_claim_payout_lock(&env.clone(), &claim_id.clone(), || {
+        settle_claim_installment(env, claim_id)
+    })
+}
+
+fn settle_claim_installment(env: Env, claim_id: BytesN<32>) -> Result<i128, Error> {
+    let store = env.storage().persistent();
+    let claim_key = DataKey::Claim(claim_id.clone());
+    let schedule_key = DataKey::PayoutSchedule(claim_id.clone());
+
+    let mut claim: InsuranceClaim = store.get(&claim_key).ok_or(Error::AssetNotFound)?;
+    let mut schedule: ClaimPayoutSchedule = store.get(&schedule_key).ok_or(Error::AssetNotFound)?;
+
+    if claim.status != ClaimStatus::Approved {
+        return Err(Error::Unauthorized);
+    }
+    if env.ledger().timestamp() < schedule.next_due {
+        return Err(Error::PeriodNotElapsed);
+    }
+
+    let policy: InsurancePolicy = store
+        .get(&DataKey::Policy(claim.policy_id.clone()))
+        .ok_or(Error::AssetNotFound)?;
+
+    // Equal installments of the net payout, with any rounding remainder paid in
+    // the last one
+    let payout = claim_payout(&policy, claim.approved_amount);
+    schedule.installments_paid += 1;
+    let amount = if schedule.installments_paid == schedule.num_installments {
+        payout - schedule.amount_paid
+    } else {
+        payout / schedule.num_installments as i128
+    };
+    schedule.amount_paid += amount;
+    schedule.next_due = schedule.next_due.saturating_add(schedule.interval_secs);
+    store.set(&schedule_key, &schedule);
+
+    if schedule.installments_paid == schedule.num_installments {
+        claim.status = ClaimStatus::Paid;
+        claim.paid_amount = payout;
+        store.set(&claim_key, &claim);
+        // Installments can be triggered by anyone, so the contract is the actor
+        record_claim_status_change(
+            &env,
+            &claim_id,
+            Some(ClaimStatus::Approved),
+            ClaimStatus::Paid,
+            &env.current_contract_address(),
+        );
+    }
+
+    pay_from_pool(&env, &claim_id, &policy, &claim.claimant, amount)?;
+    adjust_total(&env, DataKey::TotalApprovedUnpaid, -amount)?;
+
+    log!(
+        &env,
+        "ClaimInstallmentPaid: {:?} {}/{}",
+        claim_id,
+        schedule.installments_paid,
+        schedule.num_installments
+    );
+    Ok(amount)
+}
+
+/// Get the installment plan for a claim, if one was scheduled
+pub fn get_claim_payout_schedule(env: Env, claim_id: BytesN<32>) -> Option<ClaimPayoutSchedule> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PayoutSchedule(claim_id))
+}
+
 /// Get a specific insurance claim by ID
 pub fn get_insurance_claim(env: Env, claim_id: BytesN<32>) -> Option<InsuranceClaim> {
     env.storage().persistent().get(&DataKey::Claim(claim_id))
 }
 
+/// Get a claim's status transitions in order as (timestamp, new status, actor)
+pub fn get_claim_timeline(env: Env, claim_id: BytesN<32>) -> Vec<(u64, ClaimStatus, Address)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ClaimTimeline(claim_id))
+        .unwrap_or_else(|| Vec::new(&env))
+}
+
 /// Get all claims for a specific asset
 pub fn get_asset_insurance_claims(env: Env, asset_id: BytesN<32>) -> Vec<BytesN<32>> {
     env.storage()
@@ -513,6 +1764,206 @@ pub fn get_asset_insurance_claims(env: Env, asset_id: BytesN<32>) -> Vec<BytesN<
         .unwrap_or_else(|| Vec::new(&env))
 }
 
+/// Get all claims filed against a specific policy, in filing order
+pub fn get_policy_claims(env: Env, policy_id: BytesN<32>) -> Vec<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PolicyClaims(policy_id))
+        .unwrap_or_else(|| Vec::new(&env))
+}
+
 pub fn get_policy(env: Env, policy_id: BytesN<32>) -> Option<InsurancePolicy> {
     env.storage().persistent().get(&DataKey::Policy(policy_id))
 }
+
+/// Get all policy IDs written by an insurer
+pub fn get_insurer_policies(env: Env, insurer: Address) -> Vec<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InsurerPolicies(insurer))
+        .unwrap_or_else(|| Vec::new(&env))
+}
+
+/// Headline figures for an insurer: (active policies, open claims, total paid out).
+///
+/// Policy and claim counts are computed by walking the insurer's indexes, so the
+/// cost grows with the number of policies and claims the insurer has written.
+/// Each walk is capped at `MAX_INSURER_SCAN` entries; the paid total is a running
+/// sum maintained by `pay_insurance_claim` and is always exact.
+pub fn insurer_summary(env: Env, insurer: Address) -> (u32, u32, i128) {
+    let store = env.storage().persistent();
+
+    let policy_ids = get_insurer_policies(env.clone(), insurer.clone());
+    let mut active_policies: u32 = 0;
+    for policy_id in policy_ids.iter().take(MAX_INSURER_SCAN as usize) {
+        if let Some(policy) = store.get::<_, InsurancePolicy>(&DataKey::Policy(policy_id)) {
+            if policy.status == PolicyStatus::Active {
+                active_policies += 1;
+            }
+        }
+    }
+
+    let claim_ids: Vec<BytesN<32>> = store
+        .get(&DataKey::InsurerClaims(insurer.clone()))
+        .unwrap_or_else(|| Vec::new(&env));
+    let mut open_claims: u32 = 0;
+    for claim_id in claim_ids.iter().take(MAX_INSURER_SCAN as usize) {
+        if let Some(claim) = store.get::<_, InsuranceClaim>(&DataKey::Claim(claim_id)) {
+            if claim.status != ClaimStatus::Paid && claim.status != ClaimStatus::Rejected {
+                open_claims += 1;
+            }
+        }
+    }
+
+    let paid_total: i128 = store.get(&DataKey::InsurerPaidTotal(insurer)).unwrap_or(0);
+
+    (active_policies, open_claims, paid_total)
+}
+
+/// Bring every one of an insurer's policies up to date in one sweep: expire those
+/// past `end_date` and auto-suspend those with lapsed premiums. Returns the
+/// (expired, suspended) counts. Permissionless like the per-policy calls.
+///
+/// Only the first `MAX_INSURER_SCAN` entries of the insurer's index are visited;
+/// policies beyond that must be reconciled individually with `expire_policy` and
+/// `check_premium_status`.
+pub fn reconcile_insurer_policies(env: Env, insurer: Address) -> (u32, u32) {
+    let store = env.storage().persistent();
+    let now = env.ledger().timestamp();
+
+    let mut expired: u32 = 0;
+    let mut suspended: u32 = 0;
+    let policy_ids = get_insurer_policies(env.clone(), insurer.clone());
+    for policy_id in policy_ids.iter().take(MAX_INSURER_SCAN as usize) {
+        let policy: InsurancePolicy = match store.get(&DataKey::Policy(policy_id.clone())) {
+            Some(p) => p,
+            None => continue,
+        };
+        if policy.status != PolicyStatus::Active && policy.status != PolicyStatus::Suspended {
+            continue;
+        }
+
+        if policy.end_date < now {
+            if expire_policy(env.clone(), policy_id).is_ok() {
+                expired += 1;
+            }
+        } else if policy.status == PolicyStatus::Active
+            && check_premium_status(env.clone(), policy_id) == Ok(PolicyStatus::Suspended)
+        {
+            suspended += 1;
+        }
+    }
+
+    env.events().publish(
+        ("insurance", "insurer_reconciled"),
+        (insurer, expired, suspended),
+    );
+
+    (expired, suspended)
+}
+
+/// Expire every Active or Suspended policy on an asset whose end date has passed,
+/// skipping the rest. Permissionless, like `expire_policy`; returns the count expired.
+pub fn expire_asset_policies(env: Env, asset_id: BytesN<32>) -> u32 {
+    let store = env.storage().persistent();
+    let now = env.ledger().timestamp();
+
+    let mut expired: u32 = 0;
+    let policy_ids = get_asset_policies(env.clone(), asset_id.clone());
+    for policy_id in policy_ids.iter().take(MAX_INSURER_SCAN as usize) {
+        let policy: InsurancePolicy = match store.get(&DataKey::Policy(policy_id.clone())) {
+            Some(p) => p,
+            None => continue,
+        };
+        if (policy.status == PolicyStatus::Active || policy.status == PolicyStatus::Suspended)
+            && policy.end_date < now
+            && expire_policy(env.clone(), policy_id).is_ok()
+        {
+            expired += 1;
+        }
+    }
+
+    env.events()
+        .publish(("insurance", "asset_policies_expired"), (asset_id, expired));
+
+    expired
+}
+
+/// Renew every Active auto-renew policy on an asset whose end date has passed:
+/// extend `end_date` by the policy's original term and charge the holder one
+/// premium into the pool. Policies without `auto_renew` are left to expire.
+/// Returns the count renewed.
+///
+/// Anyone may run the sweep. The premium is drawn against an allowance the holder
+/// has granted this contract on the premium token; a policy whose premium cannot
+/// be drawn is skipped and left to expire, without affecting the others.
+pub fn process_auto_renewals(env: Env, asset_id: BytesN<32>) -> Result<u32, Error> {
+    let store = env.storage().persistent();
+    let now = env.ledger().timestamp();
+
+    let mut renewed: u32 = 0;
+    let policy_ids = get_asset_policies(env.clone(), asset_id.clone());
+    for policy_id in policy_ids.iter().take(MAX_INSURER_SCAN as usize) {
+        let key = DataKey::Policy(policy_id.clone());
+        let mut policy: InsurancePolicy = match store.get(&key) {
+            Some(p) => p,
+            None => continue,
+        };
+        if !policy.auto_renew || policy.status != PolicyStatus::Active || policy.end_date >= now {
+            continue;
+        }
+
+        // The term is fixed at the first renewal so later ones don't compound it
+        let term_key = DataKey::PolicyTerm(policy_id.clone());
+        let term: u64 = store
+            .get(&term_key)
+            .unwrap_or(policy.end_date - policy.start_date);
+        store.set(&term_key, &term);
+
+        let contract = env.current_contract_address();
+        let paid = token::TokenClient::new(&env, &policy.premium_token).try_transfer_from(
+            &contract,
+            &policy.holder,
+            &contract,
+            &policy.premium,
+        );
+        if !matches!(paid, Ok(Ok(()))) {
+            continue;
+        }
+        credit_pool(&env, &policy.premium_token, policy.premium)?;
+
+        policy.end_date = policy.end_date.saturating_add(term);
+        policy.last_payment = now;
+        store.set(&key, &policy);
+
+        audit::append_audit_log(
+            &env,
+            &policy.asset_id,
+            String::from_str(&env, "INSURANCE_POLICY_AUTO_RENEWED"),
+            policy.holder.clone(),
+            String::from_str(&env, "Insurance policy auto-renewed"),
+        );
+        env.events().publish(
+            ("insurance", "policy_auto_renewed"),
+            (policy_id.clone(), policy.end_date, policy.premium),
+        );
+        log!(&env, "PolicyAutoRenewed: {:?}", policy_id);
+        renewed += 1;
+    }
+
+    env.events()
+        .publish(("insurance", "asset_policies_renewed"), (asset_id, renewed));
+
+    Ok(renewed)
+}
+
+/// Aggregate (active coverage, approved-but-unpaid claims) across all policies.
+/// Both figures are running totals maintained incrementally on every policy status
+/// change and claim approval, revision and payout, so this read is O(1).
+pub fn global_exposure(env: Env) -> (i128, i128) {
+    let store = env.storage().persistent();
+    (
+        store.get(&DataKey::TotalActiveCoverage).unwrap_or(0),
+        store.get(&DataKey::TotalApprovedUnpaid).unwrap_or(0),
+    )
+}
@@ -109,7 +109,7 @@ pub fn create_lease(
     if let Some(existing_id) = get_active_lease_id(env, &asset_id) {
         let existing = load_lease(env, &existing_id)?;
         if existing.status == LeaseStatus::Active {
-            return Err(Error::AssetAlreadyLeased);
+            return Err(Error::AssetEncumbered);
         }
     }
 
@@ -145,7 +145,7 @@ pub fn return_leased_asset(env: &Env, lease_id: BytesN<32>, caller: Address) ->
     }
 
     if lease.status != LeaseStatus::Active {
-        return Err(Error::InvalidLeaseStatus);
+        return Err(Error::InvalidStatusTransition);
     }
 
     lease.status = LeaseStatus::Returned;
@@ -168,11 +168,11 @@ pub fn cancel_lease(env: &Env, lease_id: BytesN<32>, caller: Address) -> Result<
     }
 
     if lease.status != LeaseStatus::Active {
-        return Err(Error::InvalidLeaseStatus);
+        return Err(Error::InvalidStatusTransition);
     }
 
     if env.ledger().timestamp() >= lease.start_timestamp {
-        return Err(Error::LeaseAlreadyStarted);
+        return Err(Error::InvalidStatusTransition);
     }
 
     lease.status = LeaseStatus::Cancelled;
@@ -191,11 +191,11 @@ pub fn expire_lease(env: &Env, lease_id: BytesN<32>) -> Result<(), Error> {
     let mut lease = load_lease(env, &lease_id)?;
 
     if lease.status != LeaseStatus::Active {
-        return Err(Error::InvalidLeaseStatus);
+        return Err(Error::InvalidStatusTransition);
     }
 
     if env.ledger().timestamp() <= lease.end_timestamp {
-        return Err(Error::LeaseNotExpired);
+        return Err(Error::PeriodNotElapsed);
     }
 
     lease.status = LeaseStatus::Expired;
@@ -3,7 +3,8 @@
 
 use crate::error::{handle_error, Error};
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, BytesN, Env, String, Vec,
+    contract, contractimpl, contracttype, log, symbol_short, Address, BytesN, Env, String, Symbol,
+    Vec,
 };
 
 pub(crate) mod asset;
@@ -12,8 +13,11 @@ pub(crate) mod branch;
 pub(crate) mod detokenization;
 pub(crate) mod dividends;
 pub(crate) mod error;
+pub(crate) mod escrow;
 pub(crate) mod insurance;
 pub(crate) mod lease;
+pub(crate) mod reentrancy;
+pub(crate) mod roles;
 pub(crate) mod tokenization;
 pub(crate) mod transfer_restrictions;
 pub(crate) mod types;
@@ -32,6 +36,7 @@ pub enum DataKey {
     TotalAssetCount,
     ContractMetadata,
     AuthorizedRegistrar(Address),
+    PendingAdmin,
     ScheduledTransfer(BytesN<32>),
     PendingApproval(BytesN<32>),
 }
@@ -79,7 +84,7 @@ impl AssetUpContract {
     pub fn get_admin(env: Env) -> Result<Address, Error> {
         let key = DataKey::Admin;
         if !env.storage().persistent().has(&key) {
-            handle_error(&env, Error::AdminNotFound)
+            handle_error(&env, Error::ContractNotInitialized)
         }
 
         let admin = env.storage().persistent().get(&key).unwrap();
@@ -111,6 +116,9 @@ impl AssetUpContract {
     }
 
     pub fn is_authorized_registrar(env: Env, address: Address) -> Result<bool, Error> {
+        if roles::has_role(&env, roles::REGISTRAR, address.clone()) {
+            return Ok(true);
+        }
         Ok(env
             .storage()
             .persistent()
@@ -121,9 +129,7 @@ impl AssetUpContract {
     // Asset functions
     pub fn register_asset(env: Env, asset: asset::Asset, caller: Address) -> Result<(), Error> {
         // Check if contract is paused
-        if Self::is_paused(env.clone())? {
-            return Err(Error::ContractPaused);
-        }
+        Self::when_not_paused(&env)?;
 
         // Check if caller is authorized registrar
         if !Self::is_authorized_registrar(env.clone(), caller.clone())? {
@@ -133,38 +139,108 @@ impl AssetUpContract {
         // Validate asset data
         Self::validate_asset(&env, &asset)?;
 
-        let key = asset::DataKey::Asset(asset.id.clone());
-        let store = env.storage().persistent();
-
         // Check if asset already exists
-        if store.has(&key) {
+        if env
+            .storage()
+            .persistent()
+            .has(&asset::DataKey::Asset(asset.id.clone()))
+        {
             return Err(Error::AssetAlreadyExists);
         }
 
+        Self::store_new_asset(&env, asset, &caller)?;
+        Ok(())
+    }
+
+    /// Register several assets in one call (authorized registrar only). Every asset
+    /// is validated and checked for an unused, unique id before any is stored, so a
+    /// single collision rejects the whole batch. `check_register_batch` returns the
+    /// offending id.
+    pub fn register_assets_batch(
+        env: Env,
+        assets: Vec<asset::Asset>,
+        registrar: Address,
+    ) -> Result<(), Error> {
+        registrar.require_auth();
+
+        Self::when_not_paused(&env)?;
+        if !Self::is_authorized_registrar(env.clone(), registrar.clone())? {
+            return Err(Error::Unauthorized);
+        }
+        if assets.is_empty() {
+            return Err(Error::InvalidAmount);
+        }
+
+        // Validate the whole batch before storing anything
+        if let Some((asset_id, err)) = Self::find_unregistrable_asset(&env, &assets) {
+            log!(&env, "asset cannot be registered: {}", asset_id);
+            return Err(err);
+        }
+
+        for asset in assets.iter() {
+            Self::store_new_asset(&env, asset, &registrar)?;
+        }
+
+        env.events().publish(
+            (symbol_short!("asset_brg"),),
+            (registrar, assets.len(), env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Return the id of the first asset in a batch that `register_assets_batch`
+    /// would reject, if any
+    pub fn check_register_batch(env: Env, assets: Vec<asset::Asset>) -> Option<BytesN<32>> {
+        Self::find_unregistrable_asset(&env, &assets).map(|(asset_id, _)| asset_id)
+    }
+
+    /// Find the first asset in a batch that cannot be registered, with the reason:
+    /// invalid fields, or an id already registered or repeated within the batch
+    fn find_unregistrable_asset(
+        env: &Env,
+        assets: &Vec<asset::Asset>,
+    ) -> Option<(BytesN<32>, Error)> {
+        let store = env.storage().persistent();
+        let mut seen: Vec<BytesN<32>> = Vec::new(env);
+        for asset in assets.iter() {
+            if let Err(err) = Self::validate_asset(env, &asset) {
+                return Some((asset.id, err));
+            }
+            if seen.contains(&asset.id) || store.has(&asset::DataKey::Asset(asset.id.clone())) {
+                return Some((asset.id, Error::AssetAlreadyExists));
+            }
+            seen.push_back(asset.id);
+        }
+        None
+    }
+
+    /// Store a validated, unused asset and index it under its owner
+    fn store_new_asset(env: &Env, asset: asset::Asset, caller: &Address) -> Result<(), Error> {
+        let store = env.storage().persistent();
+
         // Store asset
-        store.set(&key, &asset);
+        store.set(&asset::DataKey::Asset(asset.id.clone()), &asset);
 
         // Update owner registry
         let owner_key = asset::DataKey::OwnerRegistry(asset.owner.clone());
         let mut owner_assets: Vec<BytesN<32>> =
-            store.get(&owner_key).unwrap_or_else(|| Vec::new(&env));
+            store.get(&owner_key).unwrap_or_else(|| Vec::new(env));
         owner_assets.push_back(asset.id.clone());
         store.set(&owner_key, &owner_assets);
 
         // Update total asset count
         let mut total_count = Self::get_total_asset_count(env.clone())?;
         total_count += 1;
-        env.storage()
-            .persistent()
-            .set(&DataKey::TotalAssetCount, &total_count);
+        store.set(&DataKey::TotalAssetCount, &total_count);
 
         // Append audit log
         audit::append_audit_log(
-            &env,
+            env,
             &asset.id,
-            String::from_str(&env, "ASSET_REGISTERED"),
+            String::from_str(env, "ASSET_REGISTERED"),
             caller.clone(),
-            String::from_str(&env, "Asset registered by authorized registrar"),
+            String::from_str(env, "Asset registered by authorized registrar"),
         );
 
         // Emit event
@@ -176,6 +252,14 @@ impl AssetUpContract {
         Ok(())
     }
 
+    /// Circuit breaker for state-changing entrypoints; getters are not guarded
+    fn when_not_paused(env: &Env) -> Result<(), Error> {
+        if Self::is_paused(env.clone())? {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+
     fn validate_asset(env: &Env, asset: &asset::Asset) -> Result<(), Error> {
         // Validate asset name length (3-100 characters)
         if asset.name.len() < 3 || asset.name.len() > 100 {
@@ -221,9 +305,7 @@ impl AssetUpContract {
         caller: Address,
     ) -> Result<(), Error> {
         // Check if contract is paused
-        if Self::is_paused(env.clone())? {
-            return Err(Error::ContractPaused);
-        }
+        Self::when_not_paused(&env)?;
 
         let key = asset::DataKey::Asset(asset_id.clone());
         let store = env.storage().persistent();
@@ -275,45 +357,338 @@ impl AssetUpContract {
         Ok(())
     }
 
+    /// Get a single custom attribute of an asset by key
+    pub fn get_asset_attribute(
+        env: Env,
+        asset_id: BytesN<32>,
+        key: String,
+    ) -> Result<Option<String>, Error> {
+        let asset = Self::get_asset(env, asset_id)?;
+        Ok(asset
+            .custom_attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value))
+    }
+
+    /// Set a single custom attribute, overwriting any existing value for the key
+    /// (owner only)
+    pub fn set_asset_attribute(
+        env: Env,
+        asset_id: BytesN<32>,
+        key: String,
+        value: String,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        Self::when_not_paused(&env)?;
+
+        let mut asset = Self::get_asset(env.clone(), asset_id.clone())?;
+        if caller != asset.owner {
+            return Err(Error::Unauthorized);
+        }
+
+        let attribute = types::CustomAttribute {
+            key: key.clone(),
+            value,
+        };
+        match asset.custom_attributes.iter().position(|a| a.key == key) {
+            Some(index) => asset.custom_attributes.set(index as u32, attribute),
+            None => asset.custom_attributes.push_back(attribute),
+        }
+        env.storage()
+            .persistent()
+            .set(&asset::DataKey::Asset(asset_id.clone()), &asset);
+
+        audit::append_audit_log(
+            &env,
+            &asset_id,
+            String::from_str(&env, "ATTRIBUTE_UPDATED"),
+            caller.clone(),
+            key.clone(),
+        );
+
+        env.events()
+            .publish((symbol_short!("attr_set"),), (asset_id, key, caller));
+
+        Ok(())
+    }
+
+    /// Move an asset to a new location (owner or authorized registrar). The previous
+    /// locations are kept in the asset's location history.
+    pub fn update_asset_location(
+        env: Env,
+        asset_id: BytesN<32>,
+        location: String,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        Self::when_not_paused(&env)?;
+
+        let mut asset = Self::get_asset(env.clone(), asset_id.clone())?;
+        if caller != asset.owner && !Self::is_authorized_registrar(env.clone(), caller.clone())? {
+            return Err(Error::Unauthorized);
+        }
+
+        let store = env.storage().persistent();
+        asset.location = location.clone();
+        store.set(&asset::DataKey::Asset(asset_id.clone()), &asset);
+
+        let mut history = Self::get_location_history(env.clone(), asset_id.clone());
+        history.push_back(asset::LocationEntry {
+            location: location.clone(),
+            moved_by: caller.clone(),
+            moved_at: env.ledger().timestamp(),
+        });
+        store.set(&asset::DataKey::LocationHistory(asset_id.clone()), &history);
+
+        audit::append_audit_log(
+            &env,
+            &asset_id,
+            String::from_str(&env, "LOCATION_UPDATED"),
+            caller,
+            location.clone(),
+        );
+
+        env.events()
+            .publish((symbol_short!("asset_loc"),), (asset_id, location));
+
+        Ok(())
+    }
+
+    /// Get every recorded location of an asset, oldest first
+    pub fn get_location_history(env: Env, asset_id: BytesN<32>) -> Vec<asset::LocationEntry> {
+        env.storage()
+            .persistent()
+            .get(&asset::DataKey::LocationHistory(asset_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Record a servicing event against an asset (owner or authorized registrar)
+    pub fn add_maintenance_record(
+        env: Env,
+        asset_id: BytesN<32>,
+        record: asset::MaintenanceRecord,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        Self::when_not_paused(&env)?;
+
+        if record.cost < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let asset = Self::get_asset(env.clone(), asset_id.clone())?;
+        if caller != asset.owner && !Self::is_authorized_registrar(env.clone(), caller.clone())? {
+            return Err(Error::Unauthorized);
+        }
+
+        let mut records = Self::get_maintenance_records(env.clone(), asset_id.clone());
+        records.push_back(record.clone());
+        env.storage()
+            .persistent()
+            .set(&asset::DataKey::Maintenance(asset_id.clone()), &records);
+
+        audit::append_audit_log(
+            &env,
+            &asset_id,
+            String::from_str(&env, "MAINTENANCE_RECORDED"),
+            caller,
+            record.description,
+        );
+
+        env.events()
+            .publish((symbol_short!("asset_mnt"),), (asset_id, record.cost));
+
+        Ok(())
+    }
+
+    /// Get every maintenance record of an asset, oldest first
+    pub fn get_maintenance_records(
+        env: Env,
+        asset_id: BytesN<32>,
+    ) -> Vec<asset::MaintenanceRecord> {
+        env.storage()
+            .persistent()
+            .get(&asset::DataKey::Maintenance(asset_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Sum of the cost of every maintenance record of an asset
+    pub fn total_maintenance_cost(env: Env, asset_id: BytesN<32>) -> Result<i128, Error> {
+        let mut total: i128 = 0;
+        for record in Self::get_maintenance_records(env, asset_id).iter() {
+            total = total
+                .checked_add(record.cost)
+                .ok_or(Error::ArithmeticOverflow)?;
+        }
+        Ok(total)
+    }
+
+    /// Transfer a registered asset to a new owner. With `cascade` set, every linked
+    /// descendant moves with it, or nothing moves at all.
     pub fn transfer_asset_ownership(
         env: Env,
         asset_id: BytesN<32>,
         new_owner: Address,
         caller: Address,
+        cascade: bool,
     ) -> Result<(), Error> {
+        caller.require_auth();
+
         // Check if contract is paused
-        if Self::is_paused(env.clone())? {
-            return Err(Error::ContractPaused);
+        Self::when_not_paused(&env)?;
+
+        Self::validate_new_owner(&env, &new_owner)?;
+        let asset = Self::transferable_asset(&env, &asset_id, &caller)?;
+
+        // With cascade set, every descendant must be transferable before anything moves
+        let mut descendants: Vec<asset::Asset> = Vec::new(&env);
+        if cascade {
+            let mut pending = Self::get_child_assets(env.clone(), asset_id.clone());
+            while let Some(child_id) = pending.pop_front() {
+                descendants.push_back(Self::transferable_asset(&env, &child_id, &caller)?);
+                pending.append(&Self::get_child_assets(env.clone(), child_id));
+            }
+        }
+
+        let old_owner = Self::move_asset_ownership(&env, asset, &new_owner, &caller);
+        for child in descendants.iter() {
+            Self::move_asset_ownership(&env, child, &new_owner, &caller);
+        }
+
+        // Emit event
+        env.events().publish(
+            (symbol_short!("asset_tx"),),
+            (asset_id, old_owner, new_owner, env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Transfer several registered assets to one new owner. Every asset must be owned
+    /// by the caller; otherwise nothing is transferred. `check_transfer_batch`
+    /// returns the offending id.
+    pub fn transfer_assets_batch(
+        env: Env,
+        asset_ids: Vec<BytesN<32>>,
+        new_owner: Address,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        Self::when_not_paused(&env)?;
+        if asset_ids.is_empty() {
+            return Err(Error::InvalidAmount);
+        }
+
+        Self::validate_new_owner(&env, &new_owner)?;
+
+        // Validate the whole batch before moving anything
+        if let Some((asset_id, err)) = Self::find_untransferable_asset(&env, &asset_ids, &caller) {
+            log!(&env, "asset cannot be transferred by caller: {}", asset_id);
+            return Err(err);
+        }
+
+        for asset_id in asset_ids.iter() {
+            let asset = Self::transferable_asset(&env, &asset_id, &caller)?;
+            Self::move_asset_ownership(&env, asset, &new_owner, &caller);
+        }
+
+        env.events().publish(
+            (symbol_short!("asset_btx"),),
+            (caller, new_owner, asset_ids.len(), env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Return the first asset id in a batch that `transfer_assets_batch` would reject
+    /// for `caller`, if any
+    pub fn check_transfer_batch(
+        env: Env,
+        asset_ids: Vec<BytesN<32>>,
+        caller: Address,
+    ) -> Option<BytesN<32>> {
+        Self::find_untransferable_asset(&env, &asset_ids, &caller).map(|(asset_id, _)| asset_id)
+    }
+
+    /// Find the first asset in a batch the caller cannot transfer, with the reason:
+    /// a repeated id, an unknown asset, or one the caller does not own
+    fn find_untransferable_asset(
+        env: &Env,
+        asset_ids: &Vec<BytesN<32>>,
+        caller: &Address,
+    ) -> Option<(BytesN<32>, Error)> {
+        let mut seen: Vec<BytesN<32>> = Vec::new(env);
+        for asset_id in asset_ids.iter() {
+            if seen.contains(&asset_id) {
+                return Some((asset_id, Error::InvalidAmount));
+            }
+            if let Err(err) = Self::transferable_asset(env, &asset_id, caller) {
+                return Some((asset_id, err));
+            }
+            seen.push_back(asset_id);
         }
+        None
+    }
 
+    fn validate_new_owner(env: &Env, new_owner: &Address) -> Result<(), Error> {
         // Validate new owner is not zero address
         let zero_address = Address::from_str(
-            &env,
+            env,
             "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
         );
-        if new_owner == zero_address {
+        if *new_owner == zero_address {
             return Err(Error::InvalidOwnerAddress);
         }
+        Ok(())
+    }
 
-        let key = asset::DataKey::Asset(asset_id.clone());
-        let store = env.storage().persistent();
-
-        let mut asset = match store.get::<_, asset::Asset>(&key) {
-            Some(a) => a,
-            None => return Err(Error::AssetNotFound),
-        };
+    /// Load an asset the caller may transfer: caller-owned and not tokenized
+    fn transferable_asset(
+        env: &Env,
+        asset_id: &BytesN<32>,
+        caller: &Address,
+    ) -> Result<asset::Asset, Error> {
+        let asset = env
+            .storage()
+            .persistent()
+            .get::<_, asset::Asset>(&asset::DataKey::Asset(asset_id.clone()))
+            .ok_or(Error::AssetNotFound)?;
 
         // Only current asset owner can transfer ownership
-        if caller != asset.owner {
+        if *caller != asset.owner {
             return Err(Error::Unauthorized);
         }
 
+        // Tokenized assets are owned through their tokens, and suspended or
+        // retired assets cannot change hands
+        Self::check_status_transition(&asset.status, &AssetStatus::Transferred)?;
+
+        // Collateral stays put until every lien is released
+        if !Self::get_liens(env.clone(), asset_id.clone()).is_empty() {
+            return Err(Error::AssetEncumbered);
+        }
+
+        Ok(asset)
+    }
+
+    /// Reassign a validated asset, update both owner registries and audit the move.
+    /// Returns the previous owner.
+    fn move_asset_ownership(
+        env: &Env,
+        mut asset: asset::Asset,
+        new_owner: &Address,
+        caller: &Address,
+    ) -> Address {
+        let store = env.storage().persistent();
+        let asset_id = asset.id.clone();
         let old_owner = asset.owner.clone();
 
         // Remove asset from old owner's registry
         let old_owner_key = asset::DataKey::OwnerRegistry(old_owner.clone());
         let mut old_owner_assets: Vec<BytesN<32>> =
-            store.get(&old_owner_key).unwrap_or_else(|| Vec::new(&env));
+            store.get(&old_owner_key).unwrap_or_else(|| Vec::new(env));
         if let Some(index) = old_owner_assets.iter().position(|x| x == asset_id) {
             old_owner_assets.remove(index as u32);
         }
@@ -322,7 +697,7 @@ impl AssetUpContract {
         // Add asset to new owner's registry
         let new_owner_key = asset::DataKey::OwnerRegistry(new_owner.clone());
         let mut new_owner_assets: Vec<BytesN<32>> =
-            store.get(&new_owner_key).unwrap_or_else(|| Vec::new(&env));
+            store.get(&new_owner_key).unwrap_or_else(|| Vec::new(env));
         new_owner_assets.push_back(asset_id.clone());
         store.set(&new_owner_key, &new_owner_assets);
 
@@ -330,31 +705,35 @@ impl AssetUpContract {
         asset.owner = new_owner.clone();
         asset.last_transfer_timestamp = env.ledger().timestamp();
         asset.status = AssetStatus::Transferred;
-        store.set(&key, &asset);
+        store.set(&asset::DataKey::Asset(asset_id.clone()), &asset);
 
         // Append audit log
         audit::append_audit_log(
-            &env,
+            env,
             &asset_id,
-            String::from_str(&env, "OWNERSHIP_TRANSFERRED"),
+            String::from_str(env, "OWNERSHIP_TRANSFERRED"),
             caller.clone(),
-            String::from_str(&env, "Asset ownership transferred to new owner"),
-        );
-
-        // Emit event
-        env.events().publish(
-            (symbol_short!("asset_tx"),),
-            (asset_id, old_owner, new_owner, env.ledger().timestamp()),
+            String::from_str(env, "Asset ownership transferred to new owner"),
         );
 
-        Ok(())
+        old_owner
     }
 
     pub fn retire_asset(env: Env, asset_id: BytesN<32>, caller: Address) -> Result<(), Error> {
+        Self::transition_asset_status(env, asset_id, AssetStatus::Retired, caller)
+    }
+
+    /// Move an asset to `Active`, `Suspended` or `Retired` (owner or admin).
+    /// Allowed moves are defined by `check_status_transition`; `Transferred` and
+    /// `Tokenized` are only entered through ownership transfer and tokenization.
+    pub fn transition_asset_status(
+        env: Env,
+        asset_id: BytesN<32>,
+        new_status: AssetStatus,
+        caller: Address,
+    ) -> Result<(), Error> {
         // Check if contract is paused
-        if Self::is_paused(env.clone())? {
-            return Err(Error::ContractPaused);
-        }
+        Self::when_not_paused(&env)?;
 
         let key = asset::DataKey::Asset(asset_id.clone());
         let store = env.storage().persistent();
@@ -364,61 +743,245 @@ impl AssetUpContract {
             None => return Err(Error::AssetNotFound),
         };
 
-        // Only asset owner or admin can retire asset
+        // Only asset owner or admin can change the status
         let admin = Self::get_admin(env.clone())?;
         if caller != asset.owner && caller != admin {
             return Err(Error::Unauthorized);
         }
 
-        asset.status = AssetStatus::Retired;
+        let (action, details) = match new_status {
+            AssetStatus::Retired => ("ASSET_RETIRED", "Asset retired from active use"),
+            AssetStatus::Suspended => ("ASSET_SUSPENDED", "Asset suspended from use"),
+            AssetStatus::Active => ("ASSET_REACTIVATED", "Asset returned to active use"),
+            AssetStatus::Transferred | AssetStatus::Tokenized => {
+                return Err(Error::InvalidStatusTransition)
+            }
+        };
+        Self::check_status_transition(&asset.status, &new_status)?;
+
+        let old_status = asset.status.clone();
+        asset.status = new_status.clone();
         store.set(&key, &asset);
 
         // Append audit log
         audit::append_audit_log(
             &env,
             &asset_id,
-            String::from_str(&env, "ASSET_RETIRED"),
+            String::from_str(&env, action),
             caller.clone(),
-            String::from_str(&env, "Asset retired from active use"),
+            String::from_str(&env, details),
         );
 
         // Emit event
         env.events().publish(
-            (symbol_short!("asset_ret"),),
-            (asset_id, caller, env.ledger().timestamp()),
+            (symbol_short!("asset_st"),),
+            (asset_id, old_status, new_status, caller),
         );
 
         Ok(())
     }
 
-    pub fn get_asset(env: Env, asset_id: BytesN<32>) -> Result<asset::Asset, Error> {
-        let key = asset::DataKey::Asset(asset_id);
-        let store = env.storage().persistent();
-        match store.get::<_, asset::Asset>(&key) {
-            Some(a) => Ok(a),
-            None => Err(Error::AssetNotFound),
+    /// The asset lifecycle in one place. Tokenized assets must be detokenized
+    /// first, and retirement is final.
+    fn check_status_transition(from: &AssetStatus, to: &AssetStatus) -> Result<(), Error> {
+        if *from == AssetStatus::Tokenized {
+            return Err(Error::AssetAlreadyTokenized);
         }
-    }
-
-    pub fn get_assets_by_owner(env: Env, owner: Address) -> Result<Vec<BytesN<32>>, Error> {
-        let key = asset::DataKey::OwnerRegistry(owner);
-        let store = env.storage().persistent();
-        match store.get(&key) {
-            Some(assets) => Ok(assets),
-            None => Ok(Vec::new(&env)),
+        let allowed = matches!(
+            (from, to),
+            (
+                AssetStatus::Active | AssetStatus::Transferred,
+                AssetStatus::Transferred | AssetStatus::Suspended | AssetStatus::Retired
+            ) | (
+                AssetStatus::Suspended,
+                AssetStatus::Active | AssetStatus::Retired
+            )
+        );
+        if !allowed {
+            return Err(Error::InvalidStatusTransition);
         }
+        Ok(())
     }
 
-    pub fn check_asset_exists(env: Env, asset_id: BytesN<32>) -> Result<bool, Error> {
-        let key = asset::DataKey::Asset(asset_id);
-        let store = env.storage().persistent();
-        Ok(store.has(&key))
-    }
+    /// Record a lien on an asset in favour of `lienholder` (owner only). A second
+    /// lien from the same lienholder adds to the secured amount.
+    pub fn place_lien(
+        env: Env,
+        asset_id: BytesN<32>,
+        lienholder: Address,
+        amount: i128,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        Self::when_not_paused(&env)?;
 
-    pub fn get_asset_info(env: Env, asset_id: BytesN<32>) -> Result<asset::AssetInfo, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
         let asset = Self::get_asset(env.clone(), asset_id.clone())?;
-        Ok(asset::AssetInfo {
-            id: asset.id,
+        if caller != asset.owner {
+            return Err(Error::Unauthorized);
+        }
+
+        let mut liens = Self::get_liens(env.clone(), asset_id.clone());
+        match liens.iter().position(|lien| lien.lienholder == lienholder) {
+            Some(index) => {
+                let mut lien = liens.get(index as u32).unwrap();
+                lien.amount = lien
+                    .amount
+                    .checked_add(amount)
+                    .ok_or(Error::ArithmeticOverflow)?;
+                liens.set(index as u32, lien);
+            }
+            None => liens.push_back(asset::Lien {
+                lienholder: lienholder.clone(),
+                amount,
+                placed_at: env.ledger().timestamp(),
+            }),
+        }
+        env.storage()
+            .persistent()
+            .set(&asset::DataKey::Liens(asset_id.clone()), &liens);
+
+        audit::append_audit_log(
+            &env,
+            &asset_id,
+            String::from_str(&env, "LIEN_PLACED"),
+            caller,
+            String::from_str(&env, "Lien placed on asset"),
+        );
+
+        env.events()
+            .publish((symbol_short!("lien_add"),), (asset_id, lienholder, amount));
+
+        Ok(())
+    }
+
+    /// Release a lien (lienholder only)
+    pub fn release_lien(env: Env, asset_id: BytesN<32>, lienholder: Address) -> Result<(), Error> {
+        lienholder.require_auth();
+
+        let mut liens = Self::get_liens(env.clone(), asset_id.clone());
+        let index = liens
+            .iter()
+            .position(|lien| lien.lienholder == lienholder)
+            .ok_or(Error::Unauthorized)?;
+        liens.remove(index as u32);
+
+        let key = asset::DataKey::Liens(asset_id.clone());
+        if liens.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &liens);
+        }
+
+        audit::append_audit_log(
+            &env,
+            &asset_id,
+            String::from_str(&env, "LIEN_RELEASED"),
+            lienholder.clone(),
+            String::from_str(&env, "Lien released from asset"),
+        );
+
+        env.events()
+            .publish((symbol_short!("lien_rel"),), (asset_id, lienholder));
+
+        Ok(())
+    }
+
+    /// Attach `child_id` as a component of `parent_id`. The caller must own both
+    /// assets, a child has at most one parent, and no asset may become its own ancestor.
+    pub fn link_child_asset(
+        env: Env,
+        parent_id: BytesN<32>,
+        child_id: BytesN<32>,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        Self::when_not_paused(&env)?;
+
+        let parent = Self::get_asset(env.clone(), parent_id.clone())?;
+        let child = Self::get_asset(env.clone(), child_id.clone())?;
+        if caller != parent.owner || caller != child.owner {
+            return Err(Error::Unauthorized);
+        }
+
+        let store = env.storage().persistent();
+        if parent_id == child_id || store.has(&asset::DataKey::Parent(child_id.clone())) {
+            return Err(Error::InvalidHierarchy);
+        }
+        // Walk up from the parent; meeting the child would close a cycle
+        let mut ancestor = store.get::<_, BytesN<32>>(&asset::DataKey::Parent(parent_id.clone()));
+        while let Some(id) = ancestor {
+            if id == child_id {
+                return Err(Error::InvalidHierarchy);
+            }
+            ancestor = store.get(&asset::DataKey::Parent(id));
+        }
+
+        let mut children = Self::get_child_assets(env.clone(), parent_id.clone());
+        children.push_back(child_id.clone());
+        store.set(&asset::DataKey::Children(parent_id.clone()), &children);
+        store.set(&asset::DataKey::Parent(child_id.clone()), &parent_id);
+
+        audit::append_audit_log(
+            &env,
+            &parent_id,
+            String::from_str(&env, "CHILD_LINKED"),
+            caller,
+            String::from_str(&env, "Child asset linked"),
+        );
+
+        env.events()
+            .publish((symbol_short!("asset_lnk"),), (parent_id, child_id));
+
+        Ok(())
+    }
+
+    /// Get the direct children of a composite asset
+    pub fn get_child_assets(env: Env, parent_id: BytesN<32>) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&asset::DataKey::Children(parent_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get the active liens on an asset
+    pub fn get_liens(env: Env, asset_id: BytesN<32>) -> Vec<asset::Lien> {
+        env.storage()
+            .persistent()
+            .get(&asset::DataKey::Liens(asset_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    pub fn get_asset(env: Env, asset_id: BytesN<32>) -> Result<asset::Asset, Error> {
+        let key = asset::DataKey::Asset(asset_id);
+        let store = env.storage().persistent();
+        match store.get::<_, asset::Asset>(&key) {
+            Some(a) => Ok(a),
+            None => Err(Error::AssetNotFound),
+        }
+    }
+
+    pub fn get_assets_by_owner(env: Env, owner: Address) -> Result<Vec<BytesN<32>>, Error> {
+        let key = asset::DataKey::OwnerRegistry(owner);
+        let store = env.storage().persistent();
+        match store.get(&key) {
+            Some(assets) => Ok(assets),
+            None => Ok(Vec::new(&env)),
+        }
+    }
+
+    pub fn check_asset_exists(env: Env, asset_id: BytesN<32>) -> Result<bool, Error> {
+        let key = asset::DataKey::Asset(asset_id);
+        let store = env.storage().persistent();
+        Ok(store.has(&key))
+    }
+
+    pub fn get_asset_info(env: Env, asset_id: BytesN<32>) -> Result<asset::AssetInfo, Error> {
+        let asset = Self::get_asset(env.clone(), asset_id.clone())?;
+        Ok(asset::AssetInfo {
+            id: asset.id,
             name: asset.name,
             category: asset.category,
             owner: asset.owner,
@@ -440,12 +1003,97 @@ impl AssetUpContract {
         Ok(results)
     }
 
+    /// Set a depreciation schedule for an asset (owner or admin)
+    pub fn set_depreciation(
+        env: Env,
+        asset_id: BytesN<32>,
+        method: asset::DepreciationMethod,
+        useful_life_secs: u64,
+        salvage_value: i128,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        Self::when_not_paused(&env)?;
+
+        let asset = Self::get_asset(env.clone(), asset_id.clone())?;
+        let admin = Self::get_admin(env.clone())?;
+        if caller != asset.owner && caller != admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if useful_life_secs == 0 {
+            return Err(Error::InvalidTimestamps);
+        }
+        if salvage_value < 0 || salvage_value > asset.purchase_value {
+            return Err(Error::InvalidPurchaseValue);
+        }
+
+        env.storage().persistent().set(
+            &asset::DataKey::Depreciation(asset_id.clone()),
+            &asset::DepreciationSchedule {
+                method,
+                useful_life_secs,
+                salvage_value,
+            },
+        );
+
+        env.events().publish(
+            (symbol_short!("depr_set"),),
+            (asset_id, useful_life_secs, salvage_value),
+        );
+
+        Ok(())
+    }
+
+    /// Get an asset's depreciation schedule, if one is set
+    pub fn get_depreciation(env: Env, asset_id: BytesN<32>) -> Option<asset::DepreciationSchedule> {
+        env.storage()
+            .persistent()
+            .get(&asset::DataKey::Depreciation(asset_id))
+    }
+
+    /// Current book value: the purchase value less depreciation since
+    /// registration, floored at the salvage value. Assets without a schedule
+    /// keep their purchase value.
+    pub fn current_book_value(env: Env, asset_id: BytesN<32>) -> Result<i128, Error> {
+        let asset = Self::get_asset(env.clone(), asset_id.clone())?;
+        let schedule = match Self::get_depreciation(env.clone(), asset_id) {
+            Some(schedule) => schedule,
+            None => return Ok(asset.purchase_value),
+        };
+
+        let elapsed = env
+            .ledger()
+            .timestamp()
+            .saturating_sub(asset.registration_timestamp);
+        if elapsed >= schedule.useful_life_secs {
+            return Ok(schedule.salvage_value);
+        }
+
+        match schedule.method {
+            asset::DepreciationMethod::StraightLine => {
+                let depreciable = asset.purchase_value - schedule.salvage_value;
+                let depreciated = depreciable
+                    .checked_mul(elapsed as i128)
+                    .ok_or(Error::ArithmeticOverflow)?
+                    / schedule.useful_life_secs as i128;
+                Ok((asset.purchase_value - depreciated).max(schedule.salvage_value))
+            }
+        }
+    }
+
     // Admin functions
-    pub fn update_admin(env: Env, new_admin: Address) -> Result<(), Error> {
-        let current_admin = Self::get_admin(env.clone())?;
+    /// Propose a new admin; the change only takes effect once they accept
+    pub fn propose_new_admin(
+        env: Env,
+        current_admin: Address,
+        new_admin: Address,
+    ) -> Result<(), Error> {
         current_admin.require_auth();
+        if current_admin != Self::get_admin(env.clone())? {
+            return Err(Error::Unauthorized);
+        }
 
-        // Validate new admin is not zero address
         let zero_address = Address::from_str(
             &env,
             "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
@@ -454,8 +1102,41 @@ impl AssetUpContract {
             return Err(Error::InvalidOwnerAddress);
         }
 
-        let old_admin = current_admin.clone();
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingAdmin, &new_admin);
+
+        env.events().publish(
+            (symbol_short!("admin_prp"),),
+            (current_admin, new_admin, env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Accept a pending admin proposal (only the proposed address can call)
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        new_admin.require_auth();
+
+        let pending: Option<Address> = env.storage().persistent().get(&DataKey::PendingAdmin);
+        if pending != Some(new_admin.clone()) {
+            return Err(Error::Unauthorized);
+        }
+
+        let old_admin = Self::get_admin(env.clone())?;
+        Self::set_admin(&env, old_admin, new_admin);
+        Ok(())
+    }
+
+    /// Get the admin awaiting acceptance, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::PendingAdmin)
+    }
+
+    fn set_admin(env: &Env, old_admin: Address, new_admin: Address) {
         env.storage().persistent().set(&DataKey::Admin, &new_admin);
+        // Any outstanding proposal is void once the admin changes
+        env.storage().persistent().remove(&DataKey::PendingAdmin);
 
         // Remove old admin from authorized registrars and add new admin
         env.storage()
@@ -470,8 +1151,6 @@ impl AssetUpContract {
             (symbol_short!("admin_chg"),),
             (old_admin, new_admin, env.ledger().timestamp()),
         );
-
-        Ok(())
     }
 
     pub fn add_authorized_registrar(env: Env, registrar: Address) -> Result<(), Error> {
@@ -499,6 +1178,76 @@ impl AssetUpContract {
         Ok(())
     }
 
+    // =====================
+    // Roles
+    // =====================
+
+    /// Grant a contract-wide role such as `REGISTRAR` to an address (admin only)
+    pub fn grant_role(
+        env: Env,
+        role: Symbol,
+        address: Address,
+        admin: Address,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        roles::grant_role(&env, role, address, admin)
+    }
+
+    /// Revoke a role from an address (admin only)
+    pub fn revoke_role(
+        env: Env,
+        role: Symbol,
+        address: Address,
+        admin: Address,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        roles::revoke_role(&env, role, address, admin)
+    }
+
+    /// Check if an address holds a role
+    pub fn has_role(env: Env, role: Symbol, address: Address) -> bool {
+        roles::has_role(&env, role, address)
+    }
+
+    /// Grant a role within a scope: `MINTER` or `OPERATOR` on an asset (its
+    /// tokenizer only), or `INSURER` for an insurer (that insurer only)
+    pub fn grant_scoped_role(
+        env: Env,
+        role: Symbol,
+        scope: roles::RoleScope,
+        address: Address,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        roles::grant_scoped_role(&env, role, scope, address, caller)
+    }
+
+    /// Revoke a role within a scope (only the scope's owner can call)
+    pub fn revoke_scoped_role(
+        env: Env,
+        role: Symbol,
+        scope: roles::RoleScope,
+        address: Address,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        roles::revoke_scoped_role(&env, role, scope, address, caller)
+    }
+
+    /// Check if an address holds a role within a scope
+    pub fn has_scoped_role(
+        env: Env,
+        role: Symbol,
+        scope: roles::RoleScope,
+        address: Address,
+    ) -> bool {
+        roles::has_scoped_role(&env, role, scope, address)
+    }
+
+    /// Pause the contract (admin only). This is the circuit breaker checked by
+    /// `when_not_paused`: state-changing entrypoints, including claim payouts,
+    /// escrows, dividends and allowances, fail with `ContractPaused` until
+    /// `unpause_contract` is called.
     pub fn pause_contract(env: Env) -> Result<(), Error> {
         let admin = Self::get_admin(env.clone())?;
         admin.require_auth();
@@ -514,6 +1263,7 @@ impl AssetUpContract {
         Ok(())
     }
 
+    /// Lift a pause set by `pause_contract` (admin only)
     pub fn unpause_contract(env: Env) -> Result<(), Error> {
         let admin = Self::get_admin(env.clone())?;
         admin.require_auth();
@@ -536,6 +1286,68 @@ impl AssetUpContract {
         Ok(audit::get_asset_log(&env, &asset_id))
     }
 
+    /// Get a page of an asset's audit log, at most 50 entries
+    pub fn get_asset_log_paged(
+        env: Env,
+        asset_id: BytesN<32>,
+        start: u32,
+        limit: u32,
+    ) -> Vec<audit::AuditEntry> {
+        audit::get_asset_log_paged(&env, &asset_id, start, limit)
+    }
+
+    /// Get every audit entry recorded by one actor on an asset
+    pub fn get_actor_audit_log(
+        env: Env,
+        asset_id: BytesN<32>,
+        actor: Address,
+    ) -> Vec<audit::AuditEntry> {
+        audit::get_actor_audit_log(&env, &asset_id, &actor)
+    }
+
+    /// Get the number of entries in an asset's audit log
+    pub fn get_asset_log_count(env: Env, asset_id: BytesN<32>) -> u32 {
+        audit::get_asset_log_count(&env, &asset_id)
+    }
+
+    /// Get an asset's audit log entries filtered by action and inclusive time range
+    pub fn get_asset_log_filtered(
+        env: Env,
+        asset_id: BytesN<32>,
+        action: Option<String>,
+        from_ts: Option<u64>,
+        to_ts: Option<u64>,
+    ) -> Vec<audit::AuditEntry> {
+        audit::get_asset_log_filtered(&env, &asset_id, action, from_ts, to_ts)
+    }
+
+    pub fn get_audit_entry(
+        env: Env,
+        asset_id: BytesN<32>,
+        index: u32,
+    ) -> Option<audit::AuditEntry> {
+        audit::get_audit_entry(&env, &asset_id, index)
+    }
+
+    /// Check that an asset's audit log hash chain is intact
+    pub fn verify_audit_chain(env: Env, asset_id: BytesN<32>) -> bool {
+        audit::verify_audit_chain(&env, &asset_id)
+    }
+
+    /// Seed an asset's audit log with back-dated entries on migration (admin only)
+    pub fn import_audit_entries(
+        env: Env,
+        asset_id: BytesN<32>,
+        entries: Vec<audit::AuditEntry>,
+        admin: Address,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        if admin != Self::get_admin(env.clone())? {
+            return Err(Error::Unauthorized);
+        }
+        audit::import_audit_entries(&env, &asset_id, entries, admin)
+    }
+
     // =====================
     // Tokenization Functions
     // =====================
@@ -552,8 +1364,10 @@ impl AssetUpContract {
         name: String,
         description: String,
         asset_type: AssetType,
+        max_supply: Option<i128>,
     ) -> Result<TokenizedAsset, Error> {
         tokenizer.require_auth();
+        Self::when_not_paused(&env)?;
 
         let metadata = TokenMetadata {
             name,
@@ -575,10 +1389,27 @@ impl AssetUpContract {
             min_voting_threshold,
             tokenizer,
             metadata,
+            max_supply,
         )
     }
 
-    /// Mint additional tokens (only tokenizer can call)
+    /// Tokens that can still be minted under the supply cap (None when uncapped)
+    pub fn get_remaining_mintable(env: Env, asset_id: u64) -> Option<i128> {
+        tokenization::get_remaining_mintable(&env, asset_id)
+    }
+
+    /// Cap the number of distinct holders (only tokenizer can call)
+    pub fn set_max_holders(
+        env: Env,
+        asset_id: u64,
+        max_holders: Option<u32>,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        tokenization::set_max_holders(&env, asset_id, max_holders, caller)
+    }
+
+    /// Mint additional tokens (tokenizer or `MINTER` role holders)
     pub fn mint_tokens(
         env: Env,
         asset_id: u64,
@@ -586,48 +1417,224 @@ impl AssetUpContract {
         minter: Address,
     ) -> Result<TokenizedAsset, Error> {
         minter.require_auth();
+        Self::when_not_paused(&env)?;
         tokenization::mint_tokens(&env, asset_id, amount, minter)
     }
 
-    /// Burn tokens (only tokenizer can call)
-    pub fn burn_tokens(
+    /// Cap minting per time window (only tokenizer can call)
+    pub fn set_mint_rate_limit(
         env: Env,
         asset_id: u64,
-        amount: i128,
-        burner: Address,
-    ) -> Result<TokenizedAsset, Error> {
-        burner.require_auth();
-        tokenization::burn_tokens(&env, asset_id, amount, burner)
+        max_per_window: i128,
+        window_secs: u64,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        tokenization::set_mint_rate_limit(&env, asset_id, max_per_window, window_secs, caller)
     }
 
-    /// Transfer tokens from one address to another
-    pub fn transfer_tokens(
+    /// Get the mint rate limit for an asset, if one is set
+    pub fn get_mint_rate_limit(env: Env, asset_id: u64) -> Option<MintRateLimit> {
+        tokenization::get_mint_rate_limit(&env, asset_id)
+    }
+
+    /// Cap a holder's transfers per time window (only tokenizer can call)
+    pub fn set_transfer_limit(
         env: Env,
         asset_id: u64,
-        from: Address,
-        to: Address,
-        amount: i128,
+        holder: Address,
+        max_per_window: i128,
+        window_secs: u64,
+        caller: Address,
     ) -> Result<(), Error> {
-        from.require_auth();
+        caller.require_auth();
+        tokenization::set_transfer_limit(
+            &env,
+            asset_id,
+            holder,
+            max_per_window,
+            window_secs,
+            caller,
+        )
+    }
 
-        // Validate transfer restrictions
-        transfer_restrictions::validate_transfer(&env, asset_id, from.clone(), to.clone())?;
+    /// Get a holder's transfer limit, if one is set
+    pub fn get_transfer_limit(env: Env, asset_id: u64, holder: Address) -> Option<TransferLimit> {
+        tokenization::get_transfer_limit(&env, asset_id, holder)
+    }
 
-        tokenization::transfer_tokens(&env, asset_id, from, to, amount)
+    /// Pause minting while leaving transfers and burns enabled (only tokenizer can call)
+    pub fn pause_minting(env: Env, asset_id: u64, caller: Address) -> Result<(), Error> {
+        caller.require_auth();
+        tokenization::pause_minting(&env, asset_id, caller)
     }
 
-    /// Get token balance for an address
-    pub fn get_token_balance(env: Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
-        tokenization::get_token_balance(&env, asset_id, holder)
+    /// Resume minting (only tokenizer can call)
+    pub fn resume_minting(env: Env, asset_id: u64, caller: Address) -> Result<(), Error> {
+        caller.require_auth();
+        tokenization::resume_minting(&env, asset_id, caller)
     }
 
-    /// Get all token holders for an asset
-    pub fn get_token_holders(env: Env, asset_id: u64) -> Result<Vec<Address>, Error> {
-        tokenization::get_token_holders(&env, asset_id)
+    /// Check whether minting is paused for an asset
+    pub fn is_minting_paused(env: Env, asset_id: u64) -> bool {
+        tokenization::is_minting_paused(&env, asset_id)
     }
 
-    /// Lock tokens until timestamp (only the asset tokenizer can call this)
-    pub fn lock_tokens(
+    /// Burn tokens (only tokenizer can call)
+    pub fn burn_tokens(
+        env: Env,
+        asset_id: u64,
+        amount: i128,
+        burner: Address,
+    ) -> Result<TokenizedAsset, Error> {
+        burner.require_auth();
+        Self::when_not_paused(&env)?;
+        tokenization::burn_tokens(&env, asset_id, amount, burner)
+    }
+
+    /// Route burns to a non-transferable burn address (only tokenizer can call)
+    pub fn set_burn_address(
+        env: Env,
+        asset_id: u64,
+        burn_address: Address,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        tokenization::set_burn_address(&env, asset_id, burn_address, caller)
+    }
+
+    /// Get the active burn address, if any
+    pub fn get_burn_address(env: Env, asset_id: u64) -> Option<Address> {
+        tokenization::get_burn_address(&env, asset_id)
+    }
+
+    /// Get the total tokens burned for an asset
+    pub fn get_burned_total(env: Env, asset_id: u64) -> i128 {
+        tokenization::get_burned_total(&env, asset_id)
+    }
+
+    /// Check that holder balances add up to the asset's supply counters (read-only)
+    pub fn verify_supply_invariant(env: Env, asset_id: u64) -> Result<bool, Error> {
+        tokenization::verify_supply_invariant(&env, asset_id)
+    }
+
+    /// Transfer tokens from one address to another
+    pub fn transfer_tokens(
+        env: Env,
+        asset_id: u64,
+        from: Address,
+        to: Address,
+        amount: i128,
+        price_per_token: Option<i128>,
+    ) -> Result<(), Error> {
+        from.require_auth();
+        Self::when_not_paused(&env)?;
+
+        // Validate transfer restrictions
+        transfer_restrictions::validate_transfer(&env, asset_id, from.clone(), to.clone())?;
+
+        tokenization::transfer_tokens(&env, asset_id, from, to, amount, price_per_token)
+    }
+
+    /// Force-move a holder's tokens without their consent (only tokenizer can call)
+    pub fn force_transfer(
+        env: Env,
+        asset_id: u64,
+        from: Address,
+        to: Address,
+        amount: i128,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        Self::when_not_paused(&env)?;
+        tokenization::force_transfer(&env, asset_id, from, to, amount, caller)
+    }
+
+    /// Transfer tokens to several recipients in one all-or-nothing call
+    pub fn batch_transfer_tokens(
+        env: Env,
+        asset_id: u64,
+        from: Address,
+        recipients: Vec<(Address, i128)>,
+    ) -> Result<(), Error> {
+        from.require_auth();
+        Self::when_not_paused(&env)?;
+        tokenization::batch_transfer_tokens(&env, asset_id, from, recipients)
+    }
+
+    /// Approve a spender to transfer up to `amount` of the owner's tokens
+    pub fn approve(
+        env: Env,
+        asset_id: u64,
+        owner: Address,
+        spender: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+        Self::when_not_paused(&env)?;
+        tokenization::approve(&env, asset_id, owner, spender, amount)
+    }
+
+    /// Get the remaining allowance of a spender over the owner's tokens
+    pub fn get_allowance(env: Env, asset_id: u64, owner: Address, spender: Address) -> i128 {
+        tokenization::get_allowance(&env, asset_id, owner, spender)
+    }
+
+    /// Transfer tokens on the owner's behalf using an allowance
+    pub fn transfer_from(
+        env: Env,
+        asset_id: u64,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        spender.require_auth();
+        Self::when_not_paused(&env)?;
+
+        // Validate transfer restrictions
+        transfer_restrictions::validate_transfer(&env, asset_id, from.clone(), to.clone())?;
+
+        tokenization::transfer_from(&env, asset_id, spender, from, to, amount)
+    }
+
+    /// Get token balance for an address
+    pub fn get_token_balance(env: Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
+        tokenization::get_token_balance(&env, asset_id, holder)
+    }
+
+    /// Get all token holders for an asset (unbounded; prefer the paged variant)
+    pub fn get_token_holders(env: Env, asset_id: u64) -> Result<Vec<Address>, Error> {
+        tokenization::get_token_holders(&env, asset_id)
+    }
+
+    /// Snapshot every holder's current balance; returns the snapshot id
+    pub fn take_balance_snapshot(env: Env, asset_id: u64) -> Result<u64, Error> {
+        tokenization::take_balance_snapshot(&env, asset_id)
+    }
+
+    /// Get a holder's balance as of a snapshot
+    pub fn get_snapshot_balance(
+        env: Env,
+        asset_id: u64,
+        snapshot_id: u64,
+        holder: Address,
+    ) -> i128 {
+        tokenization::get_snapshot_balance(&env, asset_id, snapshot_id, holder)
+    }
+
+    /// Get a page of token holders for an asset
+    pub fn get_token_holders_paged(
+        env: Env,
+        asset_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> Vec<Address> {
+        tokenization::get_token_holders_paged(&env, asset_id, start, limit)
+    }
+
+    /// Lock tokens until timestamp (only the asset tokenizer or lock authority can call this)
+    pub fn lock_tokens(
         env: Env,
         asset_id: u64,
         holder: Address,
@@ -648,6 +1655,71 @@ impl AssetUpContract {
         tokenization::is_tokens_locked(&env, asset_id, holder)
     }
 
+    /// Seconds until the holder's tokens unlock (zero if unlocked)
+    pub fn lock_remaining_secs(env: Env, asset_id: u64, holder: Address) -> u64 {
+        tokenization::lock_remaining_secs(&env, asset_id, holder)
+    }
+
+    /// Lock part of the holder's own balance until a timestamp (cannot be undone early)
+    pub fn self_lock_tokens(
+        env: Env,
+        asset_id: u64,
+        holder: Address,
+        amount: i128,
+        until: u64,
+    ) -> Result<(), Error> {
+        holder.require_auth();
+        tokenization::self_lock_tokens(&env, asset_id, holder, amount, until)
+    }
+
+    /// Get the holder's active self-lock, if any
+    pub fn get_self_lock(env: Env, asset_id: u64, holder: Address) -> Option<SelfLock> {
+        tokenization::get_self_lock(&env, asset_id, holder)
+    }
+
+    /// Put part of a holder's tokens on a linear vesting schedule (tokenizer or lock authority)
+    pub fn set_vesting_schedule(
+        env: Env,
+        asset_id: u64,
+        holder: Address,
+        total_amount: i128,
+        start: u64,
+        cliff: u64,
+        duration: u64,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        tokenization::set_vesting_schedule(
+            &env,
+            asset_id,
+            holder,
+            total_amount,
+            start,
+            cliff,
+            duration,
+            caller,
+        )
+    }
+
+    /// Get a holder's vesting schedule
+    pub fn get_vesting_schedule(
+        env: Env,
+        asset_id: u64,
+        holder: Address,
+    ) -> Option<VestingSchedule> {
+        tokenization::get_vesting_schedule(&env, asset_id, holder)
+    }
+
+    /// Tokens vested so far under a holder's schedule
+    pub fn vested_amount(env: Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
+        tokenization::vested_amount(&env, asset_id, holder)
+    }
+
+    /// Tokens the holder cannot currently transfer
+    pub fn locked_balance(env: Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
+        tokenization::locked_balance(&env, asset_id, holder)
+    }
+
     /// Get ownership percentage for a holder (in basis points)
     pub fn get_ownership_percentage(
         env: Env,
@@ -657,31 +1729,208 @@ impl AssetUpContract {
         tokenization::calculate_ownership_percentage(&env, asset_id, holder)
     }
 
+    /// Get ownership percentage computed live from balance and supply (in basis points)
+    pub fn live_ownership_percentage(
+        env: Env,
+        asset_id: u64,
+        holder: Address,
+    ) -> Result<i128, Error> {
+        tokenization::live_ownership_percentage(&env, asset_id, holder)
+    }
+
+    /// Recompute ownership percentages for a page of holders, returning how many were updated
+    pub fn recompute_percentages(
+        env: Env,
+        asset_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> Result<u32, Error> {
+        tokenization::recompute_percentages(&env, asset_id, start, limit)
+    }
+
+    /// Re-derive all ownership percentages so they sum to exactly 10000
+    pub fn normalize_percentages(env: Env, asset_id: u64) -> Result<(), Error> {
+        tokenization::normalize_percentages(&env, asset_id)
+    }
+
     /// Get tokenized asset details
     pub fn get_tokenized_asset(env: Env, asset_id: u64) -> Result<TokenizedAsset, Error> {
         tokenization::get_tokenized_asset(&env, asset_id)
     }
 
+    /// Get the most recent valuation updates, oldest first
+    pub fn get_valuation_history(env: Env, asset_id: u64) -> Vec<(u64, i128)> {
+        tokenization::get_valuation_history(&env, asset_id)
+    }
+
+    /// Get the valuation recorded at or before a timestamp
+    pub fn valuation_at(env: Env, asset_id: u64, timestamp: u64) -> i128 {
+        tokenization::valuation_at(&env, asset_id, timestamp)
+    }
+
+    /// Get the total value locked across tokenized assets and claim pools
+    pub fn total_value_locked(env: Env) -> i128 {
+        tokenization::total_value_locked(&env)
+    }
+
     /// Update asset valuation
     pub fn update_valuation(env: Env, asset_id: u64, new_valuation: i128) -> Result<(), Error> {
         tokenization::update_valuation(&env, asset_id, new_valuation)
     }
 
+    /// Set or clear the appraiser for an asset (only tokenizer can call)
+    pub fn set_appraiser(
+        env: Env,
+        asset_id: u64,
+        appraiser: Option<Address>,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        tokenization::set_appraiser(&env, asset_id, appraiser, caller)
+    }
+
+    /// Set or clear the lock authority for an asset (only tokenizer can call)
+    pub fn set_lock_authority(
+        env: Env,
+        asset_id: u64,
+        authority: Option<Address>,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        tokenization::set_lock_authority(&env, asset_id, authority, caller)
+    }
+
+    /// Replace the treasury addresses for an asset (only tokenizer can call)
+    pub fn set_treasury_addresses(
+        env: Env,
+        asset_id: u64,
+        treasury: Vec<Address>,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        tokenization::set_treasury_addresses(&env, asset_id, treasury, caller)
+    }
+
+    /// Set the transfer tax (basis points) accrued to other holders as dividends
+    pub fn set_reflection_tax(
+        env: Env,
+        asset_id: u64,
+        tax_bps: u32,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        tokenization::set_reflection_tax(&env, asset_id, tax_bps, caller)
+    }
+
+    /// Get the reflection tax rate in basis points
+    pub fn get_reflection_tax(env: Env, asset_id: u64) -> u32 {
+        tokenization::get_reflection_tax(&env, asset_id)
+    }
+
+    /// Get the tokens withheld as reflection tax, backing the dividends they accrued
+    pub fn get_reflection_pool(env: Env, asset_id: u64) -> i128 {
+        tokenization::get_reflection_pool(&env, asset_id)
+    }
+
+    /// Toggle exit-only mode for wind-down (only tokenizer can call)
+    pub fn set_exit_only(
+        env: Env,
+        asset_id: u64,
+        exit_only: bool,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        tokenization::set_exit_only(&env, asset_id, exit_only, caller)
+    }
+
+    /// Check whether an asset is in exit-only mode
+    pub fn is_exit_only(env: Env, asset_id: u64) -> bool {
+        tokenization::is_exit_only(&env, asset_id)
+    }
+
+    /// Enable or disable global token symbol uniqueness (admin only)
+    pub fn set_enforce_unique_symbols(env: Env, enforce: bool) -> Result<(), Error> {
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+        tokenization::set_enforce_unique_symbols(&env, enforce);
+        Ok(())
+    }
+
+    /// Check whether token symbols must be unique across assets
+    pub fn is_enforce_unique_symbols(env: Env) -> bool {
+        tokenization::is_enforce_unique_symbols(&env)
+    }
+
+    /// Get the asset holding a token symbol, if any
+    pub fn get_symbol_asset(env: Env, symbol: String) -> Option<u64> {
+        tokenization::get_symbol_asset(&env, symbol)
+    }
+
+    /// Get every configured role holder for an asset
+    pub fn get_asset_roles(env: Env, asset_id: u64) -> Result<AssetRoles, Error> {
+        tokenization::get_asset_roles(&env, asset_id)
+    }
+
+    /// Export an asset's full on-chain state for backup
+    pub fn export_asset_state(env: Env, asset_id: u64) -> Result<AssetStateExport, Error> {
+        tokenization::export_asset_state(&env, asset_id)
+    }
+
+    /// Export a page of an asset's holders with their lock records
+    pub fn export_asset_holders(
+        env: Env,
+        asset_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<HolderExport>, Error> {
+        tokenization::export_asset_holders(&env, asset_id, start, limit)
+    }
+
     // =====================
     // Dividend Functions
     // =====================
 
-    /// Distribute dividends proportionally to all holders
-    pub fn distribute_dividends(env: Env, asset_id: u64, total_amount: i128) -> Result<(), Error> {
-        dividends::distribute_dividends(&env, asset_id, total_amount)
+    /// Distribute dividends proportionally to all holders (tokenizer only)
+    pub fn distribute_dividends(
+        env: Env,
+        asset_id: u64,
+        total_amount: i128,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        Self::when_not_paused(&env)?;
+        dividends::distribute_dividends(&env, asset_id, total_amount, caller)
     }
 
     /// Claim unclaimed dividends
     pub fn claim_dividends(env: Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
         holder.require_auth();
+        Self::when_not_paused(&env)?;
         dividends::claim_dividends(&env, asset_id, holder)
     }
 
+    /// Preview what `claim_dividends` would pay, or why it would fail
+    pub fn preview_claim(env: Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
+        dividends::preview_claim(&env, asset_id, holder)
+    }
+
+    /// Claim unclaimed dividends from a single epoch
+    pub fn claim_epoch_dividends(
+        env: Env,
+        asset_id: u64,
+        holder: Address,
+        epoch: u32,
+    ) -> Result<i128, Error> {
+        holder.require_auth();
+        Self::when_not_paused(&env)?;
+        dividends::claim_epoch_dividends(&env, asset_id, holder, epoch)
+    }
+
+    /// Get a holder's unclaimed dividends broken down by epoch
+    pub fn get_unclaimed_by_epoch(env: Env, asset_id: u64, holder: Address) -> Vec<(u32, i128)> {
+        dividends::get_unclaimed_by_epoch(&env, asset_id, holder)
+    }
+
     /// Get unclaimed dividends for a holder
     pub fn get_unclaimed_dividends(
         env: Env,
@@ -691,49 +1940,258 @@ impl AssetUpContract {
         dividends::get_unclaimed_dividends(&env, asset_id, holder)
     }
 
-    /// Enable revenue sharing for an asset
-    pub fn enable_revenue_sharing(env: Env, asset_id: u64) -> Result<(), Error> {
-        dividends::enable_revenue_sharing(&env, asset_id)
+    /// Declare a dividend, freezing transfers until it is finalized or the freeze lapses
+    pub fn declare_dividend(
+        env: Env,
+        asset_id: u64,
+        total_amount: i128,
+        freeze_secs: u64,
+        declarer: Address,
+    ) -> Result<(), Error> {
+        declarer.require_auth();
+        dividends::declare_dividend(&env, asset_id, total_amount, freeze_secs, declarer)
+    }
+
+    /// Distribute a declared dividend and lift its transfer freeze
+    pub fn finalize_dividend(env: Env, asset_id: u64, declarer: Address) -> Result<(), Error> {
+        declarer.require_auth();
+        dividends::finalize_dividend(&env, asset_id, declarer)
+    }
+
+    /// Get the pending dividend declaration for an asset
+    pub fn get_dividend_declaration(env: Env, asset_id: u64) -> Option<DividendDeclaration> {
+        dividends::get_dividend_declaration(&env, asset_id)
     }
 
-    /// Disable revenue sharing for an asset
-    pub fn disable_revenue_sharing(env: Env, asset_id: u64) -> Result<(), Error> {
-        dividends::disable_revenue_sharing(&env, asset_id)
+    /// Open a dividend epoch against a snapshot of current balances
+    pub fn open_dividend_epoch(env: Env, asset_id: u64, caller: Address) -> Result<u32, Error> {
+        caller.require_auth();
+        dividends::open_dividend_epoch(&env, asset_id, caller)
+    }
+
+    /// Add funds to an open dividend epoch
+    pub fn fund_epoch(env: Env, asset_id: u64, epoch: u32, amount: i128) -> Result<(), Error> {
+        dividends::fund_epoch(&env, asset_id, epoch, amount)
+    }
+
+    /// Close a dividend epoch and credit holders pro-rata to the snapshot
+    pub fn close_dividend_epoch(env: Env, asset_id: u64, epoch: u32) -> Result<(), Error> {
+        dividends::close_dividend_epoch(&env, asset_id, epoch)
+    }
+
+    /// Get a dividend epoch
+    pub fn get_dividend_epoch(env: Env, asset_id: u64, epoch: u32) -> Result<DividendEpoch, Error> {
+        dividends::get_dividend_epoch(&env, asset_id, epoch)
+    }
+
+    /// Get a holder's share of a dividend epoch
+    pub fn get_epoch_entitlement(
+        env: Env,
+        asset_id: u64,
+        epoch: u32,
+        holder: Address,
+    ) -> Result<i128, Error> {
+        dividends::get_epoch_entitlement(&env, asset_id, epoch, holder)
+    }
+
+    /// Enable or disable revenue sharing for an asset (tokenizer only)
+    pub fn set_revenue_sharing(
+        env: Env,
+        asset_id: u64,
+        enabled: bool,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        dividends::set_revenue_sharing(&env, asset_id, enabled, caller)
     }
 
     // =====================
     // Voting Functions
     // =====================
 
-    /// Cast a vote on a proposal
+    /// Cast a vote for or against a proposal, weighted by the proposal's balance snapshot
     pub fn cast_vote(
         env: Env,
         asset_id: u64,
         proposal_id: u64,
         voter: Address,
+        support: bool,
+    ) -> Result<(), Error> {
+        voter.require_auth();
+        voting::cast_vote(&env, asset_id, proposal_id, voter, support)
+    }
+
+    /// Set the minimum token balance required to vote (only tokenizer can call)
+    pub fn set_min_voting_balance(
+        env: Env,
+        asset_id: u64,
+        min_balance: i128,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        voting::set_min_voting_balance(&env, asset_id, min_balance, caller)
+    }
+
+    /// Get the minimum token balance required to vote
+    pub fn get_min_voting_balance(env: Env, asset_id: u64) -> i128 {
+        voting::get_min_voting_balance(&env, asset_id)
+    }
+
+    /// Set the voting weight of self-locked tokens in basis points (tokenizer only)
+    pub fn set_lock_vote_multiplier(
+        env: Env,
+        asset_id: u64,
+        multiplier_bps: u32,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        voting::set_lock_vote_multiplier(&env, asset_id, multiplier_bps, caller)
+    }
+
+    /// Get the voting weight of self-locked tokens in basis points
+    pub fn get_lock_vote_multiplier(env: Env, asset_id: u64) -> u32 {
+        voting::get_lock_vote_multiplier(&env, asset_id)
+    }
+
+    /// Get a holder's voting power including any self-lock bonus
+    pub fn effective_voting_power(env: Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
+        voting::effective_voting_power(&env, asset_id, holder)
+    }
+
+    /// Delegate voting power to another address
+    pub fn delegate_votes(
+        env: Env,
+        asset_id: u64,
+        delegator: Address,
+        delegatee: Address,
+    ) -> Result<(), Error> {
+        delegator.require_auth();
+        voting::delegate_votes(&env, asset_id, delegator, delegatee)
+    }
+
+    /// Revoke a vote delegation
+    pub fn revoke_delegation(env: Env, asset_id: u64, delegator: Address) -> Result<(), Error> {
+        delegator.require_auth();
+        voting::revoke_delegation(&env, asset_id, delegator)
+    }
+
+    /// Get the address a holder has delegated their votes to
+    pub fn get_delegate(env: Env, asset_id: u64, delegator: Address) -> Option<Address> {
+        voting::get_delegate(&env, asset_id, delegator)
+    }
+
+    /// Get a voter's own voting power plus power delegated to them
+    pub fn total_voting_power(env: Env, asset_id: u64, voter: Address) -> Result<i128, Error> {
+        voting::total_voting_power(&env, asset_id, voter)
+    }
+
+    /// Get vote tally for a proposal
+    pub fn get_vote_tally(env: Env, asset_id: u64, proposal_id: u64) -> Result<i128, Error> {
+        voting::get_vote_tally(&env, asset_id, proposal_id)
+    }
+
+    /// Check if an address has voted
+    pub fn has_voted(
+        env: Env,
+        asset_id: u64,
+        proposal_id: u64,
+        voter: Address,
+    ) -> Result<bool, Error> {
+        voting::has_voted(&env, asset_id, proposal_id, voter)
+    }
+
+    /// Check if proposal passed
+    pub fn proposal_passed(env: Env, asset_id: u64, proposal_id: u64) -> Result<bool, Error> {
+        voting::proposal_passed(&env, asset_id, proposal_id)
+    }
+
+    /// Create a governance proposal (holders at or above the voting threshold)
+    pub fn create_proposal(
+        env: Env,
+        asset_id: u64,
+        proposer: Address,
+        description: String,
+        voting_deadline: u64,
+    ) -> Result<u64, Error> {
+        proposer.require_auth();
+        voting::create_proposal(&env, asset_id, proposer, description, voting_deadline)
+    }
+
+    /// Vote for or against a governance proposal
+    pub fn vote_on_proposal(
+        env: Env,
+        asset_id: u64,
+        proposal_id: u64,
+        voter: Address,
+        support: bool,
+    ) -> Result<(), Error> {
+        voter.require_auth();
+        voting::vote_on_proposal(&env, asset_id, proposal_id, voter, support)
+    }
+
+    /// Flip a recorded vote on a governance proposal
+    pub fn change_vote(
+        env: Env,
+        asset_id: u64,
+        proposal_id: u64,
+        voter: Address,
+        support: bool,
+    ) -> Result<(), Error> {
+        voter.require_auth();
+        voting::change_vote(&env, asset_id, proposal_id, voter, support)
+    }
+
+    /// Get a voter's receipt on a governance proposal
+    pub fn get_vote_receipt(
+        env: Env,
+        asset_id: u64,
+        proposal_id: u64,
+        voter: Address,
+    ) -> Option<VoteReceipt> {
+        voting::get_vote_receipt(&env, asset_id, proposal_id, voter)
+    }
+
+    /// Get a governance proposal
+    pub fn get_governance_proposal(
+        env: Env,
+        asset_id: u64,
+        proposal_id: u64,
+    ) -> Result<GovernanceProposal, Error> {
+        voting::get_governance_proposal(&env, asset_id, proposal_id)
+    }
+
+    /// Tally a governance proposal as (votes_for, votes_against)
+    pub fn tally_proposal(
+        env: Env,
+        asset_id: u64,
+        proposal_id: u64,
+    ) -> Result<(i128, i128), Error> {
+        voting::tally_proposal(&env, asset_id, proposal_id)
+    }
+
+    /// Set the governance quorum in basis points of circulation (tokenizer only)
+    pub fn set_proposal_quorum(
+        env: Env,
+        asset_id: u64,
+        quorum_bps: u32,
+        caller: Address,
     ) -> Result<(), Error> {
-        voter.require_auth();
-        voting::cast_vote(&env, asset_id, proposal_id, voter)
+        caller.require_auth();
+        voting::set_proposal_quorum(&env, asset_id, quorum_bps, caller)
     }
 
-    /// Get vote tally for a proposal
-    pub fn get_vote_tally(env: Env, asset_id: u64, proposal_id: u64) -> Result<i128, Error> {
-        voting::get_vote_tally(&env, asset_id, proposal_id)
+    /// Get the governance quorum in basis points
+    pub fn get_proposal_quorum(env: Env, asset_id: u64) -> u32 {
+        voting::get_proposal_quorum(&env, asset_id)
     }
 
-    /// Check if an address has voted
-    pub fn has_voted(
+    /// Check if a governance proposal met quorum and passed
+    pub fn governance_proposal_passed(
         env: Env,
         asset_id: u64,
         proposal_id: u64,
-        voter: Address,
     ) -> Result<bool, Error> {
-        voting::has_voted(&env, asset_id, proposal_id, voter)
-    }
-
-    /// Check if proposal passed
-    pub fn proposal_passed(env: Env, asset_id: u64, proposal_id: u64) -> Result<bool, Error> {
-        voting::proposal_passed(&env, asset_id, proposal_id)
+        voting::governance_proposal_passed(&env, asset_id, proposal_id)
     }
 
     // =====================
@@ -776,6 +2234,131 @@ impl AssetUpContract {
         transfer_restrictions::get_whitelist(&env, asset_id)
     }
 
+    /// Add address to blacklist (requires the tokenizer's authorization)
+    pub fn add_to_blacklist(env: Env, asset_id: u64, address: Address) -> Result<(), Error> {
+        transfer_restrictions::add_to_blacklist(&env, asset_id, address)
+    }
+
+    /// Remove address from blacklist (requires the tokenizer's authorization)
+    pub fn remove_from_blacklist(env: Env, asset_id: u64, address: Address) -> Result<(), Error> {
+        transfer_restrictions::remove_from_blacklist(&env, asset_id, address)
+    }
+
+    /// Check if address is blacklisted
+    pub fn is_blacklisted(env: Env, asset_id: u64, address: Address) -> bool {
+        transfer_restrictions::is_blacklisted(&env, asset_id, address)
+    }
+
+    /// Freeze a holder's tokens in both directions (only tokenizer can call)
+    pub fn freeze_holder(
+        env: Env,
+        asset_id: u64,
+        holder: Address,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        transfer_restrictions::freeze_holder(&env, asset_id, holder, caller)
+    }
+
+    /// Lift a holder freeze (only tokenizer can call)
+    pub fn unfreeze_holder(
+        env: Env,
+        asset_id: u64,
+        holder: Address,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        transfer_restrictions::unfreeze_holder(&env, asset_id, holder, caller)
+    }
+
+    /// Check if a holder is frozen
+    pub fn is_holder_frozen(env: Env, asset_id: u64, holder: Address) -> bool {
+        transfer_restrictions::is_holder_frozen(&env, asset_id, &holder)
+    }
+
+    /// Request to be added to an asset's whitelist
+    pub fn request_whitelist(env: Env, asset_id: u64, requester: Address) -> Result<(), Error> {
+        requester.require_auth();
+        transfer_restrictions::request_whitelist(&env, asset_id, requester)
+    }
+
+    /// Approve a pending whitelist request (only tokenizer can call)
+    pub fn approve_whitelist_request(
+        env: Env,
+        asset_id: u64,
+        requester: Address,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        transfer_restrictions::approve_whitelist_request(&env, asset_id, requester, caller)
+    }
+
+    /// Reject a pending whitelist request (only tokenizer can call)
+    pub fn reject_whitelist_request(
+        env: Env,
+        asset_id: u64,
+        requester: Address,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        transfer_restrictions::reject_whitelist_request(&env, asset_id, requester, caller)
+    }
+
+    /// Get pending whitelist requests
+    pub fn get_pending_whitelist_requests(env: Env, asset_id: u64) -> Vec<Address> {
+        transfer_restrictions::get_pending_whitelist_requests(&env, asset_id)
+    }
+
+    /// Explain whether a transfer would be allowed without executing it
+    pub fn transfer_restriction_reason(
+        env: Env,
+        asset_id: u64,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<TransferDecision, Error> {
+        if Self::is_paused(env.clone())? {
+            return Ok(TransferDecision::Paused);
+        }
+        transfer_restrictions::transfer_restriction_reason(&env, asset_id, from, to, amount)
+    }
+
+    // =====================
+    // Escrow
+    // =====================
+
+    /// Escrow tokens for one or more recipients, releasable after `release_after`
+    pub fn escrow_transfer(
+        env: Env,
+        asset_id: u64,
+        sender: Address,
+        amount: i128,
+        recipients: Vec<(Address, i128)>,
+        release_after: u64,
+    ) -> Result<u64, Error> {
+        sender.require_auth();
+        Self::when_not_paused(&env)?;
+        escrow::escrow_transfer(&env, asset_id, sender, amount, recipients, release_after)
+    }
+
+    /// Release an escrow to all of its recipients
+    pub fn claim_escrow(env: Env, escrow_id: u64) -> Result<(), Error> {
+        Self::when_not_paused(&env)?;
+        escrow::claim_escrow(&env, escrow_id)
+    }
+
+    /// Cancel an escrow and refund the sender
+    pub fn cancel_escrow(env: Env, escrow_id: u64, sender: Address) -> Result<(), Error> {
+        sender.require_auth();
+        Self::when_not_paused(&env)?;
+        escrow::cancel_escrow(&env, escrow_id, sender)
+    }
+
+    /// Get an escrow by ID
+    pub fn get_escrow(env: Env, escrow_id: u64) -> Result<escrow::Escrow, Error> {
+        escrow::get_escrow(&env, escrow_id)
+    }
+
     // =====================
     // Detokenization
     // =====================
@@ -790,11 +2373,60 @@ impl AssetUpContract {
         detokenization::propose_detokenization(&env, asset_id, proposer)
     }
 
+    /// Vote for or against the active detokenization proposal
+    pub fn vote_detokenization(
+        env: Env,
+        asset_id: u64,
+        voter: Address,
+        support: bool,
+    ) -> Result<(), Error> {
+        voter.require_auth();
+        detokenization::vote_detokenization(&env, asset_id, voter, support)
+    }
+
     /// Execute detokenization (if vote passed)
     pub fn execute_detokenization(env: Env, asset_id: u64, proposal_id: u64) -> Result<(), Error> {
         detokenization::execute_detokenization(&env, asset_id, proposal_id)
     }
 
+    /// Tokenize a detokenized asset again (only the former tokenizer can call)
+    pub fn retokenize_asset(
+        env: Env,
+        asset_id: u64,
+        symbol: String,
+        total_supply: i128,
+        decimals: u32,
+        min_voting_threshold: i128,
+        caller: Address,
+        name: String,
+        description: String,
+        asset_type: AssetType,
+    ) -> Result<TokenizedAsset, Error> {
+        caller.require_auth();
+
+        let metadata = TokenMetadata {
+            name,
+            description,
+            asset_type,
+            ipfs_uri: None,
+            legal_docs_hash: None,
+            valuation_report_hash: None,
+            accredited_investor_required: false,
+            geographic_restrictions: Vec::new(&env),
+        };
+
+        detokenization::retokenize_asset(
+            &env,
+            asset_id,
+            symbol,
+            total_supply,
+            decimals,
+            min_voting_threshold,
+            caller,
+            metadata,
+        )
+    }
+
     /// Get detokenization proposal status
     pub fn get_detokenization_proposal(
         env: Env,
@@ -821,6 +2453,26 @@ impl AssetUpContract {
         insurance::create_policy(env, policy)
     }
 
+    /// Create several policies atomically (insurer only); rejects the whole batch on any
+    /// failure. Use `check_policies_batch` to find the offending policy ID.
+    pub fn create_policies_batch(
+        env: Env,
+        policies: Vec<insurance::InsurancePolicy>,
+        insurer: Address,
+    ) -> Result<(), Error> {
+        insurer.require_auth();
+        insurance::create_policies_batch(env, policies, insurer)
+    }
+
+    /// Return the ID of the first policy in a batch that would be rejected, if any
+    pub fn check_policies_batch(
+        env: Env,
+        policies: Vec<insurance::InsurancePolicy>,
+        insurer: Address,
+    ) -> Option<BytesN<32>> {
+        insurance::find_invalid_policy(&env, &policies, &insurer).map(|(policy_id, _)| policy_id)
+    }
+
     /// Cancel a policy (holder or insurer)
     pub fn cancel_insurance_policy(
         env: Env,
@@ -831,6 +2483,17 @@ impl AssetUpContract {
         insurance::cancel_policy(env, policy_id, caller)
     }
 
+    /// Transfer a policy to a new holder (current holder only)
+    pub fn transfer_policy(
+        env: Env,
+        policy_id: BytesN<32>,
+        new_holder: Address,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        insurance::transfer_policy(env, policy_id, new_holder, caller)
+    }
+
     /// Suspend a policy (insurer only)
     pub fn suspend_insurance_policy(
         env: Env,
@@ -858,6 +2521,81 @@ impl AssetUpContract {
         insurance::renew_policy(env, policy_id, new_end_date, new_premium, insurer)
     }
 
+    /// Amend the claim types excluded from a policy (insurer only)
+    pub fn endorse_policy_exclusions(
+        env: Env,
+        policy_id: BytesN<32>,
+        excluded_claim_types: Vec<insurance::ClaimType>,
+        insurer: Address,
+    ) -> Result<(), Error> {
+        insurer.require_auth();
+        insurance::endorse_policy_exclusions(env, policy_id, excluded_claim_types, insurer)
+    }
+
+    /// Pay a policy premium in its premium token
+    pub fn pay_premium(
+        env: Env,
+        policy_id: BytesN<32>,
+        payer: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        payer.require_auth();
+        insurance::pay_premium(env, policy_id, payer, amount)
+    }
+
+    /// Deposit funds into the claim payout pool for a token
+    pub fn fund_claim_pool(
+        env: Env,
+        premium_token: Address,
+        funder: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        funder.require_auth();
+        insurance::fund_claim_pool(env, premium_token, funder, amount)
+    }
+
+    /// Get the claim payout pool balance for a token
+    pub fn get_pool_balance(env: Env, premium_token: Address) -> i128 {
+        insurance::get_pool_balance(env, premium_token)
+    }
+
+    /// Auto-suspend a policy whose premium is overdue past grace (permissionless)
+    pub fn check_premium_status(
+        env: Env,
+        policy_id: BytesN<32>,
+    ) -> Result<insurance::PolicyStatus, Error> {
+        insurance::check_premium_status(env, policy_id)
+    }
+
+    /// Check whether a policy's premium is overdue by more than `grace_period`
+    pub fn is_policy_lapsed(env: Env, policy_id: BytesN<32>, grace_period: u64) -> bool {
+        insurance::is_policy_lapsed(env, policy_id, grace_period)
+    }
+
+    /// Expire and auto-suspend an insurer's policies in one sweep (permissionless)
+    pub fn reconcile_insurer_policies(env: Env, insurer: Address) -> (u32, u32) {
+        insurance::reconcile_insurer_policies(env, insurer)
+    }
+
+    /// Expire every lapsed policy on an asset in one sweep (permissionless)
+    pub fn expire_asset_policies(env: Env, asset_id: BytesN<32>) -> u32 {
+        insurance::expire_asset_policies(env, asset_id)
+    }
+
+    /// Renew every lapsed auto-renew policy on an asset, drawing each premium from
+    /// the holder's allowance to this contract; unfunded policies are skipped
+    pub fn process_auto_renewals(env: Env, asset_id: BytesN<32>) -> Result<u32, Error> {
+        insurance::process_auto_renewals(env, asset_id)
+    }
+
+    /// Tell a policyholder what to do next (pay premium, renew, nothing)
+    pub fn policy_next_action(
+        env: Env,
+        policy_id: BytesN<32>,
+    ) -> Result<insurance::PolicyAction, Error> {
+        insurance::policy_next_action(env, policy_id)
+    }
+
     /// Get a specific policy
     pub fn get_insurance_policy(
         env: Env,
@@ -866,11 +2604,201 @@ impl AssetUpContract {
         insurance::get_policy(env, policy_id)
     }
 
+    /// Check whether a policy covered a loss at the given timestamp
+    pub fn is_covered_at(env: Env, policy_id: BytesN<32>, loss_timestamp: u64) -> bool {
+        insurance::is_covered_at(env, policy_id, loss_timestamp)
+    }
+
     /// Get all policies for an asset
     pub fn get_asset_insurance_policies(env: Env, asset_id: BytesN<32>) -> Vec<BytesN<32>> {
         insurance::get_asset_policies(env, asset_id)
     }
 
+    /// Set the cap on policy coverage as a multiple of a tokenized asset's
+    /// valuation, in basis points (admin only)
+    pub fn set_max_coverage_ratio(env: Env, ratio_bps: u32) -> Result<(), Error> {
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+        insurance::set_max_coverage_ratio(&env, ratio_bps)
+    }
+
+    /// Get the cap on policy coverage relative to a tokenized asset's valuation
+    pub fn get_max_coverage_ratio(env: Env) -> u32 {
+        insurance::get_max_coverage_ratio(&env)
+    }
+
+    /// Aggregate active coverage and approved-but-unpaid claims across all assets
+    pub fn global_exposure(env: Env) -> (i128, i128) {
+        insurance::global_exposure(env)
+    }
+
+    /// Get active policy count, open claim count and total paid out for an insurer
+    pub fn insurer_summary(env: Env, insurer: Address) -> (u32, u32, i128) {
+        insurance::insurer_summary(env, insurer)
+    }
+
+    // =====================
+    // Insurance Claims
+    // =====================
+
+    /// File a claim against an active policy (claimant authenticates)
+    pub fn file_insurance_claim(env: Env, claim: insurance::InsuranceClaim) -> Result<(), Error> {
+        Self::when_not_paused(&env)?;
+        insurance::file_insurance_claim(env, claim)
+    }
+
+    /// Attach more supporting evidence to an open claim (claimant only)
+    pub fn add_claim_evidence(
+        env: Env,
+        claim_id: BytesN<32>,
+        evidence_hash: BytesN<32>,
+        caller: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        insurance::add_claim_evidence(env, claim_id, evidence_hash, caller)
+    }
+
+    /// Get every evidence hash attached to a claim
+    pub fn get_claim_evidence(env: Env, claim_id: BytesN<32>) -> Vec<BytesN<32>> {
+        insurance::get_claim_evidence(env, claim_id)
+    }
+
+    /// Move a submitted claim into review (insurer only)
+    pub fn start_claim_review(
+        env: Env,
+        claim_id: BytesN<32>,
+        reviewer: Address,
+    ) -> Result<(), Error> {
+        insurance::start_claim_review(env, claim_id, reviewer)
+    }
+
+    /// Approve a claim under review (insurer only)
+    pub fn approve_insurance_claim(
+        env: Env,
+        claim_id: BytesN<32>,
+        insurer: Address,
+        approved_amount: i128,
+    ) -> Result<(), Error> {
+        Self::when_not_paused(&env)?;
+        insurance::approve_insurance_claim(env, claim_id, insurer, approved_amount)
+    }
+
+    /// Revise the approved amount of an unpaid claim (insurer only)
+    pub fn revise_approval(
+        env: Env,
+        claim_id: BytesN<32>,
+        new_amount: i128,
+        approver: Address,
+    ) -> Result<(), Error> {
+        insurance::revise_approval(env, claim_id, new_amount, approver)
+    }
+
+    /// Reject an under-review claim with a reason (insurer only)
+    pub fn reject_insurance_claim(
+        env: Env,
+        claim_id: BytesN<32>,
+        insurer: Address,
+        reason: String,
+    ) -> Result<(), Error> {
+        insurance::reject_insurance_claim(env, claim_id, insurer, reason)
+    }
+
+    /// Dispute a rejected or approved claim (claimant only)
+    pub fn dispute_insurance_claim(
+        env: Env,
+        claim_id: BytesN<32>,
+        claimant: Address,
+    ) -> Result<(), Error> {
+        insurance::dispute_insurance_claim(env, claim_id, claimant)
+    }
+
+    /// Resolve a disputed claim (insurer only), either upholding the original
+    /// decision or approving the claim for `final_amount`
+    pub fn resolve_dispute(
+        env: Env,
+        claim_id: BytesN<32>,
+        insurer: Address,
+        uphold: bool,
+        final_amount: i128,
+    ) -> Result<insurance::ClaimStatus, Error> {
+        insurance::resolve_dispute(env, claim_id, insurer, uphold, final_amount)
+    }
+
+    /// Pay an approved claim out of the pool for the policy's token (insurer only)
+    pub fn pay_insurance_claim(
+        env: Env,
+        claim_id: BytesN<32>,
+        insurer: Address,
+    ) -> Result<(), Error> {
+        Self::when_not_paused(&env)?;
+        reentrancy::guarded(&env.clone(), || {
+            insurance::pay_insurance_claim(env, claim_id, insurer)
+        })
+    }
+
+    /// Split an approved claim's payout into installments (insurer only)
+    pub fn schedule_claim_payout(
+        env: Env,
+        claim_id: BytesN<32>,
+        num_installments: u32,
+        interval_secs: u64,
+        insurer: Address,
+    ) -> Result<(), Error> {
+        insurance::schedule_claim_payout(env, claim_id, num_installments, interval_secs, insurer)
+    }
+
+    /// Pay the next due installment of a scheduled claim payout
+    pub fn pay_claim_installment(env: Env, claim_id: BytesN<32>) -> Result<i128, Error> {
+        Self::when_not_paused(&env)?;
+        reentrancy::guarded(&env.clone(), || {
+            insurance::pay_claim_installment(env, claim_id)
+        })
+    }
+
+    /// Get the installment plan for a claim
+    pub fn get_claim_payout_schedule(
+        env: Env,
+        claim_id: BytesN<32>,
+    ) -> Option<insurance::ClaimPayoutSchedule> {
+        insurance::get_claim_payout_schedule(env, claim_id)
+    }
+
+    /// Get how much of a claim's payout each insurer has borne
+    pub fn get_claim_insurer_shares(env: Env, claim_id: BytesN<32>) -> Vec<(Address, i128)> {
+        insurance::get_claim_insurer_shares(env, claim_id)
+    }
+
+    /// Get a specific claim
+    pub fn get_insurance_claim(
+        env: Env,
+        claim_id: BytesN<32>,
+    ) -> Option<insurance::InsuranceClaim> {
+        insurance::get_insurance_claim(env, claim_id)
+    }
+
+    /// Get the most that can be approved for a claim after deductible and co-insurance
+    pub fn get_max_claim_payout(env: Env, claim_id: BytesN<32>) -> Result<i128, Error> {
+        insurance::get_max_claim_payout(env, claim_id)
+    }
+
+    /// Get a claim's chronological status timeline
+    pub fn get_claim_timeline(
+        env: Env,
+        claim_id: BytesN<32>,
+    ) -> Vec<(u64, insurance::ClaimStatus, Address)> {
+        insurance::get_claim_timeline(env, claim_id)
+    }
+
+    /// Get all claims filed against an asset
+    pub fn get_asset_insurance_claims(env: Env, asset_id: BytesN<32>) -> Vec<BytesN<32>> {
+        insurance::get_asset_insurance_claims(env, asset_id)
+    }
+
+    /// Get all claims filed against a policy, in filing order
+    pub fn get_policy_claims(env: Env, policy_id: BytesN<32>) -> Vec<BytesN<32>> {
+        insurance::get_policy_claims(env, policy_id)
+    }
+
     /// Create a new lease. Lessor authenticates; asset must not already be actively leased.
     pub fn create_lease(
         env: Env,
@@ -0,0 +1,23 @@
+use crate::error::Error;
+use soroban_sdk::{contracttype, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Locked,
+}
+
+/// Run `f` with the contract-wide reentrancy lock held. Entrypoints that call out
+/// to other contracts (token payouts) go through this so a callee cannot re-enter
+/// a guarded path mid-flight; a nested call fails with `Error::Reentrancy`.
+pub(crate) fn guarded<T>(env: &Env, f: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+    let store = env.storage().instance();
+    if store.has(&DataKey::Locked) {
+        return Err(Error::Reentrancy);
+    }
+
+    store.set(&DataKey::Locked, &true);
+    let result = f();
+    store.remove(&DataKey::Locked);
+    result
+}
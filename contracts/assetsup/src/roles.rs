@@ -0,0 +1,144 @@
+use crate::error::Error;
+use crate::types::{TokenDataKey, TokenizedAsset};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+/// Global: may register assets, in addition to the authorized registrars
+pub const REGISTRAR: Symbol = symbol_short!("REGISTRAR");
+/// Asset scope: may mint on the asset, in addition to its tokenizer
+pub const MINTER: Symbol = symbol_short!("MINTER");
+/// Asset scope: passes the asset's tokenizer checks on the tokenizer's behalf
+pub const OPERATOR: Symbol = symbol_short!("OPERATOR");
+/// Insurer scope: may act for the insurer on its policies and claims
+pub const INSURER: Symbol = symbol_short!("INSURER");
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Role(Symbol, Address),
+    ScopedRole(Symbol, RoleScope, Address),
+}
+
+/// What a scoped role applies to, and so who may grant it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RoleScope {
+    /// A tokenized asset; granted by its tokenizer
+    Asset(u64),
+    /// The policies and claims written by an insurer; granted by the insurer
+    Insurer(Address),
+}
+
+/// Grant `role` to `address` (admin only)
+pub fn grant_role(env: &Env, role: Symbol, address: Address, admin: Address) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Role(role.clone(), address.clone()), &true);
+
+    env.events()
+        .publish(("roles", "granted"), (role, address, admin));
+
+    Ok(())
+}
+
+/// Revoke `role` from `address` (admin only)
+pub fn revoke_role(env: &Env, role: Symbol, address: Address, admin: Address) -> Result<(), Error> {
+    require_admin(env, &admin)?;
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Role(role.clone(), address.clone()));
+
+    env.events()
+        .publish(("roles", "revoked"), (role, address, admin));
+
+    Ok(())
+}
+
+/// Check if `address` holds `role`
+pub fn has_role(env: &Env, role: Symbol, address: Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Role(role, address))
+}
+
+/// Grant `role` within `scope` to `address` (only the scope's owner can call)
+pub fn grant_scoped_role(
+    env: &Env,
+    role: Symbol,
+    scope: RoleScope,
+    address: Address,
+    caller: Address,
+) -> Result<(), Error> {
+    require_scope_owner(env, &scope, &caller)?;
+
+    env.storage().persistent().set(
+        &DataKey::ScopedRole(role.clone(), scope.clone(), address.clone()),
+        &true,
+    );
+
+    env.events()
+        .publish(("roles", "scoped_granted"), (role, scope, address, caller));
+
+    Ok(())
+}
+
+/// Revoke `role` within `scope` from `address` (only the scope's owner can call)
+pub fn revoke_scoped_role(
+    env: &Env,
+    role: Symbol,
+    scope: RoleScope,
+    address: Address,
+    caller: Address,
+) -> Result<(), Error> {
+    require_scope_owner(env, &scope, &caller)?;
+
+    env.storage().persistent().remove(&DataKey::ScopedRole(
+        role.clone(),
+        scope.clone(),
+        address.clone(),
+    ));
+
+    env.events()
+        .publish(("roles", "scoped_revoked"), (role, scope, address, caller));
+
+    Ok(())
+}
+
+/// Check if `address` holds `role` within `scope`
+pub fn has_scoped_role(env: &Env, role: Symbol, scope: RoleScope, address: Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::ScopedRole(role, scope, address))
+}
+
+fn require_scope_owner(env: &Env, scope: &RoleScope, caller: &Address) -> Result<(), Error> {
+    let owner = match scope {
+        RoleScope::Asset(asset_id) => {
+            let tokenized_asset: TokenizedAsset = env
+                .storage()
+                .persistent()
+                .get(&TokenDataKey::TokenizedAsset(*asset_id))
+                .ok_or(Error::AssetNotTokenized)?;
+            tokenized_asset.tokenizer
+        }
+        RoleScope::Insurer(insurer) => insurer.clone(),
+    };
+    if owner != *caller {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
+
+fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+    let stored: Address = env
+        .storage()
+        .persistent()
+        .get(&crate::DataKey::Admin)
+        .ok_or(Error::ContractNotInitialized)?;
+    if stored != *admin {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
@@ -1,14 +1,16 @@
+use crate::error::Error;
 use crate::tests::helpers::*;
 use soroban_sdk::{testutils::Address as _, Address};
 
 #[test]
-fn test_update_admin_success() {
+fn test_admin_handover_success() {
     let env = create_env();
     let (admin, new_admin, _, _) = create_mock_addresses(&env);
     let client = initialize_contract(&env, &admin);
 
     env.mock_all_auths();
-    client.update_admin(&new_admin);
+    client.propose_new_admin(&admin, &new_admin);
+    client.accept_admin(&new_admin);
 
     // Verify admin was updated
     assert_eq!(client.get_admin(), new_admin);
@@ -22,7 +24,7 @@ fn test_update_admin_success() {
 
 #[test]
 #[should_panic(expected = "Error(Contract, #39)")]
-fn test_update_admin_zero_address() {
+fn test_propose_zero_address_admin() {
     let env = create_env();
     let admin = Address::generate(&env);
     let client = initialize_contract(&env, &admin);
@@ -35,7 +37,7 @@ fn test_update_admin_zero_address() {
     env.mock_all_auths();
 
     // Should panic with InvalidOwnerAddress error
-    client.update_admin(&zero_address);
+    client.propose_new_admin(&admin, &zero_address);
 }
 
 #[test]
@@ -103,3 +105,78 @@ fn test_remove_admin_from_registrars() {
     // Should panic with Unauthorized error - cannot remove admin
     client.remove_authorized_registrar(&admin);
 }
+
+#[test]
+fn test_pause_blocks_token_operations() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+
+    client.pause_contract();
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user1, &user2, &100i128, &None),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_mint_tokens(&1u64, &100i128, &user1),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_burn_tokens(&1u64, &100i128, &user1),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_claim_dividends(&1u64, &user1),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    // Getters keep working while paused
+    assert_eq!(client.get_token_balance(&1u64, &user1), 1000);
+    assert_eq!(client.get_tokenized_asset(&1u64).total_supply, 1000);
+
+    client.unpause_contract();
+    client.transfer_tokens(&1u64, &user1, &user2, &100i128, &None);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 100);
+}
+
+#[test]
+fn test_two_step_admin_handover() {
+    let env = create_env();
+    let (admin, new_admin, user1, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+
+    assert_eq!(
+        client.try_propose_new_admin(&user1, &new_admin),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.propose_new_admin(&admin, &new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    // Nothing changes until the proposed admin accepts
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(
+        client.try_accept_admin(&user1),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+    assert!(client.is_authorized_registrar(&new_admin));
+    assert!(!client.is_authorized_registrar(&admin));
+
+    // The old admin has lost its privileges
+    assert_eq!(
+        client.try_propose_new_admin(&admin, &user1),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_accept_admin(&new_admin),
+        Err(Ok(Error::Unauthorized))
+    );
+}
@@ -1,6 +1,9 @@
+use crate::asset::{DepreciationMethod, MaintenanceRecord};
+use crate::error::Error;
 use crate::tests::helpers::*;
 use crate::types::AssetStatus;
-use soroban_sdk::{testutils::Address as _, Address, String, Vec};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, String, Vec};
 
 #[test]
 fn test_register_asset_success() {
@@ -207,7 +210,7 @@ fn test_transfer_asset_ownership_success() {
     client.register_asset(&asset, &admin);
 
     // Transfer ownership
-    client.transfer_asset_ownership(&asset_id, &user2, &user1);
+    client.transfer_asset_ownership(&asset_id, &user2, &user1, &false);
 
     // Verify ownership was transferred
     let transferred_asset = client.get_asset(&asset_id);
@@ -237,7 +240,26 @@ fn test_transfer_asset_ownership_unauthorized() {
     client.register_asset(&asset, &admin);
 
     // user3 is not owner - should panic with Unauthorized
-    client.transfer_asset_ownership(&asset_id, &user2, &user3);
+    client.transfer_asset_ownership(&asset_id, &user2, &user3, &false);
+}
+
+#[test]
+fn test_transfer_asset_ownership_requires_owner_auth() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 1);
+    let asset = create_test_asset(&env, &user1, asset_id.clone());
+
+    env.mock_all_auths();
+    client.register_asset(&asset, &admin);
+
+    // Naming the owner as caller is not enough without the owner's signature
+    env.set_auths(&[]);
+    let result = client.try_transfer_asset_ownership(&asset_id, &user2, &user1, &true);
+    assert!(result.is_err());
+    assert_eq!(client.get_asset(&asset_id).owner, user1);
 }
 
 #[test]
@@ -338,3 +360,511 @@ fn test_batch_get_asset_info() {
     let infos = client.batch_get_asset_info(&ids);
     assert_eq!(infos.len(), 2);
 }
+
+#[test]
+fn test_transfer_assets_batch() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let mut asset_ids = Vec::new(&env);
+    for i in 1..=3u32 {
+        let asset_id = generate_asset_id(&env, i);
+        client.register_asset(&create_test_asset(&env, &user1, asset_id.clone()), &admin);
+        asset_ids.push_back(asset_id);
+    }
+
+    client.transfer_assets_batch(&asset_ids, &user2, &user1);
+
+    assert_eq!(client.get_assets_by_owner(&user1).len(), 0);
+    assert_eq!(client.get_assets_by_owner(&user2), asset_ids);
+    for asset_id in asset_ids.iter() {
+        let asset = client.get_asset(&asset_id);
+        assert_eq!(asset.owner, user2);
+        assert_eq!(asset.status, AssetStatus::Transferred);
+        let log = client.get_asset_audit_logs(&asset_id);
+        assert_eq!(
+            log.last().unwrap().action,
+            String::from_str(&env, "OWNERSHIP_TRANSFERRED")
+        );
+    }
+}
+
+#[test]
+fn test_transfer_assets_batch_rejects_unowned_asset() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let owned = generate_asset_id(&env, 1);
+    let foreign = generate_asset_id(&env, 2);
+    client.register_asset(&create_test_asset(&env, &user1, owned.clone()), &admin);
+    client.register_asset(&create_test_asset(&env, &user3, foreign.clone()), &admin);
+
+    let batch = vec![&env, owned.clone(), foreign.clone()];
+    assert_eq!(
+        client.try_transfer_assets_batch(&batch, &user2, &user1),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.check_transfer_batch(&batch, &user1),
+        Some(foreign.clone())
+    );
+    assert_eq!(
+        client.check_transfer_batch(&vec![&env, owned.clone()], &user1),
+        None
+    );
+
+    // Nothing moved, including the asset the caller does own
+    assert_eq!(client.get_asset(&owned).owner, user1);
+    assert_eq!(client.get_asset(&foreign).owner, user3);
+    assert_eq!(client.get_assets_by_owner(&user2).len(), 0);
+}
+
+#[test]
+fn test_straight_line_depreciation() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let asset = create_test_asset(&env, &user1, generate_asset_id(&env, 1));
+    client.register_asset(&asset, &admin);
+
+    // Without a schedule the book value is the purchase value
+    assert_eq!(client.current_book_value(&asset.id), 1000);
+
+    assert_eq!(
+        client.try_set_depreciation(
+            &asset.id,
+            &DepreciationMethod::StraightLine,
+            &1_000u64,
+            &200i128,
+            &user2
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_depreciation(
+            &asset.id,
+            &DepreciationMethod::StraightLine,
+            &1_000u64,
+            &1_001i128,
+            &user1
+        ),
+        Err(Ok(Error::InvalidPurchaseValue))
+    );
+    client.pause_contract();
+    assert_eq!(
+        client.try_set_depreciation(
+            &asset.id,
+            &DepreciationMethod::StraightLine,
+            &1_000u64,
+            &200i128,
+            &user1
+        ),
+        Err(Ok(Error::ContractPaused))
+    );
+    client.unpause_contract();
+    client.set_depreciation(
+        &asset.id,
+        &DepreciationMethod::StraightLine,
+        &1_000u64,
+        &200i128,
+        &user1,
+    );
+
+    // At registration time
+    assert_eq!(client.current_book_value(&asset.id), 1000);
+
+    // Mid-life: half of the depreciable 800 is gone
+    env.ledger().with_mut(|li| li.timestamp = 1_500);
+    assert_eq!(client.current_book_value(&asset.id), 600);
+
+    // Past useful life the value floors at salvage
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    assert_eq!(client.current_book_value(&asset.id), 200);
+}
+
+#[test]
+fn test_set_and_get_asset_attribute() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let asset = create_test_asset(&env, &user1, generate_asset_id(&env, 1));
+    client.register_asset(&asset, &admin);
+
+    let color = String::from_str(&env, "color");
+    // Missing keys read as None
+    assert_eq!(client.get_asset_attribute(&asset.id, &color), None);
+
+    assert_eq!(
+        client.try_set_asset_attribute(&asset.id, &color, &String::from_str(&env, "red"), &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_asset_attribute(&asset.id, &color, &String::from_str(&env, "red"), &user1);
+    assert_eq!(
+        client.get_asset_attribute(&asset.id, &color),
+        Some(String::from_str(&env, "red"))
+    );
+
+    // Setting an existing key overwrites it in place
+    client.set_asset_attribute(&asset.id, &color, &String::from_str(&env, "blue"), &user1);
+    assert_eq!(
+        client.get_asset_attribute(&asset.id, &color),
+        Some(String::from_str(&env, "blue"))
+    );
+    assert_eq!(client.get_asset(&asset.id).custom_attributes.len(), 1);
+
+    let log = client.get_asset_audit_logs(&asset.id);
+    let entry = log.last().unwrap();
+    assert_eq!(entry.action, String::from_str(&env, "ATTRIBUTE_UPDATED"));
+    assert_eq!(entry.details, color);
+}
+
+#[test]
+fn test_register_assets_batch() {
+    let env = create_env();
+    let (admin, user1, _, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let mut assets = Vec::new(&env);
+    for seed in 1..=5u32 {
+        assets.push_back(create_test_asset(
+            &env,
+            &user1,
+            generate_asset_id(&env, seed),
+        ));
+    }
+
+    client.register_assets_batch(&assets, &admin);
+
+    assert_eq!(client.get_total_asset_count(), 5);
+    assert_eq!(client.get_assets_by_owner(&user1).len(), 5);
+    for asset in assets.iter() {
+        assert_eq!(client.get_asset(&asset.id), asset);
+        assert_eq!(client.get_asset_audit_logs(&asset.id).len(), 1);
+    }
+}
+
+#[test]
+fn test_register_assets_batch_with_duplicate_reverts() {
+    let env = create_env();
+    let (admin, user1, _, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let existing = create_test_asset(&env, &user1, generate_asset_id(&env, 9));
+    client.register_asset(&existing, &admin);
+
+    let fresh = create_test_asset(&env, &user1, generate_asset_id(&env, 1));
+
+    // A repeated id within the batch
+    let assets = vec![&env, fresh.clone(), fresh.clone()];
+    assert_eq!(
+        client.try_register_assets_batch(&assets, &admin),
+        Err(Ok(Error::AssetAlreadyExists))
+    );
+
+    // An id that is already registered
+    let assets = vec![&env, fresh.clone(), existing.clone()];
+    assert_eq!(
+        client.try_register_assets_batch(&assets, &admin),
+        Err(Ok(Error::AssetAlreadyExists))
+    );
+    assert_eq!(
+        client.check_register_batch(&assets),
+        Some(existing.id.clone())
+    );
+    assert_eq!(
+        client.check_register_batch(&vec![&env, fresh.clone()]),
+        None
+    );
+
+    // Nothing from either batch was stored
+    assert_eq!(client.get_total_asset_count(), 1);
+    assert!(client.try_get_asset(&fresh.id).is_err());
+    assert_eq!(client.get_assets_by_owner(&user1).len(), 1);
+}
+
+#[test]
+fn test_asset_status_legal_transitions() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let asset = create_test_asset(&env, &user1, generate_asset_id(&env, 1));
+    client.register_asset(&asset, &admin);
+
+    // Active -> Suspended -> Active
+    client.transition_asset_status(&asset.id, &AssetStatus::Suspended, &user1);
+    assert_eq!(client.get_asset(&asset.id).status, AssetStatus::Suspended);
+    client.transition_asset_status(&asset.id, &AssetStatus::Active, &admin);
+    assert_eq!(client.get_asset(&asset.id).status, AssetStatus::Active);
+
+    // Active -> Transferred -> Suspended -> Retired
+    client.transfer_asset_ownership(&asset.id, &user2, &user1, &false);
+    client.transition_asset_status(&asset.id, &AssetStatus::Suspended, &user2);
+    client.transition_asset_status(&asset.id, &AssetStatus::Retired, &user2);
+    assert_eq!(client.get_asset(&asset.id).status, AssetStatus::Retired);
+
+    let mut actions = Vec::new(&env);
+    for entry in client.get_asset_audit_logs(&asset.id).iter() {
+        actions.push_back(entry.action);
+    }
+    assert!(actions.contains(String::from_str(&env, "ASSET_SUSPENDED")));
+    assert!(actions.contains(String::from_str(&env, "ASSET_REACTIVATED")));
+    assert!(actions.contains(String::from_str(&env, "ASSET_RETIRED")));
+
+    // Active -> Retired directly
+    let other = create_test_asset(&env, &user1, generate_asset_id(&env, 2));
+    client.register_asset(&other, &admin);
+    client.retire_asset(&other.id, &user1);
+    assert_eq!(client.get_asset(&other.id).status, AssetStatus::Retired);
+}
+
+#[test]
+fn test_asset_status_illegal_transitions() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let asset = create_test_asset(&env, &user1, generate_asset_id(&env, 1));
+    client.register_asset(&asset, &admin);
+
+    // No-op and flow-only targets are rejected
+    for status in [
+        AssetStatus::Active,
+        AssetStatus::Transferred,
+        AssetStatus::Tokenized,
+    ] {
+        assert_eq!(
+            client.try_transition_asset_status(&asset.id, &status, &user1),
+            Err(Ok(Error::InvalidStatusTransition))
+        );
+    }
+
+    // Suspended assets cannot change hands
+    client.transition_asset_status(&asset.id, &AssetStatus::Suspended, &user1);
+    assert_eq!(
+        client.try_transfer_asset_ownership(&asset.id, &user2, &user1, &false),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+
+    // Retirement is final
+    client.retire_asset(&asset.id, &user1);
+    assert_eq!(
+        client.try_transition_asset_status(&asset.id, &AssetStatus::Active, &user1),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+    assert_eq!(
+        client.try_retire_asset(&asset.id, &user1),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+}
+
+#[test]
+fn test_lien_blocks_ownership_transfer() {
+    let env = create_env();
+    let (admin, user1, user2, lender) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let asset = create_test_asset(&env, &user1, generate_asset_id(&env, 1));
+    client.register_asset(&asset, &admin);
+
+    assert_eq!(
+        client.try_place_lien(&asset.id, &lender, &500i128, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.place_lien(&asset.id, &lender, &500i128, &user1);
+    client.place_lien(&asset.id, &lender, &250i128, &user1);
+
+    let liens = client.get_liens(&asset.id);
+    assert_eq!(liens.len(), 1);
+    assert_eq!(liens.get(0).unwrap().amount, 750);
+
+    assert_eq!(
+        client.try_transfer_asset_ownership(&asset.id, &user2, &user1, &false),
+        Err(Ok(Error::AssetEncumbered))
+    );
+
+    // Only the lienholder can release
+    assert_eq!(
+        client.try_release_lien(&asset.id, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.release_lien(&asset.id, &lender);
+    assert_eq!(client.get_liens(&asset.id).len(), 0);
+
+    client.transfer_asset_ownership(&asset.id, &user2, &user1, &false);
+    assert_eq!(client.get_asset(&asset.id).owner, user2);
+}
+
+#[test]
+fn test_cascading_transfer_moves_child_assets() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let parent = create_test_asset(&env, &user1, generate_asset_id(&env, 1));
+    let child = create_test_asset(&env, &user1, generate_asset_id(&env, 2));
+    let grandchild = create_test_asset(&env, &user1, generate_asset_id(&env, 3));
+    client.register_asset(&parent, &admin);
+    client.register_asset(&child, &admin);
+    client.register_asset(&grandchild, &admin);
+
+    client.link_child_asset(&parent.id, &child.id, &user1);
+    client.link_child_asset(&child.id, &grandchild.id, &user1);
+    assert_eq!(
+        client.get_child_assets(&parent.id),
+        vec![&env, child.id.clone()]
+    );
+
+    // Without cascade only the parent moves
+    client.transfer_asset_ownership(&parent.id, &user2, &user1, &false);
+    assert_eq!(client.get_asset(&parent.id).owner, user2);
+    assert_eq!(client.get_asset(&child.id).owner, user1);
+
+    // A blocked descendant stops the whole cascade
+    client.transfer_asset_ownership(&parent.id, &user1, &user2, &false);
+    client.place_lien(&grandchild.id, &admin, &100i128, &user1);
+    assert_eq!(
+        client.try_transfer_asset_ownership(&parent.id, &user2, &user1, &true),
+        Err(Ok(Error::AssetEncumbered))
+    );
+    assert_eq!(client.get_asset(&parent.id).owner, user1);
+
+    client.release_lien(&grandchild.id, &admin);
+    client.transfer_asset_ownership(&parent.id, &user2, &user1, &true);
+    assert_eq!(client.get_asset(&parent.id).owner, user2);
+    assert_eq!(client.get_asset(&child.id).owner, user2);
+    assert_eq!(client.get_asset(&grandchild.id).owner, user2);
+}
+
+#[test]
+fn test_link_child_asset_rejects_cycles() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let a = create_test_asset(&env, &user1, generate_asset_id(&env, 1));
+    let b = create_test_asset(&env, &user1, generate_asset_id(&env, 2));
+    let c = create_test_asset(&env, &user1, generate_asset_id(&env, 3));
+    let other = create_test_asset(&env, &user2, generate_asset_id(&env, 4));
+    client.register_asset(&a, &admin);
+    client.register_asset(&b, &admin);
+    client.register_asset(&c, &admin);
+    client.register_asset(&other, &admin);
+
+    assert_eq!(
+        client.try_link_child_asset(&a.id, &a.id, &user1),
+        Err(Ok(Error::InvalidHierarchy))
+    );
+    assert_eq!(
+        client.try_link_child_asset(&a.id, &other.id, &user1),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.link_child_asset(&a.id, &b.id, &user1);
+    client.link_child_asset(&b.id, &c.id, &user1);
+
+    // c -> a would make a its own ancestor
+    assert_eq!(
+        client.try_link_child_asset(&c.id, &a.id, &user1),
+        Err(Ok(Error::InvalidHierarchy))
+    );
+    // b already has a parent
+    assert_eq!(
+        client.try_link_child_asset(&c.id, &b.id, &user1),
+        Err(Ok(Error::InvalidHierarchy))
+    );
+}
+
+#[test]
+fn test_asset_location_history() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let asset = create_test_asset(&env, &user1, generate_asset_id(&env, 1));
+    client.register_asset(&asset, &admin);
+
+    let warehouse = String::from_str(&env, "Warehouse A");
+    let port = String::from_str(&env, "Port of Lagos");
+    let site = String::from_str(&env, "Site 7");
+
+    assert_eq!(
+        client.try_update_asset_location(&asset.id, &warehouse, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.update_asset_location(&asset.id, &warehouse, &user1);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    client.update_asset_location(&asset.id, &port, &admin);
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    client.update_asset_location(&asset.id, &site, &user1);
+
+    assert_eq!(client.get_asset(&asset.id).location, site);
+
+    let history = client.get_location_history(&asset.id);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(0).unwrap().location, warehouse);
+    assert_eq!(history.get(1).unwrap().location, port);
+    assert_eq!(history.get(1).unwrap().moved_by, admin);
+    assert_eq!(history.get(2).unwrap().location, site);
+    assert_eq!(
+        history.get(2).unwrap().moved_at,
+        history.get(0).unwrap().moved_at + 200
+    );
+}
+
+#[test]
+fn test_maintenance_records_total_cost() {
+    let env = create_env();
+    let (admin, user1, user2, technician) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let asset = create_test_asset(&env, &user1, generate_asset_id(&env, 1));
+    client.register_asset(&asset, &admin);
+
+    let record = |cost: i128, description: &str| MaintenanceRecord {
+        timestamp: env.ledger().timestamp(),
+        performed_by: technician.clone(),
+        description: String::from_str(&env, description),
+        cost,
+    };
+
+    assert_eq!(
+        client.try_add_maintenance_record(&asset.id, &record(100, "Oil change"), &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_add_maintenance_record(&asset.id, &record(-1, "Refund"), &user1),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    client.add_maintenance_record(&asset.id, &record(100, "Oil change"), &user1);
+    client.add_maintenance_record(&asset.id, &record(250, "Brake pads"), &admin);
+    client.add_maintenance_record(&asset.id, &record(75, "Inspection"), &user1);
+
+    let records = client.get_maintenance_records(&asset.id);
+    assert_eq!(records.len(), 3);
+    assert_eq!(
+        records.get(1).unwrap().description,
+        String::from_str(&env, "Brake pads")
+    );
+    assert_eq!(client.total_maintenance_cost(&asset.id), 425);
+}
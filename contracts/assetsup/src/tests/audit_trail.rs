@@ -1,8 +1,10 @@
 #![cfg(test)]
 
+use crate::error::Error;
 use crate::types::AssetStatus;
-use crate::{asset, AssetUpContract, AssetUpContractClient};
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String, Vec};
+use crate::{asset, audit, AssetUpContract, AssetUpContractClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, BytesN, Env, String, Vec};
 
 fn create_test_asset(env: &Env, id: BytesN<32>, owner: Address) -> asset::Asset {
     asset::Asset {
@@ -17,6 +19,7 @@ fn create_test_asset(env: &Env, id: BytesN<32>, owner: Address) -> asset::Asset
         metadata_uri: String::from_str(env, "ipfs://QmTest123"),
         purchase_value: 1000,
         custom_attributes: Vec::new(env),
+        location: String::from_str(env, ""),
     }
 }
 
@@ -76,7 +79,7 @@ fn test_audit_log_on_ownership_transfer() {
     client.register_asset(&asset, &admin);
 
     // Transfer ownership
-    client.transfer_asset_ownership(&asset_id, &new_owner, &owner);
+    client.transfer_asset_ownership(&asset_id, &new_owner, &owner, &false);
 
     // Get audit log
     let logs = client.get_asset_audit_logs(&asset_id);
@@ -157,7 +160,7 @@ fn test_audit_log_chronological_order() {
     );
 
     // Transfer ownership
-    client.transfer_asset_ownership(&asset_id, &new_owner, &owner);
+    client.transfer_asset_ownership(&asset_id, &new_owner, &owner, &false);
 
     // Get audit log
     let logs = client.get_asset_audit_logs(&asset_id);
@@ -186,6 +189,382 @@ fn test_audit_log_chronological_order() {
     );
 }
 
+#[test]
+fn test_get_audit_entry_by_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(AssetUpContract, ());
+    let client = AssetUpContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.add_authorized_registrar(&admin);
+
+    let asset_id = BytesN::from_array(&env, &[5u8; 32]);
+    let asset = create_test_asset(&env, asset_id.clone(), owner.clone());
+    client.register_asset(&asset, &admin);
+    client.transfer_asset_ownership(&asset_id, &new_owner, &owner, &false);
+
+    // First entry
+    let first = client.get_audit_entry(&asset_id, &0).unwrap();
+    assert_eq!(first.action, String::from_str(&env, "ASSET_REGISTERED"));
+
+    // Last entry
+    let last = client.get_audit_entry(&asset_id, &1).unwrap();
+    assert_eq!(last.action, String::from_str(&env, "OWNERSHIP_TRANSFERRED"));
+    assert_eq!(last.actor, owner);
+
+    // Out of range and unknown asset
+    assert_eq!(client.get_audit_entry(&asset_id, &2), None);
+    let unknown = BytesN::from_array(&env, &[6u8; 32]);
+    assert_eq!(client.get_audit_entry(&unknown, &0), None);
+}
+
+#[test]
+fn test_import_audit_entries() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+
+    let contract_id = env.register(AssetUpContract, ());
+    let client = AssetUpContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let legacy_actor = Address::generate(&env);
+    client.initialize(&admin);
+
+    let asset_id = BytesN::from_array(&env, &[7u8; 32]);
+    let entry = |timestamp: u64, action: &str| audit::AuditEntry {
+        timestamp,
+        action: String::from_str(&env, action),
+        actor: legacy_actor.clone(),
+        details: String::from_str(&env, "Migrated"),
+        prev_hash: BytesN::from_array(&env, &[0u8; 32]),
+    };
+
+    let mut entries = Vec::new(&env);
+    entries.push_back(entry(1_000, "ASSET_REGISTERED"));
+    entries.push_back(entry(5_000, "OWNERSHIP_TRANSFERRED"));
+
+    // Only the admin may import
+    let result = client.try_import_audit_entries(&asset_id, &entries, &legacy_actor);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.import_audit_entries(&asset_id, &entries, &admin);
+
+    let logs = client.get_asset_audit_logs(&asset_id);
+    assert_eq!(logs.len(), 3);
+    let marker = logs.get(0).unwrap();
+    assert_eq!(marker.action, String::from_str(&env, "AUDIT_IMPORT"));
+    assert_eq!(marker.actor, admin);
+    assert_eq!(marker.timestamp, 10_000);
+    let imported = logs.get(1).unwrap();
+    assert_eq!(imported.timestamp, 1_000);
+    assert_eq!(imported.action, String::from_str(&env, "ASSET_REGISTERED"));
+    let imported = logs.get(2).unwrap();
+    assert_eq!(imported.timestamp, 5_000);
+    assert_eq!(
+        imported.action,
+        String::from_str(&env, "OWNERSHIP_TRANSFERRED")
+    );
+
+    // Imported entries are linked onto the hash chain
+    assert!(client.verify_audit_chain(&asset_id));
+
+    // Out-of-order batches are rejected
+    let mut unordered = Vec::new(&env);
+    unordered.push_back(entry(5_000, "A"));
+    unordered.push_back(entry(1_000, "B"));
+    let result = client.try_import_audit_entries(&asset_id, &unordered, &admin);
+    assert_eq!(result, Err(Ok(Error::InvalidTimestamps)));
+}
+
+#[test]
+fn test_audit_hash_chain_verifies() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(AssetUpContract, ());
+    let client = AssetUpContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.add_authorized_registrar(&admin);
+
+    // An empty log is trivially intact
+    let asset_id = BytesN::from_array(&env, &[8u8; 32]);
+    assert!(client.verify_audit_chain(&asset_id));
+
+    let asset = create_test_asset(&env, asset_id.clone(), owner.clone());
+    client.register_asset(&asset, &admin);
+    client.transfer_asset_ownership(&asset_id, &new_owner, &owner, &false);
+    client.retire_asset(&asset_id, &new_owner);
+
+    let logs = client.get_asset_audit_logs(&asset_id);
+    assert_eq!(logs.len(), 3);
+    assert_eq!(
+        logs.get(0).unwrap().prev_hash,
+        BytesN::from_array(&env, &[0u8; 32])
+    );
+    assert_ne!(
+        logs.get(1).unwrap().prev_hash,
+        logs.get(2).unwrap().prev_hash
+    );
+    assert!(client.verify_audit_chain(&asset_id));
+}
+
+#[test]
+fn test_audit_hash_chain_detects_tampering() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(AssetUpContract, ());
+    let client = AssetUpContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.add_authorized_registrar(&admin);
+
+    let asset_id = BytesN::from_array(&env, &[9u8; 32]);
+    let asset = create_test_asset(&env, asset_id.clone(), owner.clone());
+    client.register_asset(&asset, &admin);
+    client.transfer_asset_ownership(&asset_id, &new_owner, &owner, &false);
+    client.retire_asset(&asset_id, &new_owner);
+    assert!(client.verify_audit_chain(&asset_id));
+
+    // Rewrite the actor of the middle entry directly in storage
+    let entry_key = audit::DataKey::AuditEntry(asset_id.clone(), 1);
+    let count_key = audit::DataKey::AuditCount(asset_id.clone());
+    let original: Vec<audit::AuditEntry> = client.get_asset_audit_logs(&asset_id);
+    let mut entry = original.get(1).unwrap();
+    entry.actor = admin.clone();
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&entry_key, &entry);
+    });
+    assert!(!client.verify_audit_chain(&asset_id));
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&entry_key, &original.get(1).unwrap());
+    });
+    assert!(client.verify_audit_chain(&asset_id));
+
+    // Dropping the latest entry breaks the link to the stored head
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&count_key, &(original.len() - 1));
+    });
+    assert!(!client.verify_audit_chain(&asset_id));
+
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&count_key, &original.len());
+    });
+    assert!(client.verify_audit_chain(&asset_id));
+}
+
+#[test]
+fn test_filter_audit_log_by_action_and_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+
+    let contract_id = env.register(AssetUpContract, ());
+    let client = AssetUpContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let asset_id = BytesN::from_array(&env, &[10u8; 32]);
+    let entry = |timestamp: u64, action: &str| audit::AuditEntry {
+        timestamp,
+        action: String::from_str(&env, action),
+        actor: admin.clone(),
+        details: String::from_str(&env, "Migrated"),
+        prev_hash: BytesN::from_array(&env, &[0u8; 32]),
+    };
+    let mut entries = Vec::new(&env);
+    entries.push_back(entry(1_000, "ASSET_REGISTERED"));
+    entries.push_back(entry(2_000, "OWNERSHIP_TRANSFERRED"));
+    entries.push_back(entry(3_000, "ASSET_UPDATED"));
+    entries.push_back(entry(4_000, "OWNERSHIP_TRANSFERRED"));
+    // Preceded by the AUDIT_IMPORT marker at 10_000
+    client.import_audit_entries(&asset_id, &entries, &admin);
+
+    let timestamps = |action: Option<&str>, from: Option<u64>, to: Option<u64>| {
+        let mut found = Vec::new(&env);
+        for e in client
+            .get_asset_log_filtered(
+                &asset_id,
+                &action.map(|a| String::from_str(&env, a)),
+                &from,
+                &to,
+            )
+            .iter()
+        {
+            found.push_back(e.timestamp);
+        }
+        found
+    };
+
+    // No filters matches the full log
+    assert_eq!(
+        client.get_asset_log_filtered(&asset_id, &None, &None, &None),
+        client.get_asset_audit_logs(&asset_id)
+    );
+
+    let transferred = Some("OWNERSHIP_TRANSFERRED");
+    assert_eq!(
+        timestamps(transferred, None, None),
+        vec![&env, 2_000u64, 4_000]
+    );
+    assert_eq!(
+        timestamps(None, Some(3_000), None),
+        vec![&env, 10_000u64, 3_000, 4_000]
+    );
+    assert_eq!(
+        timestamps(None, None, Some(2_000)),
+        vec![&env, 1_000u64, 2_000]
+    );
+    assert_eq!(
+        timestamps(None, Some(2_000), Some(3_000)),
+        vec![&env, 2_000u64, 3_000]
+    );
+    assert_eq!(
+        timestamps(transferred, Some(3_000), Some(9_000)),
+        vec![&env, 4_000u64]
+    );
+    assert_eq!(timestamps(Some("ASSET_RETIRED"), None, None).len(), 0);
+}
+
+#[test]
+fn test_audit_log_paging() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+
+    let contract_id = env.register(AssetUpContract, ());
+    let client = AssetUpContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    // 119 imported entries plus the AUDIT_IMPORT marker make 120
+    let asset_id = BytesN::from_array(&env, &[11u8; 32]);
+    let mut entries = Vec::new(&env);
+    for i in 1..120u64 {
+        entries.push_back(audit::AuditEntry {
+            timestamp: i,
+            action: String::from_str(&env, "ASSET_UPDATED"),
+            actor: admin.clone(),
+            details: String::from_str(&env, "Migrated"),
+            prev_hash: BytesN::from_array(&env, &[0u8; 32]),
+        });
+    }
+    client.import_audit_entries(&asset_id, &entries, &admin);
+    assert_eq!(client.get_asset_log_count(&asset_id), 120);
+
+    // Pages are capped at 50 and walk the log in order
+    let first = client.get_asset_log_paged(&asset_id, &0u32, &100u32);
+    assert_eq!(first.len(), 50);
+    assert_eq!(first.get(0).unwrap().timestamp, 10_000);
+    assert_eq!(first.get(49).unwrap().timestamp, 49);
+
+    let second = client.get_asset_log_paged(&asset_id, &50u32, &50u32);
+    assert_eq!(second.len(), 50);
+    assert_eq!(second.get(0).unwrap().timestamp, 50);
+
+    let last = client.get_asset_log_paged(&asset_id, &100u32, &50u32);
+    assert_eq!(last.len(), 20);
+    assert_eq!(last.get(19).unwrap().timestamp, 119);
+
+    // Beyond the end is empty
+    assert_eq!(
+        client.get_asset_log_paged(&asset_id, &120u32, &50u32).len(),
+        0
+    );
+    assert_eq!(
+        client
+            .get_asset_log_paged(&asset_id, &u32::MAX, &u32::MAX)
+            .len(),
+        0
+    );
+    assert_eq!(
+        client
+            .get_asset_log_paged(&asset_id, &119u32, &u32::MAX)
+            .len(),
+        1
+    );
+    assert_eq!(
+        client.get_asset_log_count(&BytesN::from_array(&env, &[12u8; 32])),
+        0
+    );
+}
+
+#[test]
+fn test_actor_audit_log_isolated_per_actor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(AssetUpContract, ());
+    let client = AssetUpContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.add_authorized_registrar(&admin);
+
+    let asset_id = BytesN::from_array(&env, &[13u8; 32]);
+    let asset = create_test_asset(&env, asset_id.clone(), owner.clone());
+    client.register_asset(&asset, &admin);
+    client.transfer_asset_ownership(&asset_id, &new_owner, &owner, &false);
+    client.retire_asset(&asset_id, &new_owner);
+
+    let actions = |actor: &Address| {
+        let mut actions = Vec::new(&env);
+        for entry in client.get_actor_audit_log(&asset_id, actor).iter() {
+            assert_eq!(entry.actor, *actor);
+            actions.push_back(entry.action);
+        }
+        actions
+    };
+
+    assert_eq!(
+        actions(&admin),
+        vec![&env, String::from_str(&env, "ASSET_REGISTERED")]
+    );
+    assert_eq!(
+        actions(&owner),
+        vec![&env, String::from_str(&env, "OWNERSHIP_TRANSFERRED")]
+    );
+    assert_eq!(
+        actions(&new_owner),
+        vec![&env, String::from_str(&env, "ASSET_RETIRED")]
+    );
+
+    // Another asset's entries stay out of this asset's actor views
+    let other_id = BytesN::from_array(&env, &[14u8; 32]);
+    client.register_asset(
+        &create_test_asset(&env, other_id.clone(), owner.clone()),
+        &admin,
+    );
+    assert_eq!(actions(&admin).len(), 1);
+    assert_eq!(client.get_actor_audit_log(&other_id, &admin).len(), 1);
+    assert_eq!(client.get_actor_audit_log(&other_id, &owner).len(), 0);
+}
+
 #[test]
 fn test_empty_audit_log_for_nonexistent_asset() {
     let env = Env::default();
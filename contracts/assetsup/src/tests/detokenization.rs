@@ -1,6 +1,8 @@
+use crate::error::Error;
 use crate::tests::helpers::*;
-use crate::types::{AssetType, DetokenizationProposal};
-use soroban_sdk::String;
+use crate::tokenization::asset_id_to_bytes;
+use crate::types::{AssetStatus, AssetType, DetokenizationProposal};
+use soroban_sdk::{vec, String};
 
 #[test]
 fn test_propose_detokenization_success() {
@@ -20,6 +22,7 @@ fn test_propose_detokenization_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Propose detokenization
@@ -50,6 +53,7 @@ fn test_propose_detokenization_already_proposed() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     client.propose_detokenization(&1u64, &user1);
@@ -89,16 +93,17 @@ fn test_execute_detokenization_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Transfer 60% to user2
-    client.transfer_tokens(&1u64, &user1, &user2, &600000i128);
+    client.transfer_tokens(&1u64, &user1, &user2, &600000i128, &None);
 
     // Propose detokenization
     let proposal_id = client.propose_detokenization(&1u64, &user1);
 
     // Vote with majority
-    client.cast_vote(&1u64, &proposal_id, &user2);
+    client.cast_vote(&1u64, &proposal_id, &user2, &true);
 
     // Execute detokenization
     client.execute_detokenization(&1u64, &proposal_id);
@@ -126,23 +131,24 @@ fn test_execute_detokenization_not_approved() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Transfer 30% to user2 (not enough for majority)
-    client.transfer_tokens(&1u64, &user1, &user2, &300000i128);
+    client.transfer_tokens(&1u64, &user1, &user2, &300000i128, &None);
 
     // Propose detokenization
     let proposal_id = client.propose_detokenization(&1u64, &user1);
 
     // Vote with minority
-    client.cast_vote(&1u64, &proposal_id, &user2);
+    client.cast_vote(&1u64, &proposal_id, &user2, &true);
 
     // Should panic with DetokenizationNotApproved error
     client.execute_detokenization(&1u64, &proposal_id);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #24)")]
+#[should_panic(expected = "Error(Contract, #23)")]
 fn test_execute_detokenization_no_proposal() {
     let env = create_env();
     let (admin, user1, _, _) = create_mock_addresses(&env);
@@ -160,9 +166,10 @@ fn test_execute_detokenization_no_proposal() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
-    // Should panic with InvalidProposal error
+    // Should panic with ProposalNotFound error
     client.execute_detokenization(&1u64, &1u64);
 }
 
@@ -184,6 +191,7 @@ fn test_get_detokenization_proposal() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     let proposal_id = client.propose_detokenization(&1u64, &user1);
@@ -217,19 +225,396 @@ fn test_detokenization_clears_all_data() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Set up some data
-    client.transfer_tokens(&1u64, &user1, &user2, &600000i128);
+    client.transfer_tokens(&1u64, &user1, &user2, &600000i128, &None);
     client.add_to_whitelist(&1u64, &user2);
-    client.enable_revenue_sharing(&1u64);
+    client.set_revenue_sharing(&1u64, &true, &user1);
 
     // Propose and execute detokenization
     let proposal_id = client.propose_detokenization(&1u64, &user1);
-    client.cast_vote(&1u64, &proposal_id, &user2);
+    client.cast_vote(&1u64, &proposal_id, &user2, &true);
     client.execute_detokenization(&1u64, &proposal_id);
 
     // Verify whitelist is cleared
     let whitelist = client.get_whitelist(&1u64);
     assert_eq!(whitelist.len(), 0);
 }
+
+#[test]
+fn test_tokenization_syncs_registry_status() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+
+    // Register the underlying asset under the id tokenization refers to
+    let registry_id = asset_id_to_bytes(&env, 1u64);
+    let asset = create_test_asset(&env, &user1, registry_id.clone());
+    client.register_asset(&asset, &admin);
+    assert_eq!(client.get_asset(&registry_id).status, AssetStatus::Active);
+
+    tokenize_test_asset(&env, &client, 1u64, 1000000, &user1);
+    assert_eq!(
+        client.get_asset(&registry_id).status,
+        AssetStatus::Tokenized
+    );
+
+    // Registry transfers and retirement are blocked while tokenized
+    let result = client.try_transfer_asset_ownership(&registry_id, &user2, &user1, &false);
+    assert_eq!(result, Err(Ok(Error::AssetAlreadyTokenized)));
+    let result = client.try_retire_asset(&registry_id, &user1);
+    assert_eq!(result, Err(Ok(Error::AssetAlreadyTokenized)));
+
+    let proposal_id = client.propose_detokenization(&1u64, &user1);
+    client.cast_vote(&1u64, &proposal_id, &user1, &true);
+    client.execute_detokenization(&1u64, &proposal_id);
+
+    assert_eq!(client.get_asset(&registry_id).status, AssetStatus::Active);
+    client.transfer_asset_ownership(&registry_id, &user2, &user1, &false);
+    assert_eq!(client.get_asset(&registry_id).owner, user2);
+}
+
+#[test]
+fn test_tokenization_requires_usable_registry_status() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+
+    let registry_id = asset_id_to_bytes(&env, 1u64);
+    let asset = create_test_asset(&env, &user1, registry_id.clone());
+    client.register_asset(&asset, &admin);
+
+    // Suspended assets cannot be tokenized until reactivated
+    client.transition_asset_status(&registry_id, &AssetStatus::Suspended, &user1);
+    let result = client.try_tokenize_asset(
+        &1u64,
+        &String::from_str(&env, "TST"),
+        &1000000i128,
+        &6u32,
+        &100i128,
+        &user1,
+        &String::from_str(&env, "Test Token"),
+        &String::from_str(&env, "A test tokenized asset"),
+        &AssetType::Physical,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidStatusTransition)));
+    client.transition_asset_status(&registry_id, &AssetStatus::Active, &user1);
+
+    // Detokenization restores the status held before tokenization
+    client.transfer_asset_ownership(&registry_id, &user2, &user1, &false);
+    assert_eq!(
+        client.get_asset(&registry_id).status,
+        AssetStatus::Transferred
+    );
+    tokenize_test_asset(&env, &client, 1u64, 1000000, &user2);
+    let proposal_id = client.propose_detokenization(&1u64, &user2);
+    client.cast_vote(&1u64, &proposal_id, &user2, &true);
+    client.execute_detokenization(&1u64, &proposal_id);
+    assert_eq!(
+        client.get_asset(&registry_id).status,
+        AssetStatus::Transferred
+    );
+}
+
+#[test]
+fn test_tokenizing_unregistered_asset_leaves_registry_untouched() {
+    let env = create_env();
+    let (admin, user1, _, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 7u64, 1000000, &user1);
+
+    assert!(!client.check_asset_exists(&asset_id_to_bytes(&env, 7u64)));
+}
+
+#[test]
+fn test_retokenize_after_detokenization() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1u64, 1000000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &600000i128, &None);
+    client.set_min_voting_balance(&1u64, &1000i128, &user1);
+    client.set_exit_only(&1u64, &true, &user1);
+
+    // Still-active tokenization cannot be retokenized
+    let result = client.try_retokenize_asset(
+        &1u64,
+        &String::from_str(&env, "NEW"),
+        &5000i128,
+        &2u32,
+        &10i128,
+        &user1,
+        &String::from_str(&env, "Round Two"),
+        &String::from_str(&env, "Second funding round"),
+        &AssetType::Physical,
+    );
+    assert_eq!(result, Err(Ok(Error::AssetAlreadyTokenized)));
+
+    let proposal_id = client.propose_detokenization(&1u64, &user1);
+    client.cast_vote(&1u64, &proposal_id, &user2, &true);
+    client.execute_detokenization(&1u64, &proposal_id);
+    let history_len = client
+        .get_asset_audit_logs(&asset_id_to_bytes(&env, 1u64))
+        .len();
+
+    // Only the former tokenizer may start a new round
+    let result = client.try_retokenize_asset(
+        &1u64,
+        &String::from_str(&env, "NEW"),
+        &5000i128,
+        &2u32,
+        &10i128,
+        &user2,
+        &String::from_str(&env, "Round Two"),
+        &String::from_str(&env, "Second funding round"),
+        &AssetType::Physical,
+    );
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    let retokenized = client.retokenize_asset(
+        &1u64,
+        &String::from_str(&env, "NEW"),
+        &5000i128,
+        &2u32,
+        &10i128,
+        &user1,
+        &String::from_str(&env, "Round Two"),
+        &String::from_str(&env, "Second funding round"),
+        &AssetType::Physical,
+    );
+    assert_eq!(retokenized.total_supply, 5000);
+    assert_eq!(retokenized.symbol, String::from_str(&env, "NEW"));
+
+    // Fresh holder and configuration state
+    assert_eq!(client.get_token_balance(&1u64, &user1), 5000);
+    assert_eq!(client.get_token_holders(&1u64).len(), 1);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 0);
+    assert!(!client.is_exit_only(&1u64));
+    assert_eq!(client.get_min_voting_balance(&1u64), 0);
+    assert_eq!(
+        client.try_get_detokenization_proposal(&1u64),
+        Err(Ok(Error::ProposalNotFound))
+    );
+
+    // Earlier history is preserved with the new round appended
+    let logs = client.get_asset_audit_logs(&asset_id_to_bytes(&env, 1u64));
+    assert_eq!(logs.len(), history_len + 2);
+    assert_eq!(
+        logs.get(0).unwrap().action,
+        String::from_str(&env, "ASSET_TOKENIZED")
+    );
+    assert_eq!(
+        logs.get(logs.len() - 1).unwrap().action,
+        String::from_str(&env, "RETOKENIZED")
+    );
+}
+
+#[test]
+fn test_detokenization_refused_while_escrow_pending() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1u64, 1000000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &600000i128, &None);
+    let recipients = vec![&env, (user3.clone(), 1000i128)];
+    let escrow_id = client.escrow_transfer(&1u64, &user1, &1000i128, &recipients, &0u64);
+
+    let proposal_id = client.propose_detokenization(&1u64, &user1);
+    client.cast_vote(&1u64, &proposal_id, &user2, &true);
+    assert_eq!(
+        client.try_execute_detokenization(&1u64, &proposal_id),
+        Err(Ok(Error::AssetEncumbered))
+    );
+
+    // Once the escrow is cancelled the proposal can be executed
+    client.cancel_escrow(&escrow_id, &user1);
+    client.execute_detokenization(&1u64, &proposal_id);
+    assert!(!client.is_detokenization_active(&1u64));
+
+    // The new round starts with no escrowed balance carried over
+    client.retokenize_asset(
+        &1u64,
+        &String::from_str(&env, "NEW"),
+        &5000i128,
+        &2u32,
+        &10i128,
+        &user1,
+        &String::from_str(&env, "Round Two"),
+        &String::from_str(&env, "Second funding round"),
+        &AssetType::Physical,
+    );
+    assert!(client.verify_supply_invariant(&1u64));
+}
+
+#[test]
+fn test_retokenize_requires_detokenized_asset() {
+    let env = create_env();
+    let (admin, user1, _, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let result = client.try_retokenize_asset(
+        &7u64,
+        &String::from_str(&env, "NEW"),
+        &5000i128,
+        &2u32,
+        &10i128,
+        &user1,
+        &String::from_str(&env, "Round Two"),
+        &String::from_str(&env, "Second funding round"),
+        &AssetType::Physical,
+    );
+    assert_eq!(result, Err(Ok(Error::AssetNotTokenized)));
+}
+
+#[test]
+fn test_vote_detokenization_reaches_threshold_and_executes() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user3, &200i128, &None);
+
+    // Voting needs an active proposal
+    assert_eq!(
+        client.try_vote_detokenization(&1u64, &user1, &true),
+        Err(Ok(Error::ProposalNotFound))
+    );
+
+    let proposal_id = client.propose_detokenization(&1u64, &user1);
+    client.vote_detokenization(&1u64, &user2, &true);
+    client.vote_detokenization(&1u64, &user3, &true);
+    assert_eq!(client.get_vote_tally(&1u64, &proposal_id), 500);
+    // Exactly 50% of circulation meets the default threshold
+    assert!(client.proposal_passed(&1u64, &proposal_id));
+
+    client.execute_detokenization(&1u64, &proposal_id);
+    assert!(client.try_get_tokenized_asset(&1u64).is_err());
+    assert_eq!(client.get_token_balance(&1u64, &user2), 0);
+    assert!(matches!(
+        client.get_detokenization_proposal(&1u64),
+        DetokenizationProposal::Executed(_)
+    ));
+}
+
+#[test]
+fn test_vote_detokenization_falls_short() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user3, &200i128, &None);
+
+    let proposal_id = client.propose_detokenization(&1u64, &user1);
+    client.vote_detokenization(&1u64, &user2, &true);
+    // The majority holder votes against; their tokens add nothing to the tally
+    client.vote_detokenization(&1u64, &user1, &false);
+    assert!(client.has_voted(&1u64, &proposal_id, &user1));
+    assert_eq!(
+        client.try_vote_detokenization(&1u64, &user1, &true),
+        Err(Ok(Error::AlreadyVoted))
+    );
+
+    assert_eq!(client.get_vote_tally(&1u64, &proposal_id), 300);
+    assert!(!client.proposal_passed(&1u64, &proposal_id));
+    assert_eq!(
+        client.try_execute_detokenization(&1u64, &proposal_id),
+        Err(Ok(Error::DetokenizationNotApproved))
+    );
+
+    // Execution only goes through once the threshold is reached
+    client.vote_detokenization(&1u64, &user3, &true);
+    client.execute_detokenization(&1u64, &proposal_id);
+    assert!(!client.is_detokenization_active(&1u64));
+}
+
+#[test]
+fn test_allowances_lapse_on_retokenization() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1u64, 1000000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &600000i128, &None);
+    client.approve(&1u64, &user1, &user3, &1000i128);
+
+    let proposal_id = client.propose_detokenization(&1u64, &user1);
+    client.cast_vote(&1u64, &proposal_id, &user2, &true);
+    client.execute_detokenization(&1u64, &proposal_id);
+
+    client.retokenize_asset(
+        &1u64,
+        &String::from_str(&env, "NEW"),
+        &5000i128,
+        &2u32,
+        &10i128,
+        &user1,
+        &String::from_str(&env, "Round Two"),
+        &String::from_str(&env, "Second funding round"),
+        &AssetType::Physical,
+    );
+
+    // The spender approved in the first round cannot move new-round tokens
+    assert_eq!(client.get_allowance(&1u64, &user1, &user3), 0);
+    assert_eq!(
+        client.try_transfer_from(&1u64, &user3, &user1, &user3, &100i128),
+        Err(Ok(Error::InsufficientAllowance))
+    );
+    assert_eq!(client.get_token_balance(&1u64, &user1), 5000);
+}
+
+#[test]
+fn test_delegations_cleared_on_detokenization() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1u64, 1000000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &600000i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user3, &100000i128, &None);
+    client.delegate_votes(&1u64, &user3, &user2);
+
+    let proposal_id = client.propose_detokenization(&1u64, &user1);
+    client.cast_vote(&1u64, &proposal_id, &user2, &true);
+    client.execute_detokenization(&1u64, &proposal_id);
+    assert_eq!(client.get_delegate(&1u64, &user3), None);
+
+    client.retokenize_asset(
+        &1u64,
+        &String::from_str(&env, "NEW"),
+        &5000i128,
+        &2u32,
+        &10i128,
+        &user1,
+        &String::from_str(&env, "Round Two"),
+        &String::from_str(&env, "Second funding round"),
+        &AssetType::Physical,
+    );
+    client.transfer_tokens(&1u64, &user1, &user3, &3000i128, &None);
+
+    // The old round's delegation no longer blocks user3 from voting directly
+    let proposal_id = client.propose_detokenization(&1u64, &user1);
+    client.cast_vote(&1u64, &proposal_id, &user3, &true);
+    assert_eq!(client.get_vote_tally(&1u64, &proposal_id), 3000);
+}
@@ -30,6 +30,7 @@ fn setup_tokenized_asset(env: &Env, asset_id: u64, tokenizer: &Address) {
             accredited_investor_required: false,
             geographic_restrictions: soroban_sdk::Vec::new(env),
         },
+        None,
     )
     .unwrap();
 }
@@ -136,7 +137,7 @@ fn test_execute_detokenization_with_majority() {
             detokenization::propose_detokenization(&env, asset_id, proposer.clone()).unwrap();
 
         // Tokenizer has 1000 tokens (100%), cast vote
-        voting::cast_vote(&env, asset_id, proposal_id, tokenizer.clone()).unwrap();
+        voting::cast_vote(&env, asset_id, proposal_id, tokenizer.clone(), true).unwrap();
 
         // Now execute - should succeed
         let ok = detokenization::execute_detokenization(&env, asset_id, proposal_id).is_ok();
@@ -163,22 +164,29 @@ fn test_detokenization_majority_threshold() {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
 
         // Transfer 400 to holder2 (40% < 50% threshold)
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder2.clone(), 400)
-            .unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder2.clone(),
+            400,
+            None,
+        )
+        .unwrap();
 
         // Propose
         let proposal_id =
             detokenization::propose_detokenization(&env, asset_id, proposer.clone()).unwrap();
 
         // Only holder2 votes (40%)
-        voting::cast_vote(&env, asset_id, proposal_id, holder2.clone()).unwrap();
+        voting::cast_vote(&env, asset_id, proposal_id, holder2.clone(), true).unwrap();
 
         // Should fail execution (only 40%)
         let first_err =
             detokenization::execute_detokenization(&env, asset_id, proposal_id).is_err();
 
         // Now tokenizer also votes (100% total)
-        voting::cast_vote(&env, asset_id, proposal_id, tokenizer.clone()).unwrap();
+        voting::cast_vote(&env, asset_id, proposal_id, tokenizer.clone(), true).unwrap();
 
         // Should succeed
         let second_ok = detokenization::execute_detokenization(&env, asset_id, proposal_id).is_ok();
@@ -203,8 +211,15 @@ fn test_token_elimination_on_execution() {
             setup_tokenized_asset(&env, asset_id, &tokenizer);
 
             // Transfer some tokens to create multiple holders
-            tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder2.clone(), 300)
-                .unwrap();
+            tokenization::transfer_tokens(
+                &env,
+                asset_id,
+                tokenizer.clone(),
+                holder2.clone(),
+                300,
+                None,
+            )
+            .unwrap();
 
             // Verify asset exists before detokenization
             let before_exists = tokenization::get_tokenized_asset(&env, asset_id).is_ok();
@@ -214,8 +229,8 @@ fn test_token_elimination_on_execution() {
                 detokenization::propose_detokenization(&env, asset_id, proposer.clone()).unwrap();
 
             // Both holders vote (100%)
-            voting::cast_vote(&env, asset_id, proposal_id, tokenizer.clone()).unwrap();
-            voting::cast_vote(&env, asset_id, proposal_id, holder2.clone()).unwrap();
+            voting::cast_vote(&env, asset_id, proposal_id, tokenizer.clone(), true).unwrap();
+            voting::cast_vote(&env, asset_id, proposal_id, holder2.clone(), true).unwrap();
 
             // Execute detokenization
             detokenization::execute_detokenization(&env, asset_id, proposal_id).unwrap();
@@ -264,7 +279,7 @@ fn test_cannot_propose_after_execution() {
         // Propose and execute detokenization
         let proposal_id =
             detokenization::propose_detokenization(&env, asset_id, proposer.clone()).unwrap();
-        voting::cast_vote(&env, asset_id, proposal_id, tokenizer.clone()).unwrap();
+        voting::cast_vote(&env, asset_id, proposal_id, tokenizer.clone(), true).unwrap();
         detokenization::execute_detokenization(&env, asset_id, proposal_id).unwrap();
 
         // Try to propose again after execution - should fail because asset is not tokenized
@@ -1,6 +1,9 @@
+use crate::error::Error;
 use crate::tests::helpers::*;
+use crate::tokenization::asset_id_to_bytes;
 use crate::types::AssetType;
-use soroban_sdk::String;
+use soroban_sdk::testutils::{Events, Ledger};
+use soroban_sdk::{vec, Address, IntoVal, String, Val, Vec};
 
 #[test]
 fn test_enable_revenue_sharing() {
@@ -20,6 +23,7 @@ fn test_enable_revenue_sharing() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Initially disabled
@@ -27,7 +31,7 @@ fn test_enable_revenue_sharing() {
     assert!(!asset.revenue_sharing_enabled);
 
     // Enable revenue sharing
-    client.enable_revenue_sharing(&1u64);
+    client.set_revenue_sharing(&1u64, &true, &user1);
 
     let asset = client.get_tokenized_asset(&1u64);
     assert!(asset.revenue_sharing_enabled);
@@ -51,10 +55,11 @@ fn test_disable_revenue_sharing() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
-    client.enable_revenue_sharing(&1u64);
-    client.disable_revenue_sharing(&1u64);
+    client.set_revenue_sharing(&1u64, &true, &user1);
+    client.set_revenue_sharing(&1u64, &false, &user1);
 
     let asset = client.get_tokenized_asset(&1u64);
     assert!(!asset.revenue_sharing_enabled);
@@ -78,16 +83,17 @@ fn test_distribute_dividends_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Enable revenue sharing
-    client.enable_revenue_sharing(&1u64);
+    client.set_revenue_sharing(&1u64, &true, &user1);
 
     // Transfer 30% to user2
-    client.transfer_tokens(&1u64, &user1, &user2, &300000i128);
+    client.transfer_tokens(&1u64, &user1, &user2, &300000i128, &None);
 
     // Distribute 10000 in dividends
-    client.distribute_dividends(&1u64, &10000i128);
+    client.distribute_dividends(&1u64, &10000i128, &user1);
 
     // Check unclaimed dividends
     let unclaimed1 = client.get_unclaimed_dividends(&1u64, &user1);
@@ -98,7 +104,7 @@ fn test_distribute_dividends_success() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #27)")]
+#[should_panic(expected = "Error(Contract, #51)")]
 fn test_distribute_dividends_invalid_amount() {
     let env = create_env();
     let (admin, user1, _, _) = create_mock_addresses(&env);
@@ -116,16 +122,17 @@ fn test_distribute_dividends_invalid_amount() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
-    client.enable_revenue_sharing(&1u64);
+    client.set_revenue_sharing(&1u64, &true, &user1);
 
-    // Should panic with InvalidDividendAmount error
-    client.distribute_dividends(&1u64, &0i128);
+    // Should panic with InvalidAmount error
+    client.distribute_dividends(&1u64, &0i128, &user1);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #27)")]
+#[should_panic(expected = "Error(Contract, #64)")]
 fn test_distribute_dividends_not_enabled() {
     let env = create_env();
     let (admin, user1, _, _) = create_mock_addresses(&env);
@@ -143,10 +150,11 @@ fn test_distribute_dividends_not_enabled() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
-    // Revenue sharing not enabled - should panic with InvalidDividendAmount
-    client.distribute_dividends(&1u64, &10000i128);
+    // Revenue sharing not enabled - should panic with RevenueSharingDisabled
+    client.distribute_dividends(&1u64, &10000i128, &user1);
 }
 
 #[test]
@@ -167,11 +175,12 @@ fn test_claim_dividends_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
-    client.enable_revenue_sharing(&1u64);
-    client.transfer_tokens(&1u64, &user1, &user2, &300000i128);
-    client.distribute_dividends(&1u64, &10000i128);
+    client.set_revenue_sharing(&1u64, &true, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300000i128, &None);
+    client.distribute_dividends(&1u64, &10000i128, &user1);
 
     // Claim dividends
     let claimed = client.claim_dividends(&1u64, &user2);
@@ -183,7 +192,6 @@ fn test_claim_dividends_success() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #26)")]
 fn test_claim_dividends_none_to_claim() {
     let env = create_env();
     let (admin, user1, _, _) = create_mock_addresses(&env);
@@ -201,10 +209,11 @@ fn test_claim_dividends_none_to_claim() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
-    // Should panic with NoDividendsToClaim error
-    client.claim_dividends(&1u64, &user1);
+    // Nothing accrued claims zero rather than failing
+    assert_eq!(client.claim_dividends(&1u64, &user1), 0);
 }
 
 #[test]
@@ -225,16 +234,17 @@ fn test_multiple_dividend_distributions() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
-    client.enable_revenue_sharing(&1u64);
-    client.transfer_tokens(&1u64, &user1, &user2, &500000i128);
+    client.set_revenue_sharing(&1u64, &true, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &500000i128, &None);
 
     // First distribution
-    client.distribute_dividends(&1u64, &10000i128);
+    client.distribute_dividends(&1u64, &10000i128, &user1);
 
     // Second distribution
-    client.distribute_dividends(&1u64, &5000i128);
+    client.distribute_dividends(&1u64, &5000i128, &user1);
 
     // Total unclaimed should be sum of both distributions
     let unclaimed1 = client.get_unclaimed_dividends(&1u64, &user1);
@@ -243,3 +253,431 @@ fn test_multiple_dividend_distributions() {
     assert_eq!(unclaimed1, 7500); // 50% of 15000
     assert_eq!(unclaimed2, 7500); // 50% of 15000
 }
+
+#[test]
+fn test_dividend_epochs_use_their_own_snapshots() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.set_revenue_sharing(&1u64, &true, &user1);
+
+    // Epoch 1: user1 holds everything
+    let epoch1 = client.open_dividend_epoch(&1u64, &user1);
+    assert_eq!(epoch1, 1);
+    client.fund_epoch(&1u64, &epoch1, &100i128);
+
+    // A second epoch cannot open while the first is still open
+    let result = client.try_open_dividend_epoch(&1u64, &user1);
+    assert_eq!(result, Err(Ok(Error::InvalidDividendEpoch)));
+
+    // Transfers after the snapshot do not affect epoch 1
+    client.transfer_tokens(&1u64, &user1, &user2, &500i128, &None);
+    client.close_dividend_epoch(&1u64, &epoch1);
+    assert_eq!(client.get_epoch_entitlement(&1u64, &epoch1, &user1), 100);
+    assert_eq!(client.get_epoch_entitlement(&1u64, &epoch1, &user2), 0);
+
+    // Epoch 2: balances are split evenly
+    let epoch2 = client.open_dividend_epoch(&1u64, &user1);
+    assert_eq!(epoch2, 2);
+    client.fund_epoch(&1u64, &epoch2, &200i128);
+    client.close_dividend_epoch(&1u64, &epoch2);
+    assert_eq!(client.get_epoch_entitlement(&1u64, &epoch2, &user1), 100);
+    assert_eq!(client.get_epoch_entitlement(&1u64, &epoch2, &user2), 100);
+
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user1), 200);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user2), 100);
+    assert_eq!(client.claim_dividends(&1u64, &user2), 100);
+
+    // Closed epochs cannot be funded or closed again
+    let result = client.try_fund_epoch(&1u64, &epoch2, &50i128);
+    assert_eq!(result, Err(Ok(Error::InvalidDividendEpoch)));
+    let result = client.try_close_dividend_epoch(&1u64, &epoch2);
+    assert_eq!(result, Err(Ok(Error::InvalidDividendEpoch)));
+}
+
+#[test]
+fn test_close_dividend_epoch_credits_holder_drained_after_snapshot() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.set_revenue_sharing(&1u64, &true, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &400i128, &None);
+
+    let epoch = client.open_dividend_epoch(&1u64, &user1);
+    client.fund_epoch(&1u64, &epoch, &100i128);
+
+    // user2 sells out before the epoch closes, deleting their holder record
+    client.transfer_tokens(&1u64, &user2, &user1, &400i128, &None);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 0);
+
+    client.close_dividend_epoch(&1u64, &epoch);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user2), 40);
+    assert_eq!(
+        client.get_unclaimed_by_epoch(&1u64, &user2),
+        vec![&env, (epoch, 40i128)]
+    );
+    assert_eq!(client.claim_epoch_dividends(&1u64, &user2, &epoch), 40);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user2), 0);
+}
+
+#[test]
+fn test_open_dividend_epoch_unauthorized() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+
+    let result = client.try_open_dividend_epoch(&1u64, &user2);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    let result = client.try_get_dividend_epoch(&1u64, &1u32);
+    assert_eq!(result, Err(Ok(Error::InvalidDividendEpoch)));
+}
+
+#[test]
+fn test_dividend_amounts_must_be_positive() {
+    let env = create_env();
+    let (admin, user1, _, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.set_revenue_sharing(&1u64, &true, &user1);
+    let epoch = client.open_dividend_epoch(&1u64, &user1);
+
+    for amount in [0i128, -1i128] {
+        assert_eq!(
+            client.try_distribute_dividends(&1u64, &amount, &user1),
+            Err(Ok(Error::InvalidAmount))
+        );
+        assert_eq!(
+            client.try_fund_epoch(&1u64, &epoch, &amount),
+            Err(Ok(Error::InvalidAmount))
+        );
+    }
+}
+
+#[test]
+fn test_dividend_declaration_freezes_transfers() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.set_revenue_sharing(&1u64, &true, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &500i128, &None);
+
+    client.declare_dividend(&1u64, &100i128, &3_600u64, &user1);
+    assert_eq!(
+        client.try_declare_dividend(&1u64, &100i128, &3_600u64, &user1),
+        Err(Ok(Error::DistributionInProgress))
+    );
+
+    // Transfers are blocked during the declaration window
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user2, &user3, &100i128, &None),
+        Err(Ok(Error::DistributionInProgress))
+    );
+
+    // Finalizing distributes and lifts the freeze
+    client.finalize_dividend(&1u64, &user1);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user1), 50);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user2), 50);
+    assert_eq!(client.get_dividend_declaration(&1u64), None);
+    client.transfer_tokens(&1u64, &user2, &user3, &100i128, &None);
+}
+
+#[test]
+fn test_dividend_declaration_freeze_lapses() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.set_revenue_sharing(&1u64, &true, &user1);
+
+    assert_eq!(
+        client.try_declare_dividend(&1u64, &100i128, &3_600u64, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.declare_dividend(&1u64, &100i128, &3_600u64, &user1);
+
+    env.ledger().with_mut(|li| li.timestamp = 4_599);
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user1, &user2, &100i128, &None),
+        Err(Ok(Error::DistributionInProgress))
+    );
+
+    // The freeze lifts on its own once the window elapses
+    env.ledger().with_mut(|li| li.timestamp = 4_600);
+    client.transfer_tokens(&1u64, &user1, &user2, &100i128, &None);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 100);
+
+    // The lapsed declaration can no longer pay out to post-freeze holders
+    assert_eq!(
+        client.try_finalize_dividend(&1u64, &user1),
+        Err(Ok(Error::NoDividendsToClaim))
+    );
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user2), 0);
+
+    // and no longer blocks a fresh declaration
+    client.declare_dividend(&1u64, &200i128, &3_600u64, &user1);
+    client.finalize_dividend(&1u64, &user1);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user1), 180);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user2), 20);
+}
+
+#[test]
+fn test_unclaimed_dividends_by_epoch() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.set_revenue_sharing(&1u64, &true, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &250i128, &None);
+
+    for amount in [100i128, 200i128] {
+        let epoch = client.open_dividend_epoch(&1u64, &user1);
+        client.fund_epoch(&1u64, &epoch, &amount);
+        client.close_dividend_epoch(&1u64, &epoch);
+    }
+
+    assert_eq!(
+        client.get_unclaimed_by_epoch(&1u64, &user2),
+        vec![&env, (1u32, 25i128), (2u32, 50i128)]
+    );
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user2), 75);
+
+    // Claiming one epoch leaves the other
+    assert_eq!(client.claim_epoch_dividends(&1u64, &user2, &1u32), 25);
+    assert_eq!(
+        client.get_unclaimed_by_epoch(&1u64, &user2),
+        vec![&env, (2u32, 50i128)]
+    );
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user2), 50);
+    assert_eq!(
+        client.try_claim_epoch_dividends(&1u64, &user2, &1u32),
+        Err(Ok(Error::NoDividendsToClaim))
+    );
+
+    // Aggregate claim clears every epoch
+    assert_eq!(client.claim_dividends(&1u64, &user1), 225);
+    assert_eq!(client.get_unclaimed_by_epoch(&1u64, &user1).len(), 0);
+}
+
+#[test]
+fn test_preview_claim() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.set_revenue_sharing(&1u64, &true, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &500i128, &None);
+    client.distribute_dividends(&1u64, &100i128, &user1);
+
+    // Preview matches the claim and leaves state untouched
+    assert_eq!(client.preview_claim(&1u64, &user2), 50);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user2), 50);
+    assert_eq!(client.claim_dividends(&1u64, &user2), 50);
+
+    // Nothing left to claim previews as zero
+    assert_eq!(client.preview_claim(&1u64, &user2), 0);
+    assert_eq!(
+        client.try_preview_claim(&1u64, &user3),
+        Err(Ok(Error::HolderNotFound))
+    );
+
+    // A declaration freeze blocks claims until it finalizes
+    client.declare_dividend(&1u64, &100i128, &3_600u64, &user1);
+    assert_eq!(
+        client.try_preview_claim(&1u64, &user1),
+        Err(Ok(Error::DistributionInProgress))
+    );
+    assert_eq!(
+        client.try_claim_dividends(&1u64, &user1),
+        Err(Ok(Error::DistributionInProgress))
+    );
+    client.finalize_dividend(&1u64, &user1);
+    assert_eq!(client.preview_claim(&1u64, &user1), 100);
+}
+
+#[test]
+fn test_distribute_dividends_proportional_with_dust_to_tokenizer() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.set_revenue_sharing(&1u64, &true, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &333i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user3, &111i128, &None);
+
+    // Only the tokenizer can distribute
+    assert_eq!(
+        client.try_distribute_dividends(&1u64, &100i128, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // 556/333/111 of 1000: 55 + 33 + 11, with the 1 left over to the tokenizer
+    client.distribute_dividends(&1u64, &100i128, &user1);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user1), 56);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user2), 33);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user3), 11);
+
+    // Shares are of tokens in circulation, so burned tokens earn nothing
+    client.burn_tokens(&1u64, &100i128, &user1);
+    client.distribute_dividends(&1u64, &90i128, &user1);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user1), 56 + 46);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user2), 33 + 33);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user3), 11 + 11);
+}
+
+#[test]
+fn test_claim_dividends_after_distribution() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.set_revenue_sharing(&1u64, &true, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &250i128, &None);
+    client.distribute_dividends(&1u64, &400i128, &user1);
+
+    assert_eq!(client.claim_dividends(&1u64, &user2), 100);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: Vec<Val> = ("dividend", "claimed").into_val(&env);
+    assert_eq!(topics, expected);
+    let (asset_id, holder, amount): (u64, Address, i128) = data.into_val(&env);
+    assert_eq!((asset_id, holder, amount), (1, user2.clone(), 100));
+
+    let logs = client.get_asset_audit_logs(&asset_id_to_bytes(&env, 1));
+    let entry = logs.get(logs.len() - 1).unwrap();
+    assert_eq!(entry.action, String::from_str(&env, "DIVIDENDS_CLAIMED"));
+    assert_eq!(entry.actor, user2);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user2), 0);
+
+    // A second claim has nothing left and is not audited again
+    let log_count = logs.len();
+    assert_eq!(client.claim_dividends(&1u64, &user2), 0);
+    assert_eq!(
+        client
+            .get_asset_audit_logs(&asset_id_to_bytes(&env, 1))
+            .len(),
+        log_count
+    );
+
+    // Other holders still hold their share
+    assert_eq!(client.claim_dividends(&1u64, &user1), 300);
+
+    // Addresses that never held tokens are not holders
+    assert_eq!(
+        client.try_claim_dividends(&1u64, &user3),
+        Err(Ok(Error::HolderNotFound))
+    );
+}
+
+#[test]
+fn test_set_revenue_sharing_gates_distribution() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    assert!(!client.get_tokenized_asset(&1u64).revenue_sharing_enabled);
+    assert_eq!(
+        client.try_distribute_dividends(&1u64, &100i128, &user1),
+        Err(Ok(Error::RevenueSharingDisabled))
+    );
+
+    // Only the tokenizer can toggle it
+    assert_eq!(
+        client.try_set_revenue_sharing(&1u64, &true, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.set_revenue_sharing(&1u64, &true, &user1);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let expected: Vec<Val> = ("dividend", "revenue_sharing_toggled").into_val(&env);
+    assert_eq!(topics, expected);
+    let toggled: (u64, bool) = data.into_val(&env);
+    assert_eq!(toggled, (1, true));
+
+    client.distribute_dividends(&1u64, &100i128, &user1);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user1), 100);
+
+    client.set_revenue_sharing(&1u64, &false, &user1);
+    assert_eq!(
+        client.try_distribute_dividends(&1u64, &100i128, &user1),
+        Err(Ok(Error::RevenueSharingDisabled))
+    );
+}
+
+#[test]
+fn test_distribute_dividends_skips_burn_addresses() {
+    let env = create_env();
+    let (admin, user1, user2, burn) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.set_revenue_sharing(&1u64, &true, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &250i128, &None);
+
+    // 500 burned tokens stay parked at the burn address
+    client.set_burn_address(&1u64, &burn, &user1);
+    client.burn_tokens(&1u64, &500i128, &user1);
+
+    client.distribute_dividends(&1u64, &100i128, &user1);
+
+    // The 500 eligible tokens split the whole amount and nothing more
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user1), 50);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user2), 50);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &burn), 0);
+}
+
+#[test]
+fn test_dividend_epoch_skips_burn_addresses() {
+    let env = create_env();
+    let (admin, user1, user2, burn) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.set_revenue_sharing(&1u64, &true, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &250i128, &None);
+
+    // 500 burned tokens stay parked at the burn address
+    client.set_burn_address(&1u64, &burn, &user1);
+    client.burn_tokens(&1u64, &500i128, &user1);
+
+    let epoch = client.open_dividend_epoch(&1u64, &user1);
+    let snapshot = client.get_dividend_epoch(&1u64, &epoch);
+    assert_eq!(snapshot.snapshot_supply, 500);
+    assert!(!snapshot.snapshot.contains_key(burn.clone()));
+    client.fund_epoch(&1u64, &epoch, &100i128);
+    client.close_dividend_epoch(&1u64, &epoch);
+
+    // Only the 500 live tokens share the epoch
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user1), 50);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user2), 50);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &burn), 0);
+}
@@ -30,6 +30,7 @@ fn setup_tokenized_asset(env: &Env, asset_id: u64, tokenizer: &Address) {
             accredited_investor_required: false,
             geographic_restrictions: soroban_sdk::Vec::new(env),
         },
+        None,
     )
     .unwrap();
 }
@@ -45,7 +46,7 @@ fn test_distribute_dividends_no_revenue_sharing() {
 
     let result_err = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        dividends::distribute_dividends(&env, asset_id, 1000).is_err()
+        dividends::distribute_dividends(&env, asset_id, 1000, tokenizer.clone()).is_err()
     });
 
     assert!(result_err);
@@ -60,11 +61,11 @@ fn test_distribute_dividends_zero_amount_fails() {
 
     let err = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
-        dividends::distribute_dividends(&env, asset_id, 0).unwrap_err()
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
+        dividends::distribute_dividends(&env, asset_id, 0, tokenizer.clone()).unwrap_err()
     });
 
-    assert_eq!(err, Error::InvalidDividendAmount);
+    assert_eq!(err, Error::InvalidAmount);
 }
 
 #[test]
@@ -76,21 +77,22 @@ fn test_distribute_dividends_negative_amount_fails() {
 
     let err = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
-        dividends::distribute_dividends(&env, asset_id, -500).unwrap_err()
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
+        dividends::distribute_dividends(&env, asset_id, -500, tokenizer.clone()).unwrap_err()
     });
 
-    assert_eq!(err, Error::InvalidDividendAmount);
+    assert_eq!(err, Error::InvalidAmount);
 }
 
 #[test]
 fn test_distribute_dividends_untokenized_asset_fails() {
     let env = Env::default();
     let contract_id = env.register(AssetUpContract, ());
+    let tokenizer = Address::generate(&env);
     let asset_id = 803u64;
 
     let err = env.as_contract(&contract_id, || {
-        dividends::distribute_dividends(&env, asset_id, 1000).unwrap_err()
+        dividends::distribute_dividends(&env, asset_id, 1000, tokenizer.clone()).unwrap_err()
     });
 
     assert_eq!(err, Error::AssetNotTokenized);
@@ -106,12 +108,19 @@ fn test_distribute_dividends_two_holders_equal_split() {
 
     let (tokenizer_dividend, holder2_dividend) = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
 
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder2.clone(), 500)
-            .unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder2.clone(),
+            500,
+            None,
+        )
+        .unwrap();
 
-        dividends::distribute_dividends(&env, asset_id, 1000).unwrap();
+        dividends::distribute_dividends(&env, asset_id, 1000, tokenizer.clone()).unwrap();
 
         let t_div = dividends::get_unclaimed_dividends(&env, asset_id, tokenizer.clone()).unwrap();
         let h2_div = dividends::get_unclaimed_dividends(&env, asset_id, holder2.clone()).unwrap();
@@ -131,11 +140,11 @@ fn test_distribute_dividends_accumulates_across_rounds() {
 
     let unclaimed = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
 
         // Two distribution rounds without claiming in between
-        dividends::distribute_dividends(&env, asset_id, 400).unwrap();
-        dividends::distribute_dividends(&env, asset_id, 600).unwrap();
+        dividends::distribute_dividends(&env, asset_id, 400, tokenizer.clone()).unwrap();
+        dividends::distribute_dividends(&env, asset_id, 600, tokenizer.clone()).unwrap();
 
         dividends::get_unclaimed_dividends(&env, asset_id, tokenizer.clone()).unwrap()
     });
@@ -153,12 +162,12 @@ fn test_distribute_dividends_after_disable_fails() {
 
     let err = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
-        dividends::disable_revenue_sharing(&env, asset_id).unwrap();
-        dividends::distribute_dividends(&env, asset_id, 1000).unwrap_err()
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
+        dividends::set_revenue_sharing(&env, asset_id, false, tokenizer.clone()).unwrap();
+        dividends::distribute_dividends(&env, asset_id, 1000, tokenizer.clone()).unwrap_err()
     });
 
-    assert_eq!(err, Error::InvalidDividendAmount);
+    assert_eq!(err, Error::RevenueSharingDisabled);
 }
 
 // ─── claim_dividends ─────────────────────────────────────────────────────────
@@ -172,8 +181,8 @@ fn test_claim_dividends() {
 
     let (claimed, remaining) = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
-        dividends::distribute_dividends(&env, asset_id, 500).unwrap();
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
+        dividends::distribute_dividends(&env, asset_id, 500, tokenizer.clone()).unwrap();
 
         let claimed = dividends::claim_dividends(&env, asset_id, tokenizer.clone()).unwrap();
         let remaining =
@@ -192,13 +201,13 @@ fn test_claim_dividends_insufficient() {
     let tokenizer = Address::generate(&env);
     let asset_id = 800u64;
 
-    let result_err = env.as_contract(&contract_id, || {
+    let claimed = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
-        dividends::claim_dividends(&env, asset_id, tokenizer.clone()).is_err()
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
+        dividends::claim_dividends(&env, asset_id, tokenizer.clone())
     });
 
-    assert!(result_err);
+    assert_eq!(claimed, Ok(0));
 }
 
 #[test]
@@ -208,17 +217,17 @@ fn test_claim_dividends_no_double_claim() {
     let tokenizer = Address::generate(&env);
     let asset_id = 820u64;
 
-    let second_err = env.as_contract(&contract_id, || {
+    let second = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
-        dividends::distribute_dividends(&env, asset_id, 500).unwrap();
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
+        dividends::distribute_dividends(&env, asset_id, 500, tokenizer.clone()).unwrap();
 
         dividends::claim_dividends(&env, asset_id, tokenizer.clone()).unwrap();
-        // Second claim must fail
-        dividends::claim_dividends(&env, asset_id, tokenizer.clone()).unwrap_err()
+        // Second claim pays nothing
+        dividends::claim_dividends(&env, asset_id, tokenizer.clone()).unwrap()
     });
 
-    assert_eq!(second_err, Error::NoDividendsToClaim);
+    assert_eq!(second, 0_i128);
 }
 
 #[test]
@@ -231,13 +240,20 @@ fn test_claim_dividends_partial_holder() {
 
     let (claimed, h2_remaining) = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
 
         // tokenizer keeps 750, holder2 gets 250
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder2.clone(), 250)
-            .unwrap();
-
-        dividends::distribute_dividends(&env, asset_id, 1000).unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder2.clone(),
+            250,
+            None,
+        )
+        .unwrap();
+
+        dividends::distribute_dividends(&env, asset_id, 1000, tokenizer.clone()).unwrap();
 
         // Only tokenizer claims
         let claimed = dividends::claim_dividends(&env, asset_id, tokenizer.clone()).unwrap();
@@ -259,12 +275,12 @@ fn test_claim_resets_then_accumulates_again() {
 
     let (first_claim, second_claim) = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
 
-        dividends::distribute_dividends(&env, asset_id, 300).unwrap();
+        dividends::distribute_dividends(&env, asset_id, 300, tokenizer.clone()).unwrap();
         let first = dividends::claim_dividends(&env, asset_id, tokenizer.clone()).unwrap();
 
-        dividends::distribute_dividends(&env, asset_id, 700).unwrap();
+        dividends::distribute_dividends(&env, asset_id, 700, tokenizer.clone()).unwrap();
         let second = dividends::claim_dividends(&env, asset_id, tokenizer.clone()).unwrap();
 
         (first, second)
@@ -317,7 +333,7 @@ fn test_enable_revenue_sharing() {
 
     let ok = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        dividends::enable_revenue_sharing(&env, asset_id).is_ok()
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).is_ok()
     });
 
     assert!(ok);
@@ -332,8 +348,8 @@ fn test_disable_revenue_sharing() {
 
     let ok = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
-        dividends::disable_revenue_sharing(&env, asset_id).is_ok()
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
+        dividends::set_revenue_sharing(&env, asset_id, false, tokenizer.clone()).is_ok()
     });
 
     assert!(ok);
@@ -346,7 +362,7 @@ fn test_enable_revenue_sharing_untokenized_fails() {
     let asset_id = 842u64;
 
     let err = env.as_contract(&contract_id, || {
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap_err()
+        dividends::set_revenue_sharing(&env, asset_id, true, Address::generate(&env)).unwrap_err()
     });
 
     assert_eq!(err, Error::AssetNotTokenized);
@@ -359,7 +375,7 @@ fn test_disable_revenue_sharing_untokenized_fails() {
     let asset_id = 843u64;
 
     let err = env.as_contract(&contract_id, || {
-        dividends::disable_revenue_sharing(&env, asset_id).unwrap_err()
+        dividends::set_revenue_sharing(&env, asset_id, false, Address::generate(&env)).unwrap_err()
     });
 
     assert_eq!(err, Error::AssetNotTokenized);
@@ -375,10 +391,12 @@ fn test_revenue_sharing_toggle_enables_distribution() {
     let (disabled_err, enabled_ok) = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
 
-        let disabled_err = dividends::distribute_dividends(&env, asset_id, 100).is_err();
+        let disabled_err =
+            dividends::distribute_dividends(&env, asset_id, 100, tokenizer.clone()).is_err();
 
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
-        let enabled_ok = dividends::distribute_dividends(&env, asset_id, 100).is_ok();
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
+        let enabled_ok =
+            dividends::distribute_dividends(&env, asset_id, 100, tokenizer.clone()).is_ok();
 
         (disabled_err, enabled_ok)
     });
@@ -400,15 +418,29 @@ fn test_proportional_dividend_distribution() {
 
     let (t_div, h2_div, h3_div) = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
 
         // tokenizer: 400, holder2: 300, holder3: 300
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder2.clone(), 300)
-            .unwrap();
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder3.clone(), 300)
-            .unwrap();
-
-        dividends::distribute_dividends(&env, asset_id, 1000).unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder2.clone(),
+            300,
+            None,
+        )
+        .unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder3.clone(),
+            300,
+            None,
+        )
+        .unwrap();
+
+        dividends::distribute_dividends(&env, asset_id, 1000, tokenizer.clone()).unwrap();
 
         let t = dividends::get_unclaimed_dividends(&env, asset_id, tokenizer.clone()).unwrap();
         let h2 = dividends::get_unclaimed_dividends(&env, asset_id, holder2.clone()).unwrap();
@@ -430,8 +462,8 @@ fn test_sole_holder_receives_full_amount() {
 
     let unclaimed = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
-        dividends::distribute_dividends(&env, asset_id, 999).unwrap();
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
+        dividends::distribute_dividends(&env, asset_id, 999, tokenizer.clone()).unwrap();
         dividends::get_unclaimed_dividends(&env, asset_id, tokenizer.clone()).unwrap()
     });
 
@@ -450,14 +482,17 @@ fn test_four_equal_holders_distribution() {
 
     let (d1, d2, d3, d4) = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
 
         // 250 each
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), h2.clone(), 250).unwrap();
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), h3.clone(), 250).unwrap();
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), h4.clone(), 250).unwrap();
+        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), h2.clone(), 250, None)
+            .unwrap();
+        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), h3.clone(), 250, None)
+            .unwrap();
+        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), h4.clone(), 250, None)
+            .unwrap();
 
-        dividends::distribute_dividends(&env, asset_id, 1000).unwrap();
+        dividends::distribute_dividends(&env, asset_id, 1000, tokenizer.clone()).unwrap();
 
         let d1 = dividends::get_unclaimed_dividends(&env, asset_id, tokenizer.clone()).unwrap();
         let d2 = dividends::get_unclaimed_dividends(&env, asset_id, h2.clone()).unwrap();
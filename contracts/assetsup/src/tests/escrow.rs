@@ -0,0 +1,120 @@
+use crate::error::Error;
+use crate::escrow::EscrowStatus;
+use crate::tests::helpers::*;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::vec;
+
+#[test]
+fn test_split_escrow_released_to_all_recipients() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, sender, r1, r2) = create_mock_addresses(&env);
+    let (_, _, _, r3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1_000, &sender);
+
+    let recipients = vec![
+        &env,
+        (r1.clone(), 100i128),
+        (r2.clone(), 200i128),
+        (r3.clone(), 300i128),
+    ];
+
+    // Recipient amounts must sum to the escrowed total
+    assert_eq!(
+        client.try_escrow_transfer(&1u64, &sender, &500i128, &recipients, &2_000u64),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    let escrow_id = client.escrow_transfer(&1u64, &sender, &600i128, &recipients, &2_000u64);
+    assert_eq!(client.get_token_balance(&1u64, &sender), 400);
+
+    // Not releasable before release_after
+    assert_eq!(
+        client.try_claim_escrow(&escrow_id),
+        Err(Ok(Error::TokensAreLocked))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    client.claim_escrow(&escrow_id);
+
+    assert_eq!(client.get_token_balance(&1u64, &r1), 100);
+    assert_eq!(client.get_token_balance(&1u64, &r2), 200);
+    assert_eq!(client.get_token_balance(&1u64, &r3), 300);
+    assert_eq!(client.get_escrow(&escrow_id).status, EscrowStatus::Released);
+
+    // Released escrows cannot be claimed or cancelled again
+    assert_eq!(
+        client.try_claim_escrow(&escrow_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_cancel_escrow(&escrow_id, &sender),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_cancel_escrow_refunds_sender() {
+    let env = create_env();
+    let (admin, sender, r1, r2) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1_000, &sender);
+
+    let recipients = vec![&env, (r1.clone(), 250i128), (r2.clone(), 250i128)];
+    let escrow_id = client.escrow_transfer(&1u64, &sender, &500i128, &recipients, &0u64);
+    assert_eq!(client.get_token_balance(&1u64, &sender), 500);
+
+    assert_eq!(
+        client.try_cancel_escrow(&escrow_id, &r1),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.cancel_escrow(&escrow_id, &sender);
+
+    assert_eq!(client.get_token_balance(&1u64, &sender), 1_000);
+    assert_eq!(
+        client.get_escrow(&escrow_id).status,
+        EscrowStatus::Cancelled
+    );
+    assert_eq!(
+        client.try_claim_escrow(&escrow_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_get_escrow(&99u64),
+        Err(Ok(Error::EscrowNotFound))
+    );
+}
+
+#[test]
+fn test_escrow_transfer_blocked_by_dividend_freeze_and_pause() {
+    let env = create_env();
+    let (admin, sender, r1, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1_000, &sender);
+    let recipients = vec![&env, (r1.clone(), 100i128)];
+
+    // A declaration freeze cannot be sidestepped through escrow
+    client.set_revenue_sharing(&1u64, &true, &sender);
+    client.declare_dividend(&1u64, &100i128, &3_600u64, &sender);
+    assert_eq!(
+        client.try_escrow_transfer(&1u64, &sender, &100i128, &recipients, &0u64),
+        Err(Ok(Error::DistributionInProgress))
+    );
+    client.finalize_dividend(&1u64, &sender);
+
+    client.pause_contract();
+    assert_eq!(
+        client.try_escrow_transfer(&1u64, &sender, &100i128, &recipients, &0u64),
+        Err(Ok(Error::ContractPaused))
+    );
+    client.unpause_contract();
+    client.escrow_transfer(&1u64, &sender, &100i128, &recipients, &0u64);
+    assert_eq!(client.get_token_balance(&1u64, &sender), 900);
+}
@@ -1,7 +1,10 @@
 use crate::asset::Asset;
-use crate::insurance::{ClaimStatus, ClaimType, InsuranceClaim, InsurancePolicy, PolicyStatus, PolicyType};
+use crate::insurance::{
+    ClaimStatus, ClaimType, InsuranceClaim, InsurancePolicy, PolicyStatus, PolicyType,
+};
 use crate::types::{AssetStatus, AssetType, CustomAttribute, TokenMetadata};
 use crate::{AssetUpContract, AssetUpContractClient};
+use soroban_sdk::token::StellarAssetClient;
 use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String, Vec};
 
 /// Create a fresh test environment
@@ -44,6 +47,7 @@ pub fn create_test_asset(env: &Env, owner: &Address, id: BytesN<32>) -> Asset {
         metadata_uri: String::from_str(env, "ipfs://QmTest123456789"),
         purchase_value: 1000,
         custom_attributes: Vec::new(env),
+        location: String::from_str(env, ""),
     }
 }
 
@@ -75,6 +79,7 @@ pub fn create_test_asset_with_attributes(
         metadata_uri: String::from_str(env, "ipfs://QmTestWithAttrs"),
         purchase_value: value,
         custom_attributes: attributes,
+        location: String::from_str(env, ""),
     }
 }
 
@@ -88,6 +93,29 @@ pub fn generate_asset_id(env: &Env, seed: u32) -> BytesN<32> {
     BytesN::from_array(env, &bytes)
 }
 
+/// Tokenize an asset with default parameters, giving the full supply to `tokenizer`
+#[allow(dead_code)]
+pub fn tokenize_test_asset(
+    env: &Env,
+    client: &AssetUpContractClient,
+    asset_id: u64,
+    total_supply: i128,
+    tokenizer: &Address,
+) {
+    client.tokenize_asset(
+        &asset_id,
+        &String::from_str(env, "TST"),
+        &total_supply,
+        &6u32,
+        &100i128,
+        tokenizer,
+        &String::from_str(env, "Test Token"),
+        &String::from_str(env, "A test tokenized asset"),
+        &AssetType::Physical,
+        &None,
+    );
+}
+
 /// Create token metadata for testing
 #[allow(dead_code)]
 pub fn create_test_token_metadata(env: &Env) -> TokenMetadata {
@@ -103,6 +131,19 @@ pub fn create_test_token_metadata(env: &Env) -> TokenMetadata {
     }
 }
 
+/// Register a Stellar asset contract to use as a test token
+pub fn create_test_token(env: &Env) -> Address {
+    let issuer = Address::generate(env);
+    env.register_stellar_asset_contract_v2(issuer).address()
+}
+
+/// Mint test tokens to an address
+#[allow(dead_code)]
+pub fn mint_test_tokens(env: &Env, token: &Address, to: &Address, amount: i128) {
+    env.mock_all_auths();
+    StellarAssetClient::new(env, token).mint(to, &amount);
+}
+
 /// Create a test insurance policy
 pub fn create_test_policy(
     env: &Env,
@@ -127,6 +168,13 @@ pub fn create_test_policy(
         status: PolicyStatus::Active,
         auto_renew: false,
         last_payment: current_time,
+        excluded_claim_types: Vec::new(env),
+        premium_token: create_test_token(env),
+        premium_interval: 0,
+        claim_waiting_period_secs: 0,
+        premium_grace_secs: 0,
+        coinsurance_bps: 0,
+        co_insurers: Vec::new(env),
     }
 }
 
@@ -151,5 +199,8 @@ pub fn create_test_claim(
         status: ClaimStatus::Submitted,
         filed_at: current_time,
         approved_amount: 0,
+        paid_amount: 0,
+        reason: None,
+        evidence_hash: BytesN::from_array(env, &[7u8; 32]),
     }
 }
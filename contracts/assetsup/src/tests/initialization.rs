@@ -68,13 +68,13 @@ fn test_get_contract_metadata_not_initialized() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #2)")]
+#[should_panic(expected = "Error(Contract, #35)")]
 fn test_get_admin_not_found() {
     let env = create_env();
 
     let contract_id = env.register(AssetUpContract, ());
     let client = AssetUpContractClient::new(&env, &contract_id);
 
-    // Should panic with AdminNotFound error
+    // Should panic with ContractNotInitialized error
     client.get_admin();
 }
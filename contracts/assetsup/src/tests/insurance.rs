@@ -1,6 +1,10 @@
+use crate::error::Error;
 use crate::insurance::PolicyStatus;
 use crate::tests::helpers::*;
+use crate::tokenization::asset_id_to_bytes;
 use soroban_sdk::testutils::Ledger;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, String, Vec};
 
 #[test]
 fn test_create_insurance_policy_success() {
@@ -44,7 +48,7 @@ fn test_create_insurance_policy_already_exists() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #9)")]
+#[should_panic(expected = "Error(Contract, #51)")]
 fn test_create_insurance_policy_invalid_coverage() {
     let env = create_env();
     let (admin, user1, insurer, _) = create_mock_addresses(&env);
@@ -60,12 +64,12 @@ fn test_create_insurance_policy_invalid_coverage() {
 
     env.mock_all_auths();
 
-    // Should panic with InvalidPayment error
+    // Should panic with InvalidAmount error
     client.create_insurance_policy(&policy);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #9)")]
+#[should_panic(expected = "Error(Contract, #51)")]
 fn test_create_insurance_policy_invalid_dates() {
     let env = create_env();
     let (admin, user1, insurer, _) = create_mock_addresses(&env);
@@ -81,7 +85,7 @@ fn test_create_insurance_policy_invalid_dates() {
 
     env.mock_all_auths();
 
-    // Should panic with InvalidPayment error
+    // Should panic with InvalidAmount error
     client.create_insurance_policy(&policy);
 }
 
@@ -336,3 +340,545 @@ fn test_policy_lifecycle_full() {
     let stored = client.get_insurance_policy(&policy_id).unwrap();
     assert_eq!(stored.status, PolicyStatus::Cancelled);
 }
+
+#[test]
+fn test_create_policies_batch_success() {
+    let env = create_env();
+    let (admin, user1, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_a = generate_asset_id(&env, 100);
+    let asset_b = generate_asset_id(&env, 101);
+    let mut policies = Vec::new(&env);
+    policies.push_back(create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &user1,
+        &insurer,
+        asset_a.clone(),
+    ));
+    policies.push_back(create_test_policy(
+        &env,
+        generate_asset_id(&env, 2),
+        &user1,
+        &insurer,
+        asset_a.clone(),
+    ));
+    policies.push_back(create_test_policy(
+        &env,
+        generate_asset_id(&env, 3),
+        &user1,
+        &insurer,
+        asset_b.clone(),
+    ));
+
+    env.mock_all_auths();
+    assert_eq!(client.check_policies_batch(&policies, &insurer), None);
+    client.create_policies_batch(&policies, &insurer);
+
+    assert_eq!(client.get_asset_insurance_policies(&asset_a).len(), 2);
+    assert_eq!(client.get_asset_insurance_policies(&asset_b).len(), 1);
+    assert_eq!(client.insurer_summary(&insurer).0, 3);
+
+    // One audit entry per policy
+    assert_eq!(client.get_asset_audit_logs(&asset_a).len(), 2);
+    assert_eq!(client.get_asset_audit_logs(&asset_b).len(), 1);
+}
+
+#[test]
+fn test_create_policies_batch_rejects_invalid_policy() {
+    let env = create_env();
+    let (admin, user1, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let bad_id = generate_asset_id(&env, 2);
+    let mut bad_policy =
+        create_test_policy(&env, bad_id.clone(), &user1, &insurer, asset_id.clone());
+    bad_policy.premium = 0;
+
+    let mut policies = Vec::new(&env);
+    policies.push_back(create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &user1,
+        &insurer,
+        asset_id.clone(),
+    ));
+    policies.push_back(bad_policy);
+    policies.push_back(create_test_policy(
+        &env,
+        generate_asset_id(&env, 3),
+        &user1,
+        &insurer,
+        asset_id.clone(),
+    ));
+
+    env.mock_all_auths();
+    assert_eq!(
+        client.check_policies_batch(&policies, &insurer),
+        Some(bad_id)
+    );
+
+    let result = client.try_create_policies_batch(&policies, &insurer);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+
+    // Nothing from the batch was written
+    assert_eq!(client.get_asset_insurance_policies(&asset_id).len(), 0);
+    assert!(client
+        .get_insurance_policy(&generate_asset_id(&env, 1))
+        .is_none());
+}
+
+#[test]
+fn test_create_policies_batch_rejects_duplicate_ids() {
+    let env = create_env();
+    let (admin, user1, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let dup_id = generate_asset_id(&env, 1);
+    let mut policies = Vec::new(&env);
+    policies.push_back(create_test_policy(
+        &env,
+        dup_id.clone(),
+        &user1,
+        &insurer,
+        asset_id.clone(),
+    ));
+    policies.push_back(create_test_policy(
+        &env,
+        dup_id.clone(),
+        &user1,
+        &insurer,
+        asset_id,
+    ));
+
+    env.mock_all_auths();
+    assert_eq!(
+        client.check_policies_batch(&policies, &insurer),
+        Some(dup_id)
+    );
+    let result = client.try_create_policies_batch(&policies, &insurer);
+    assert_eq!(result, Err(Ok(Error::AssetAlreadyExists)));
+}
+
+#[test]
+fn test_is_covered_at() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let policy_id = generate_asset_id(&env, 1);
+    let asset_id = generate_asset_id(&env, 100);
+    let mut policy = create_test_policy(&env, policy_id.clone(), &user1, &insurer, asset_id);
+    policy.start_date = 2_000;
+    policy.end_date = 10_000;
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+
+    assert!(client.is_covered_at(&policy_id, &2_000));
+    assert!(client.is_covered_at(&policy_id, &5_000));
+    assert!(!client.is_covered_at(&policy_id, &1_999));
+    assert!(!client.is_covered_at(&policy_id, &10_001));
+
+    // Losses during a suspension are not covered; earlier ones still are
+    env.ledger().with_mut(|li| li.timestamp = 6_000);
+    client.suspend_insurance_policy(&policy_id, &insurer);
+    assert!(client.is_covered_at(&policy_id, &5_999));
+    assert!(!client.is_covered_at(&policy_id, &6_000));
+    assert!(!client.is_covered_at(&policy_id, &8_000));
+
+    assert!(!client.is_covered_at(&generate_asset_id(&env, 2), &5_000));
+}
+
+#[test]
+fn test_reconcile_insurer_policies() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+    let asset_id = generate_asset_id(&env, 100);
+
+    env.mock_all_auths();
+    let policy = |seed: u32| {
+        create_test_policy(
+            &env,
+            generate_asset_id(&env, seed),
+            &holder,
+            &insurer,
+            asset_id.clone(),
+        )
+    };
+
+    // Due to expire
+    let mut ending = policy(1);
+    ending.end_date = 2_000;
+    client.create_insurance_policy(&ending);
+
+    // Premium lapsed past grace
+    let mut lapsed = policy(2);
+    lapsed.premium_interval = 500;
+    lapsed.premium_grace_secs = 100;
+    client.create_insurance_policy(&lapsed);
+
+    // Current, and already cancelled
+    let current = policy(3);
+    client.create_insurance_policy(&current);
+    let cancelled = policy(4);
+    client.create_insurance_policy(&cancelled);
+    client.cancel_insurance_policy(&cancelled.policy_id, &insurer);
+
+    env.ledger().with_mut(|li| li.timestamp = 3_000);
+    assert_eq!(client.reconcile_insurer_policies(&insurer), (1, 1));
+
+    let status = |id| client.get_insurance_policy(id).unwrap().status;
+    assert_eq!(status(&ending.policy_id), PolicyStatus::Expired);
+    assert_eq!(status(&lapsed.policy_id), PolicyStatus::Suspended);
+    assert_eq!(status(&current.policy_id), PolicyStatus::Active);
+    assert_eq!(status(&cancelled.policy_id), PolicyStatus::Cancelled);
+
+    // A second sweep has nothing left to do
+    assert_eq!(client.reconcile_insurer_policies(&insurer), (0, 0));
+}
+
+#[test]
+fn test_coverage_capped_by_tokenized_valuation() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    // Valuation starts at total supply
+    tokenize_test_asset(&env, &client, 1, 10000, &holder);
+    let asset_id = asset_id_to_bytes(&env, 1);
+    assert_eq!(client.get_max_coverage_ratio(), 10000);
+
+    // Within the default 1x limit
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    client.create_insurance_policy(&policy);
+
+    // Over the limit
+    let mut over = create_test_policy(
+        &env,
+        generate_asset_id(&env, 2),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    over.coverage_amount = 10001;
+    assert_eq!(
+        client.try_create_insurance_policy(&over),
+        Err(Ok(Error::CoverageExceedsValuation))
+    );
+
+    // Raising the factor admits the same coverage
+    assert_eq!(
+        client.try_set_max_coverage_ratio(&0u32),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.set_max_coverage_ratio(&15000u32);
+    client.create_insurance_policy(&over);
+    over.policy_id = generate_asset_id(&env, 3);
+    over.coverage_amount = 15001;
+    assert_eq!(
+        client.try_create_insurance_policy(&over),
+        Err(Ok(Error::CoverageExceedsValuation))
+    );
+}
+
+#[test]
+fn test_coverage_unchecked_for_non_tokenized_asset() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &holder);
+
+    // Encodes an ID with no tokenization, so any positive coverage is accepted
+    let mut policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id_to_bytes(&env, 2),
+    );
+    policy.coverage_amount = 10_000_000;
+    client.create_insurance_policy(&policy);
+    assert_eq!(
+        client
+            .get_insurance_policy(&policy.policy_id)
+            .unwrap()
+            .coverage_amount,
+        10_000_000
+    );
+}
+
+#[test]
+fn test_expire_asset_policies() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+    let asset_id = generate_asset_id(&env, 100);
+
+    env.mock_all_auths();
+    let policy = |seed: u32, end_date: u64| {
+        let mut policy = create_test_policy(
+            &env,
+            generate_asset_id(&env, seed),
+            &holder,
+            &insurer,
+            asset_id.clone(),
+        );
+        policy.end_date = end_date;
+        client.create_insurance_policy(&policy);
+        policy.policy_id
+    };
+
+    let stale_active = policy(1, 2_000);
+    let stale_suspended = policy(2, 2_500);
+    client.suspend_insurance_policy(&stale_suspended, &insurer);
+    let still_valid = policy(3, 5_000);
+    let stale_cancelled = policy(4, 2_000);
+    client.cancel_insurance_policy(&stale_cancelled, &insurer);
+
+    // A policy on another asset is not touched
+    let mut other = create_test_policy(
+        &env,
+        generate_asset_id(&env, 5),
+        &holder,
+        &insurer,
+        generate_asset_id(&env, 101),
+    );
+    other.end_date = 2_000;
+    client.create_insurance_policy(&other);
+
+    env.ledger().with_mut(|li| li.timestamp = 3_000);
+    assert_eq!(client.expire_asset_policies(&asset_id), 2);
+
+    let status = |id| client.get_insurance_policy(id).unwrap().status;
+    assert_eq!(status(&stale_active), PolicyStatus::Expired);
+    assert_eq!(status(&stale_suspended), PolicyStatus::Expired);
+    assert_eq!(status(&still_valid), PolicyStatus::Active);
+    assert_eq!(status(&stale_cancelled), PolicyStatus::Cancelled);
+    assert_eq!(status(&other.policy_id), PolicyStatus::Active);
+
+    assert_eq!(client.expire_asset_policies(&asset_id), 0);
+}
+
+#[test]
+fn test_transfer_policy_to_new_holder() {
+    let env = create_env();
+    let (admin, user1, insurer, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let policy_id = generate_asset_id(&env, 1);
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(&env, policy_id.clone(), &user1, &insurer, asset_id.clone());
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+
+    client.transfer_policy(&policy_id, &user3, &user1);
+
+    let stored_policy = client.get_insurance_policy(&policy_id).unwrap();
+    assert_eq!(stored_policy.holder, user3);
+
+    let logs = client.get_asset_audit_logs(&asset_id);
+    let last = logs.get(logs.len() - 1).unwrap();
+    assert_eq!(
+        last.action,
+        String::from_str(&env, "INSURANCE_POLICY_TRANSFERRED")
+    );
+    assert_eq!(last.actor, user1);
+}
+
+#[test]
+fn test_transfer_policy_rejected_for_non_holder() {
+    let env = create_env();
+    let (admin, user1, insurer, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let policy_id = generate_asset_id(&env, 1);
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(&env, policy_id.clone(), &user1, &insurer, asset_id);
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+
+    // Even the insurer cannot move the policy to someone else
+    assert_eq!(
+        client.try_transfer_policy(&policy_id, &user3, &insurer),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.get_insurance_policy(&policy_id).unwrap().holder,
+        user1
+    );
+}
+
+#[test]
+fn test_transfer_policy_rejected_when_expired() {
+    let env = create_env();
+    let (admin, user1, insurer, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let policy_id = generate_asset_id(&env, 1);
+    let asset_id = generate_asset_id(&env, 100);
+    let mut policy = create_test_policy(&env, policy_id.clone(), &user1, &insurer, asset_id);
+
+    env.ledger().with_mut(|li| li.timestamp = 5000);
+    policy.start_date = 5000;
+    policy.end_date = 6000;
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+
+    env.ledger().with_mut(|li| li.timestamp = 7000);
+    client.expire_insurance_policy(&policy_id);
+
+    assert_eq!(
+        client.try_transfer_policy(&policy_id, &user3, &user1),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_process_auto_renewals() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+    let asset_id = generate_asset_id(&env, 100);
+
+    env.mock_all_auths();
+    let policy = |seed: u32, auto_renew: bool| {
+        let mut policy = create_test_policy(
+            &env,
+            generate_asset_id(&env, seed),
+            &holder,
+            &insurer,
+            asset_id.clone(),
+        );
+        policy.end_date = 2_000;
+        policy.auto_renew = auto_renew;
+        client.create_insurance_policy(&policy);
+        policy
+    };
+    let renewing = policy(1, true);
+    let manual = policy(2, false);
+    mint_test_tokens(&env, &renewing.premium_token, &holder, 1_000);
+    TokenClient::new(&env, &renewing.premium_token).approve(
+        &holder,
+        &client.address,
+        &1_000,
+        &env.ledger().sequence(),
+    );
+
+    // Nothing is due before the end date
+    assert_eq!(client.process_auto_renewals(&asset_id), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_500);
+    assert_eq!(client.process_auto_renewals(&asset_id), 1);
+
+    // Extended by the original 1000s term and one premium charged
+    let stored = client.get_insurance_policy(&renewing.policy_id).unwrap();
+    assert_eq!(stored.status, PolicyStatus::Active);
+    assert_eq!(stored.end_date, 3_000);
+    assert_eq!(stored.last_payment, 2_500);
+    assert_eq!(
+        TokenClient::new(&env, &renewing.premium_token).balance(&holder),
+        900
+    );
+
+    // The non-auto policy is untouched and can still be expired
+    let stored = client.get_insurance_policy(&manual.policy_id).unwrap();
+    assert_eq!(stored.status, PolicyStatus::Active);
+    assert_eq!(stored.end_date, 2_000);
+    assert_eq!(client.expire_asset_policies(&asset_id), 1);
+    assert_eq!(
+        client
+            .get_insurance_policy(&manual.policy_id)
+            .unwrap()
+            .status,
+        PolicyStatus::Expired
+    );
+
+    // A second renewal uses the same term rather than the extended one
+    env.ledger().with_mut(|li| li.timestamp = 3_500);
+    assert_eq!(client.process_auto_renewals(&asset_id), 1);
+    assert_eq!(
+        client
+            .get_insurance_policy(&renewing.policy_id)
+            .unwrap()
+            .end_date,
+        4_000
+    );
+}
+
+#[test]
+fn test_process_auto_renewals_skips_unfunded_holder() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, funded, unfunded, insurer) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+    let asset_id = generate_asset_id(&env, 100);
+
+    env.mock_all_auths();
+    let policy = |seed: u32, holder: &Address| {
+        let mut policy = create_test_policy(
+            &env,
+            generate_asset_id(&env, seed),
+            holder,
+            &insurer,
+            asset_id.clone(),
+        );
+        policy.end_date = 2_000;
+        policy.auto_renew = true;
+        client.create_insurance_policy(&policy);
+        policy
+    };
+    let paid = policy(1, &funded);
+    let lapsing = policy(2, &unfunded);
+    mint_test_tokens(&env, &paid.premium_token, &funded, 1_000);
+    TokenClient::new(&env, &paid.premium_token).approve(
+        &funded,
+        &client.address,
+        &1_000,
+        &env.ledger().sequence(),
+    );
+
+    // The keeper's sweep runs without any holder signing for it
+    env.set_auths(&[]);
+    env.ledger().with_mut(|li| li.timestamp = 2_500);
+    assert_eq!(client.process_auto_renewals(&asset_id), 1);
+
+    assert_eq!(
+        client
+            .get_insurance_policy(&paid.policy_id)
+            .unwrap()
+            .end_date,
+        3_000
+    );
+    assert_eq!(
+        TokenClient::new(&env, &paid.premium_token).balance(&funded),
+        900
+    );
+    assert_eq!(
+        client
+            .get_insurance_policy(&lapsing.policy_id)
+            .unwrap()
+            .end_date,
+        2_000
+    );
+}
@@ -0,0 +1,1944 @@
+use crate::error::Error;
+use crate::insurance::{self, ClaimStatus, ClaimType, PolicyAction, PolicyStatus};
+use crate::reentrancy;
+use crate::tests::helpers::*;
+use soroban_sdk::testutils::Events;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{vec, Address, BytesN, Env, IntoVal, String, Val, Vec};
+
+#[test]
+fn test_insurer_summary_mixed_states() {
+    let env = create_env();
+    let (admin, holder, insurer, other_insurer) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy_a = generate_asset_id(&env, 1);
+    let policy_b = generate_asset_id(&env, 2);
+    let policy_c = generate_asset_id(&env, 3);
+    let policy_other = generate_asset_id(&env, 4);
+
+    env.mock_all_auths();
+    for policy_id in [policy_a.clone(), policy_b.clone(), policy_c.clone()] {
+        let policy = create_test_policy(&env, policy_id, &holder, &insurer, asset_id.clone());
+        client.create_insurance_policy(&policy);
+    }
+    let policy = create_test_policy(
+        &env,
+        policy_other.clone(),
+        &holder,
+        &other_insurer,
+        asset_id.clone(),
+    );
+    client.create_insurance_policy(&policy);
+
+    // Claims against policy A: one paid, one rejected
+    let paid_claim = generate_asset_id(&env, 10);
+    let rejected_claim = generate_asset_id(&env, 11);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        paid_claim.clone(),
+        policy_a.clone(),
+        asset_id.clone(),
+        &holder,
+    ));
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        rejected_claim.clone(),
+        policy_a.clone(),
+        asset_id.clone(),
+        &holder,
+    ));
+    client.start_claim_review(&paid_claim, &insurer);
+    // 2500 approved pays 2000 after the 500 deductible
+    client.approve_insurance_claim(&paid_claim, &insurer, &2500i128);
+    let token = client
+        .get_insurance_policy(&policy_a)
+        .unwrap()
+        .premium_token;
+    mint_test_tokens(&env, &token, &insurer, 2000);
+    client.fund_claim_pool(&token, &insurer, &2000i128);
+    client.pay_insurance_claim(&paid_claim, &insurer);
+    client.start_claim_review(&rejected_claim, &insurer);
+    client.reject_insurance_claim(
+        &rejected_claim,
+        &insurer,
+        &String::from_str(&env, "Damage predates policy"),
+    );
+
+    // Claims against policy B: one under review, one submitted
+    let review_claim = generate_asset_id(&env, 12);
+    let submitted_claim = generate_asset_id(&env, 13);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        review_claim.clone(),
+        policy_b.clone(),
+        asset_id.clone(),
+        &holder,
+    ));
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        submitted_claim,
+        policy_b,
+        asset_id.clone(),
+        &holder,
+    ));
+    client.start_claim_review(&review_claim, &insurer);
+
+    // Claim against another insurer's policy must not be counted
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        generate_asset_id(&env, 14),
+        policy_other,
+        asset_id,
+        &holder,
+    ));
+
+    // Policy C is cancelled and no longer active
+    client.cancel_insurance_policy(&policy_c, &insurer);
+
+    let stored = client.get_insurance_claim(&paid_claim).unwrap();
+    assert_eq!(stored.status, ClaimStatus::Paid);
+
+    let (active_policies, open_claims, paid_total) = client.insurer_summary(&insurer);
+    assert_eq!(active_policies, 2);
+    assert_eq!(open_claims, 2);
+    assert_eq!(paid_total, 2000);
+
+    let (other_active, other_open, other_paid) = client.insurer_summary(&other_insurer);
+    assert_eq!(other_active, 1);
+    assert_eq!(other_open, 1);
+    assert_eq!(other_paid, 0);
+}
+
+#[test]
+fn test_insurer_summary_unknown_insurer() {
+    let env = create_env();
+    let (admin, _, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    assert_eq!(client.insurer_summary(&insurer), (0, 0, 0));
+}
+
+#[test]
+fn test_excluded_peril_rejected_and_covered_peril_accepted() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy_id = generate_asset_id(&env, 1);
+    let mut policy =
+        create_test_policy(&env, policy_id.clone(), &holder, &insurer, asset_id.clone());
+    policy.excluded_claim_types = Vec::from_array(&env, [ClaimType::Loss]);
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+
+    // Theft is covered
+    let mut theft = create_test_claim(
+        &env,
+        generate_asset_id(&env, 10),
+        policy_id.clone(),
+        asset_id.clone(),
+        &holder,
+    );
+    theft.claim_type = ClaimType::Theft;
+    client.file_insurance_claim(&theft);
+
+    // Loss is excluded
+    let mut loss = create_test_claim(
+        &env,
+        generate_asset_id(&env, 11),
+        policy_id,
+        asset_id.clone(),
+        &holder,
+    );
+    loss.claim_type = ClaimType::Loss;
+    let result = client.try_file_insurance_claim(&loss);
+    assert_eq!(result, Err(Ok(Error::PerilExcluded)));
+
+    assert_eq!(client.get_asset_insurance_claims(&asset_id).len(), 1);
+}
+
+#[test]
+fn test_claims_rejected_during_waiting_period() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy_id = generate_asset_id(&env, 1);
+    let mut policy =
+        create_test_policy(&env, policy_id.clone(), &holder, &insurer, asset_id.clone());
+    policy.claim_waiting_period_secs = 500;
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+
+    let claim = create_test_claim(
+        &env,
+        generate_asset_id(&env, 10),
+        policy_id,
+        asset_id.clone(),
+        &holder,
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 1_499);
+    let result = client.try_file_insurance_claim(&claim);
+    assert_eq!(result, Err(Ok(Error::PeriodNotElapsed)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_500);
+    client.file_insurance_claim(&claim);
+    assert_eq!(client.get_asset_insurance_claims(&asset_id).len(), 1);
+}
+
+#[test]
+fn test_endorsement_amends_exclusions() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy_id = generate_asset_id(&env, 1);
+    let policy = create_test_policy(&env, policy_id.clone(), &holder, &insurer, asset_id.clone());
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+
+    // Holder cannot endorse
+    let exclusions = Vec::from_array(&env, [ClaimType::Theft]);
+    let result = client.try_endorse_policy_exclusions(&policy_id, &exclusions, &holder);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.endorse_policy_exclusions(&policy_id, &exclusions, &insurer);
+    let stored = client.get_insurance_policy(&policy_id).unwrap();
+    assert_eq!(stored.excluded_claim_types, exclusions);
+
+    let mut theft = create_test_claim(
+        &env,
+        generate_asset_id(&env, 10),
+        policy_id,
+        asset_id,
+        &holder,
+    );
+    theft.claim_type = ClaimType::Theft;
+    let result = client.try_file_insurance_claim(&theft);
+    assert_eq!(result, Err(Ok(Error::PerilExcluded)));
+}
+
+#[test]
+fn test_premiums_route_to_policy_token() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy_a = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    let policy_b = create_test_policy(
+        &env,
+        generate_asset_id(&env, 2),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    let token_a = policy_a.premium_token.clone();
+    let token_b = policy_b.premium_token.clone();
+    assert_ne!(token_a, token_b);
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy_a);
+    client.create_insurance_policy(&policy_b);
+
+    mint_test_tokens(&env, &token_a, &holder, 1000);
+    mint_test_tokens(&env, &token_b, &holder, 1000);
+
+    client.pay_premium(&policy_a.policy_id, &holder, &100i128);
+    client.pay_premium(&policy_b.policy_id, &holder, &250i128);
+
+    assert_eq!(TokenClient::new(&env, &token_a).balance(&holder), 900);
+    assert_eq!(TokenClient::new(&env, &token_b).balance(&holder), 750);
+    assert_eq!(
+        TokenClient::new(&env, &token_a).balance(&client.address),
+        100
+    );
+    assert_eq!(
+        TokenClient::new(&env, &token_b).balance(&client.address),
+        250
+    );
+    assert_eq!(client.get_pool_balance(&token_a), 100);
+    assert_eq!(client.get_pool_balance(&token_b), 250);
+
+    // Underpaying the premium is rejected
+    let result = client.try_pay_premium(&policy_a.policy_id, &holder, &99i128);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+}
+
+#[test]
+fn test_claim_paid_from_policy_token_pool() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+
+    let claim_id = generate_asset_id(&env, 10);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id,
+        &holder,
+    ));
+    client.start_claim_review(&claim_id, &insurer);
+    client.approve_insurance_claim(&claim_id, &insurer, &3000i128);
+
+    // Pool is empty, so the payout cannot be made
+    let result = client.try_pay_insurance_claim(&claim_id, &insurer);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+
+    mint_test_tokens(&env, &token, &insurer, 5000);
+    client.fund_claim_pool(&token, &insurer, &5000i128);
+    client.pay_insurance_claim(&claim_id, &insurer);
+
+    // 3000 approved less the 500 deductible
+    assert_eq!(TokenClient::new(&env, &token).balance(&holder), 2500);
+    assert_eq!(client.get_pool_balance(&token), 2500);
+}
+
+#[test]
+fn test_claim_lifecycle_blocked_while_paused() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    mint_test_tokens(&env, &token, &insurer, 5000);
+    client.fund_claim_pool(&token, &insurer, &5000i128);
+
+    let claim_id = generate_asset_id(&env, 10);
+    let claim = create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id,
+        &holder,
+    );
+
+    client.pause_contract();
+    let result = client.try_file_insurance_claim(&claim);
+    assert_eq!(result, Err(Ok(Error::ContractPaused)));
+    client.unpause_contract();
+
+    client.file_insurance_claim(&claim);
+    client.start_claim_review(&claim_id, &insurer);
+
+    client.pause_contract();
+    let result = client.try_approve_insurance_claim(&claim_id, &insurer, &3000i128);
+    assert_eq!(result, Err(Ok(Error::ContractPaused)));
+    client.unpause_contract();
+    client.approve_insurance_claim(&claim_id, &insurer, &3000i128);
+
+    // No funds leave the pool while paused
+    client.pause_contract();
+    let result = client.try_pay_insurance_claim(&claim_id, &insurer);
+    assert_eq!(result, Err(Ok(Error::ContractPaused)));
+    let result = client.try_pay_claim_installment(&claim_id);
+    assert_eq!(result, Err(Ok(Error::ContractPaused)));
+    assert_eq!(client.get_pool_balance(&token), 5000);
+
+    client.unpause_contract();
+    client.pay_insurance_claim(&claim_id, &insurer);
+    assert_eq!(TokenClient::new(&env, &token).balance(&holder), 2500);
+}
+
+#[test]
+fn test_create_policy_rejects_non_token_premium_address() {
+    let env = create_env();
+    let (admin, holder, insurer, not_a_token) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let mut policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        generate_asset_id(&env, 100),
+    );
+    policy.premium_token = not_a_token;
+
+    env.mock_all_auths();
+    let result = client.try_create_insurance_policy(&policy);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+}
+
+#[test]
+fn test_policy_next_action_states() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy_id = generate_asset_id(&env, 1);
+    let day = 24 * 60 * 60;
+
+    env.mock_all_auths();
+    let mut policy = create_test_policy(&env, policy_id.clone(), &holder, &insurer, asset_id);
+    policy.premium_interval = 90 * day;
+    client.create_insurance_policy(&policy);
+
+    // Fresh policy with a recent payment
+    assert_eq!(client.policy_next_action(&policy_id), PolicyAction::Current);
+
+    // Inside the notice window before the next premium
+    let due = 1_000 + 90 * day;
+    env.ledger().with_mut(|li| li.timestamp = due - day);
+    assert_eq!(
+        client.policy_next_action(&policy_id),
+        PolicyAction::PremiumDue(100, due)
+    );
+
+    // Paying the premium pushes the due date out
+    mint_test_tokens(&env, &policy.premium_token, &holder, 100);
+    client.pay_premium(&policy_id, &holder, &100);
+    assert_eq!(client.policy_next_action(&policy_id), PolicyAction::Current);
+
+    // Close to end_date, paid up so the next premium would fall after it
+    env.ledger()
+        .with_mut(|li| li.timestamp = policy.end_date - 10 * day);
+    mint_test_tokens(&env, &policy.premium_token, &holder, 100);
+    client.pay_premium(&policy_id, &holder, &100);
+    assert_eq!(
+        client.policy_next_action(&policy_id),
+        PolicyAction::RenewalDue(policy.end_date)
+    );
+
+    // Past end_date
+    env.ledger()
+        .with_mut(|li| li.timestamp = policy.end_date + 1);
+    assert_eq!(client.policy_next_action(&policy_id), PolicyAction::Expired);
+}
+
+#[test]
+fn test_policy_next_action_cancelled_and_missing() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let policy_id = generate_asset_id(&env, 1);
+    env.mock_all_auths();
+    let policy = create_test_policy(
+        &env,
+        policy_id.clone(),
+        &holder,
+        &insurer,
+        generate_asset_id(&env, 100),
+    );
+    client.create_insurance_policy(&policy);
+    client.cancel_insurance_policy(&policy_id, &holder);
+    assert_eq!(
+        client.policy_next_action(&policy_id),
+        PolicyAction::Cancelled
+    );
+
+    let result = client.try_policy_next_action(&generate_asset_id(&env, 2));
+    assert_eq!(result, Err(Ok(Error::AssetNotFound)));
+}
+
+#[test]
+fn test_claim_paid_in_installments() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    mint_test_tokens(&env, &token, &insurer, 5000);
+    client.fund_claim_pool(&token, &insurer, &5000i128);
+
+    let claim_id = generate_asset_id(&env, 10);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id,
+        &holder,
+    ));
+    client.start_claim_review(&claim_id, &insurer);
+    // 1500 approved leaves 1000 to pay after the 500 deductible
+    client.approve_insurance_claim(&claim_id, &insurer, &1500i128);
+    client.schedule_claim_payout(&claim_id, &3u32, &100u64, &insurer);
+
+    // Lump-sum payout is no longer available
+    let result = client.try_pay_insurance_claim(&claim_id, &insurer);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    // First installment is due immediately
+    assert_eq!(client.pay_claim_installment(&claim_id), 333);
+    let result = client.try_pay_claim_installment(&claim_id);
+    assert_eq!(result, Err(Ok(Error::PeriodNotElapsed)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_099);
+    let result = client.try_pay_claim_installment(&claim_id);
+    assert_eq!(result, Err(Ok(Error::PeriodNotElapsed)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_100);
+    assert_eq!(client.pay_claim_installment(&claim_id), 333);
+    assert_eq!(
+        client.get_insurance_claim(&claim_id).unwrap().status,
+        ClaimStatus::Approved
+    );
+
+    // Final installment carries the rounding remainder and closes the claim
+    env.ledger().with_mut(|li| li.timestamp = 1_200);
+    assert_eq!(client.pay_claim_installment(&claim_id), 334);
+    assert_eq!(
+        client.get_insurance_claim(&claim_id).unwrap().status,
+        ClaimStatus::Paid
+    );
+
+    let schedule = client.get_claim_payout_schedule(&claim_id).unwrap();
+    assert_eq!(schedule.installments_paid, 3);
+    assert_eq!(schedule.amount_paid, 1000);
+    assert_eq!(TokenClient::new(&env, &token).balance(&holder), 1000);
+    assert_eq!(client.get_pool_balance(&token), 4000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_300);
+    let result = client.try_pay_claim_installment(&claim_id);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_revise_approval_before_payout() {
+    let env = create_env();
+    let (admin, holder, insurer, other) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    mint_test_tokens(&env, &token, &insurer, 5000);
+    client.fund_claim_pool(&token, &insurer, &5000i128);
+
+    let claim_id = generate_asset_id(&env, 10);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id.clone(),
+        &holder,
+    ));
+    client.start_claim_review(&claim_id, &insurer);
+    client.approve_insurance_claim(&claim_id, &insurer, &3000i128);
+
+    // Claim of 5000 caps the revision at 5000
+    let result = client.try_revise_approval(&claim_id, &5001i128, &insurer);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+    let result = client.try_revise_approval(&claim_id, &2000i128, &other);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.revise_approval(&claim_id, &2000i128, &insurer);
+    assert_eq!(
+        client
+            .get_insurance_claim(&claim_id)
+            .unwrap()
+            .approved_amount,
+        2000
+    );
+    let logs = client.get_asset_audit_logs(&asset_id);
+    assert_eq!(
+        logs.get(logs.len() - 1).unwrap().action,
+        String::from_str(&env, "INSURANCE_CLAIM_APPROVAL_REVISED")
+    );
+
+    client.pay_insurance_claim(&claim_id, &insurer);
+    assert_eq!(TokenClient::new(&env, &token).balance(&holder), 1500);
+
+    let result = client.try_revise_approval(&claim_id, &2500i128, &insurer);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_premium_and_pool_amounts_must_be_positive() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        generate_asset_id(&env, 100),
+    );
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+
+    for amount in [0i128, -1i128] {
+        assert_eq!(
+            client.try_pay_premium(&policy.policy_id, &holder, &amount),
+            Err(Ok(Error::InvalidAmount))
+        );
+        assert_eq!(
+            client.try_fund_claim_pool(&token, &insurer, &amount),
+            Err(Ok(Error::InvalidAmount))
+        );
+
+        let mut bad_policy = create_test_policy(
+            &env,
+            generate_asset_id(&env, 2),
+            &holder,
+            &insurer,
+            generate_asset_id(&env, 100),
+        );
+        bad_policy.premium = amount;
+        assert_eq!(
+            client.try_create_insurance_policy(&bad_policy),
+            Err(Ok(Error::InvalidAmount))
+        );
+    }
+}
+
+/// Status transitions (old, new) published by the most recent contract call
+fn claim_status_changes(env: &Env) -> Vec<(Option<ClaimStatus>, ClaimStatus)> {
+    let topics: Vec<Val> = ("insurance", "claim_status_changed").into_val(env);
+    let mut changes = Vec::new(env);
+    for (_, event_topics, data) in env.events().all().iter() {
+        if event_topics == topics {
+            let (_, old, new, _): (BytesN<32>, Option<ClaimStatus>, ClaimStatus, Address) =
+                data.into_val(env);
+            changes.push_back((old, new));
+        }
+    }
+    changes
+}
+
+#[test]
+fn test_claim_status_changed_events() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    mint_test_tokens(&env, &token, &insurer, 5000);
+    client.fund_claim_pool(&token, &insurer, &5000i128);
+
+    let claim_id = generate_asset_id(&env, 10);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id,
+        &holder,
+    ));
+    assert_eq!(
+        claim_status_changes(&env),
+        vec![&env, (None, ClaimStatus::Submitted)]
+    );
+
+    client.start_claim_review(&claim_id, &insurer);
+    assert_eq!(
+        claim_status_changes(&env),
+        vec![
+            &env,
+            (Some(ClaimStatus::Submitted), ClaimStatus::UnderReview)
+        ]
+    );
+
+    client.approve_insurance_claim(&claim_id, &insurer, &3000i128);
+    assert_eq!(
+        claim_status_changes(&env),
+        vec![
+            &env,
+            (Some(ClaimStatus::UnderReview), ClaimStatus::Approved)
+        ]
+    );
+
+    client.pay_insurance_claim(&claim_id, &insurer);
+    assert_eq!(
+        claim_status_changes(&env),
+        vec![&env, (Some(ClaimStatus::Approved), ClaimStatus::Paid)]
+    );
+}
+
+#[test]
+fn test_claim_timeline_records_each_transition() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    mint_test_tokens(&env, &token, &insurer, 5000);
+    client.fund_claim_pool(&token, &insurer, &5000i128);
+
+    let claim_id = generate_asset_id(&env, 10);
+    assert_eq!(client.get_claim_timeline(&claim_id).len(), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_100);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id,
+        &holder,
+    ));
+    env.ledger().with_mut(|li| li.timestamp = 1_200);
+    client.start_claim_review(&claim_id, &insurer);
+    env.ledger().with_mut(|li| li.timestamp = 1_300);
+    client.approve_insurance_claim(&claim_id, &insurer, &3000i128);
+    env.ledger().with_mut(|li| li.timestamp = 1_400);
+    client.pay_insurance_claim(&claim_id, &insurer);
+
+    assert_eq!(
+        client.get_claim_timeline(&claim_id),
+        vec![
+            &env,
+            (1_100u64, ClaimStatus::Submitted, holder.clone()),
+            (1_200u64, ClaimStatus::UnderReview, insurer.clone()),
+            (1_300u64, ClaimStatus::Approved, insurer.clone()),
+            (1_400u64, ClaimStatus::Paid, insurer),
+        ]
+    );
+}
+
+#[test]
+fn test_global_exposure_running_totals() {
+    let env = create_env();
+    let (admin, holder, insurer, other_insurer) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_a = generate_asset_id(&env, 100);
+    let asset_b = generate_asset_id(&env, 101);
+    let policy_a = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_a.clone(),
+    );
+    let policy_b = create_test_policy(
+        &env,
+        generate_asset_id(&env, 2),
+        &holder,
+        &other_insurer,
+        asset_b,
+    );
+    let token = policy_a.premium_token.clone();
+
+    env.mock_all_auths();
+    assert_eq!(client.global_exposure(), (0, 0));
+
+    client.create_insurance_policy(&policy_a);
+    client.create_insurance_policy(&policy_b);
+    assert_eq!(client.global_exposure(), (20000, 0));
+
+    client.cancel_insurance_policy(&policy_b.policy_id, &holder);
+    assert_eq!(client.global_exposure(), (10000, 0));
+
+    mint_test_tokens(&env, &token, &insurer, 5000);
+    client.fund_claim_pool(&token, &insurer, &5000i128);
+
+    let claim_id = generate_asset_id(&env, 10);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy_a.policy_id.clone(),
+        asset_a,
+        &holder,
+    ));
+    client.start_claim_review(&claim_id, &insurer);
+    // Approved-unpaid tracks what will be paid, net of the 500 deductible
+    client.approve_insurance_claim(&claim_id, &insurer, &3000i128);
+    assert_eq!(client.global_exposure(), (10000, 2500));
+
+    client.revise_approval(&claim_id, &2500i128, &insurer);
+    assert_eq!(client.global_exposure(), (10000, 2000));
+
+    client.pay_insurance_claim(&claim_id, &insurer);
+    assert_eq!(client.global_exposure(), (10000, 0));
+
+    client.suspend_insurance_policy(&policy_a.policy_id, &insurer);
+    assert_eq!(client.global_exposure(), (0, 0));
+}
+
+#[test]
+fn test_payouts_reject_reentrant_calls() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    mint_test_tokens(&env, &token, &insurer, 5000);
+    client.fund_claim_pool(&token, &insurer, &5000i128);
+
+    let lump_sum_claim = generate_asset_id(&env, 10);
+    let scheduled_claim = generate_asset_id(&env, 11);
+    for claim_id in [lump_sum_claim.clone(), scheduled_claim.clone()] {
+        client.file_insurance_claim(&create_test_claim(
+            &env,
+            claim_id.clone(),
+            policy.policy_id.clone(),
+            asset_id.clone(),
+            &holder,
+        ));
+        client.start_claim_review(&claim_id, &insurer);
+        // Each pays 1000 after the 500 deductible
+        client.approve_insurance_claim(&claim_id, &insurer, &1500i128);
+    }
+    client.schedule_claim_payout(&scheduled_claim, &1u32, &0u64, &insurer);
+
+    // Simulate a call arriving while a guarded payout is still in flight
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&reentrancy::DataKey::Locked, &true);
+    });
+    assert_eq!(
+        client.try_pay_insurance_claim(&lump_sum_claim, &insurer),
+        Err(Ok(Error::Reentrancy))
+    );
+    assert_eq!(
+        client.try_pay_claim_installment(&scheduled_claim),
+        Err(Ok(Error::Reentrancy))
+    );
+
+    // Once the lock is released both paths pay out and release it again
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .remove(&reentrancy::DataKey::Locked);
+    });
+    client.pay_insurance_claim(&lump_sum_claim, &insurer);
+    assert_eq!(client.pay_claim_installment(&scheduled_claim), 1000);
+    assert_eq!(TokenClient::new(&env, &token).balance(&holder), 2000);
+}
+
+#[test]
+fn test_nested_claim_payout_is_rejected() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    mint_test_tokens(&env, &token, &insurer, 5000);
+    client.fund_claim_pool(&token, &insurer, &5000i128);
+
+    let claim_id = generate_asset_id(&env, 10);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id.clone(),
+        &holder,
+    ));
+    client.start_claim_review(&claim_id, &insurer);
+    client.approve_insurance_claim(&claim_id, &insurer, &1500i128);
+
+    // Simulate the outer pay_claim still holding this claim's payout lock
+    let lock_key = insurance::DataKey::ClaimPayoutLock(claim_id.clone());
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(&lock_key, &true);
+    });
+    assert_eq!(
+        client.try_pay_insurance_claim(&claim_id, &insurer),
+        Err(Ok(Error::Reentrancy))
+    );
+    assert_eq!(
+        client.get_insurance_claim(&claim_id).unwrap().status,
+        ClaimStatus::Approved
+    );
+
+    // The outer call finishes and releases the lock; the payout then succeeds once
+    env.as_contract(&client.address, || {
+        env.storage().persistent().remove(&lock_key);
+    });
+    client.pay_insurance_claim(&claim_id, &insurer);
+    assert_eq!(TokenClient::new(&env, &token).balance(&holder), 1000);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().persistent().has(&lock_key));
+    });
+}
+
+#[test]
+fn test_missed_premium_auto_suspends_until_paid() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let mut policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    policy.premium_interval = 100;
+    policy.premium_grace_secs = 50;
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+
+    // Still within the grace period
+    env.ledger().with_mut(|li| li.timestamp = 1_150);
+    assert_eq!(
+        client.check_premium_status(&policy.policy_id),
+        PolicyStatus::Active
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 1_151);
+    assert_eq!(
+        client.check_premium_status(&policy.policy_id),
+        PolicyStatus::Suspended
+    );
+    let logs = client.get_asset_audit_logs(&asset_id);
+    assert_eq!(
+        logs.get(logs.len() - 1).unwrap().action,
+        String::from_str(&env, "INSURANCE_POLICY_LAPSE_SUSPENDED")
+    );
+
+    mint_test_tokens(&env, &token, &holder, 100);
+    client.pay_premium(&policy.policy_id, &holder, &100i128);
+    let stored = client.get_insurance_policy(&policy.policy_id).unwrap();
+    assert_eq!(stored.status, PolicyStatus::Active);
+    assert_eq!(stored.last_payment, 1_151);
+}
+
+#[test]
+fn test_premium_payment_does_not_lift_insurer_suspension() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        generate_asset_id(&env, 100),
+    );
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    client.suspend_insurance_policy(&policy.policy_id, &insurer);
+
+    mint_test_tokens(&env, &token, &holder, 100);
+    client.pay_premium(&policy.policy_id, &holder, &100i128);
+    assert_eq!(
+        client
+            .get_insurance_policy(&policy.policy_id)
+            .unwrap()
+            .status,
+        PolicyStatus::Suspended
+    );
+}
+
+#[test]
+fn test_coinsurance_reduces_payout_after_deductible() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let mut policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    policy.coinsurance_bps = 2000;
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    mint_test_tokens(&env, &token, &insurer, 10000);
+    client.fund_claim_pool(&token, &insurer, &10000i128);
+
+    // 5000 loss: (5000 - 500 deductible) * 80% = 3600
+    let claim_id = generate_asset_id(&env, 10);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id.clone(),
+        &holder,
+    ));
+    client.start_claim_review(&claim_id, &insurer);
+    assert_eq!(client.get_max_claim_payout(&claim_id), 3600);
+
+    let result = client.try_approve_insurance_claim(&claim_id, &insurer, &5001i128);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+    client.approve_insurance_claim(&claim_id, &insurer, &5000i128);
+    client.pay_insurance_claim(&claim_id, &insurer);
+
+    // Holder bears the 500 deductible plus 20% of the remaining 4500
+    assert_eq!(TokenClient::new(&env, &token).balance(&holder), 3600);
+
+    // Losses above coverage are capped before the deductible applies
+    let mut large = create_test_claim(
+        &env,
+        generate_asset_id(&env, 11),
+        policy.policy_id.clone(),
+        asset_id.clone(),
+        &holder,
+    );
+    large.amount = 20000;
+    client.file_insurance_claim(&large);
+    assert_eq!(client.get_max_claim_payout(&large.claim_id), 7600);
+
+    // Losses at or below the deductible pay nothing
+    let mut small = create_test_claim(
+        &env,
+        generate_asset_id(&env, 12),
+        policy.policy_id,
+        asset_id,
+        &holder,
+    );
+    small.amount = 400;
+    client.file_insurance_claim(&small);
+    assert_eq!(client.get_max_claim_payout(&small.claim_id), 0);
+}
+
+#[test]
+fn test_approve_claim_full_and_partial_amounts() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let mut policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    policy.deductible = 0;
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+
+    let file = |seed: u32| {
+        let claim_id = generate_asset_id(&env, seed);
+        client.file_insurance_claim(&create_test_claim(
+            &env,
+            claim_id.clone(),
+            policy.policy_id.clone(),
+            asset_id.clone(),
+            &holder,
+        ));
+        client.start_claim_review(&claim_id, &insurer);
+        claim_id
+    };
+
+    // Full approval of the 5000 claimed
+    let full = file(10);
+    client.approve_insurance_claim(&full, &insurer, &5000i128);
+    let claim = client.get_insurance_claim(&full).unwrap();
+    assert_eq!(claim.status, ClaimStatus::Approved);
+    assert_eq!(claim.approved_amount, 5000);
+
+    // Partial approval after assessment
+    let partial = file(11);
+    client.approve_insurance_claim(&partial, &insurer, &3000i128);
+    assert_eq!(
+        client
+            .get_insurance_claim(&partial)
+            .unwrap()
+            .approved_amount,
+        3000
+    );
+
+    // Zero and over-claim approvals are rejected
+    let rejected = file(12);
+    assert_eq!(
+        client.try_approve_insurance_claim(&rejected, &insurer, &0i128),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_approve_insurance_claim(&rejected, &insurer, &5001i128),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.get_insurance_claim(&rejected).unwrap().status,
+        ClaimStatus::UnderReview
+    );
+}
+
+#[test]
+fn test_approve_claim_capped_at_coverage() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let mut policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    policy.coverage_amount = 4000;
+    policy.deductible = 0;
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+
+    let claim_id = generate_asset_id(&env, 10);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id.clone(),
+        &holder,
+    ));
+    client.start_claim_review(&claim_id, &insurer);
+
+    assert_eq!(
+        client.try_approve_insurance_claim(&claim_id, &insurer, &4001i128),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.approve_insurance_claim(&claim_id, &insurer, &4000i128);
+}
+
+#[test]
+fn test_pay_claim_applies_deductible() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    mint_test_tokens(&env, &token, &insurer, 5000);
+    client.fund_claim_pool(&token, &insurer, &5000i128);
+
+    let topics: Vec<Val> = ("insurance", "claim_paid").into_val(&env);
+    let token_client = TokenClient::new(&env, &token);
+
+    // Deductible of 500 against approvals below, equal to and above it
+    for (seed, approved, expected) in [(10u32, 2000i128, 1500i128), (11, 500, 0), (12, 300, 0)] {
+        let claim_id = generate_asset_id(&env, seed);
+        client.file_insurance_claim(&create_test_claim(
+            &env,
+            claim_id.clone(),
+            policy.policy_id.clone(),
+            asset_id.clone(),
+            &holder,
+        ));
+        client.start_claim_review(&claim_id, &insurer);
+        client.approve_insurance_claim(&claim_id, &insurer, &approved);
+
+        let before = token_client.balance(&holder);
+        client.pay_insurance_claim(&claim_id, &insurer);
+
+        let (_, event_topics, data) = env.events().all().last().unwrap();
+        assert_eq!(event_topics, topics);
+        let (paid_id, payout): (BytesN<32>, i128) = data.into_val(&env);
+        assert_eq!(paid_id, claim_id);
+        assert_eq!(payout, expected);
+
+        let claim = client.get_insurance_claim(&claim_id).unwrap();
+        assert_eq!(claim.status, ClaimStatus::Paid);
+        assert_eq!(claim.approved_amount, approved);
+        assert_eq!(claim.paid_amount, expected);
+        assert_eq!(token_client.balance(&holder) - before, expected);
+    }
+
+    assert_eq!(client.get_pool_balance(&token), 3500);
+}
+
+#[test]
+fn test_reject_claim_stores_reason() {
+    let env = create_env();
+    let (admin, holder, insurer, other) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+
+    let claim_id = generate_asset_id(&env, 10);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id.clone(),
+        &holder,
+    ));
+    client.start_claim_review(&claim_id, &insurer);
+
+    let reason = String::from_str(&env, "Loss not covered by policy terms");
+    let result = client.try_reject_insurance_claim(&claim_id, &other, &reason);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.reject_insurance_claim(&claim_id, &insurer, &reason);
+    let claim = client.get_insurance_claim(&claim_id).unwrap();
+    assert_eq!(claim.status, ClaimStatus::Rejected);
+    assert_eq!(claim.reason, Some(reason.clone()));
+
+    let logs = client.get_asset_audit_logs(&asset_id);
+    let entry = logs.get(logs.len() - 1).unwrap();
+    assert_eq!(
+        entry.action,
+        String::from_str(&env, "INSURANCE_CLAIM_REJECTED")
+    );
+    assert_eq!(entry.details, reason);
+}
+
+#[test]
+fn test_reject_claim_fails_once_approved_or_paid() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    mint_test_tokens(&env, &token, &insurer, 5000);
+    client.fund_claim_pool(&token, &insurer, &5000i128);
+
+    let claim_id = generate_asset_id(&env, 10);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id,
+        &holder,
+    ));
+    client.start_claim_review(&claim_id, &insurer);
+    client.approve_insurance_claim(&claim_id, &insurer, &2000i128);
+
+    let reason = String::from_str(&env, "Reconsidered");
+    let result = client.try_reject_insurance_claim(&claim_id, &insurer, &reason);
+    assert_eq!(result, Err(Ok(Error::InvalidStatusTransition)));
+
+    client.pay_insurance_claim(&claim_id, &insurer);
+    let result = client.try_reject_insurance_claim(&claim_id, &insurer, &reason);
+    assert_eq!(result, Err(Ok(Error::InvalidStatusTransition)));
+
+    let claim = client.get_insurance_claim(&claim_id).unwrap();
+    assert_eq!(claim.status, ClaimStatus::Paid);
+    assert_eq!(claim.reason, None);
+}
+
+#[test]
+fn test_policy_claims_index() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    let other_policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 2),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    client.create_insurance_policy(&other_policy);
+    assert_eq!(client.get_policy_claims(&policy.policy_id).len(), 0);
+
+    let claim_ids = vec![
+        &env,
+        generate_asset_id(&env, 10),
+        generate_asset_id(&env, 11),
+        generate_asset_id(&env, 12),
+    ];
+    for claim_id in claim_ids.iter() {
+        client.file_insurance_claim(&create_test_claim(
+            &env,
+            claim_id,
+            policy.policy_id.clone(),
+            asset_id.clone(),
+            &holder,
+        ));
+    }
+    // A claim on another policy of the same asset is indexed separately
+    let other_claim = generate_asset_id(&env, 13);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        other_claim.clone(),
+        other_policy.policy_id.clone(),
+        asset_id,
+        &holder,
+    ));
+
+    assert_eq!(client.get_policy_claims(&policy.policy_id), claim_ids);
+    assert_eq!(
+        client.get_policy_claims(&other_policy.policy_id),
+        vec![&env, other_claim]
+    );
+}
+
+#[test]
+fn test_dispute_rejected_claim_resolved_in_favor() {
+    let env = create_env();
+    let (admin, holder, insurer, other) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    mint_test_tokens(&env, &token, &insurer, 5000);
+    client.fund_claim_pool(&token, &insurer, &5000i128);
+
+    let claim_id = generate_asset_id(&env, 10);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id.clone(),
+        &holder,
+    ));
+
+    // Submitted claims cannot be disputed or resolved
+    assert_eq!(
+        client.try_dispute_insurance_claim(&claim_id, &holder),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.start_claim_review(&claim_id, &insurer);
+    client.reject_insurance_claim(
+        &claim_id,
+        &insurer,
+        &String::from_str(&env, "Insufficient evidence"),
+    );
+    assert_eq!(
+        client.try_resolve_dispute(&claim_id, &insurer, &false, &3000i128),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.dispute_insurance_claim(&claim_id, &holder);
+    assert_eq!(
+        client.get_insurance_claim(&claim_id).unwrap().status,
+        ClaimStatus::Disputed
+    );
+    let logs = client.get_asset_audit_logs(&asset_id);
+    assert_eq!(
+        logs.get(logs.len() - 1).unwrap().action,
+        String::from_str(&env, "INSURANCE_CLAIM_DISPUTED")
+    );
+
+    // Only the policy's insurer resolves, within the covered loss
+    assert_eq!(
+        client.try_resolve_dispute(&claim_id, &other, &false, &3000i128),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_resolve_dispute(&claim_id, &insurer, &false, &0i128),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_resolve_dispute(&claim_id, &insurer, &false, &5001i128),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    let status = client.resolve_dispute(&claim_id, &insurer, &false, &3000i128);
+    assert_eq!(status, ClaimStatus::Approved);
+    let claim = client.get_insurance_claim(&claim_id).unwrap();
+    assert_eq!(claim.status, ClaimStatus::Approved);
+    assert_eq!(claim.approved_amount, 3000);
+    assert_eq!(client.global_exposure(), (10000, 2500));
+
+    let logs = client.get_asset_audit_logs(&asset_id);
+    assert_eq!(
+        logs.get(logs.len() - 1).unwrap().action,
+        String::from_str(&env, "INSURANCE_CLAIM_DISPUTE_RESOLVED")
+    );
+
+    let mut timeline = Vec::new(&env);
+    for (_, status, _) in client.get_claim_timeline(&claim_id).iter() {
+        timeline.push_back(status);
+    }
+    assert_eq!(
+        timeline,
+        vec![
+            &env,
+            ClaimStatus::Submitted,
+            ClaimStatus::UnderReview,
+            ClaimStatus::Rejected,
+            ClaimStatus::Disputed,
+            ClaimStatus::Approved
+        ]
+    );
+
+    client.pay_insurance_claim(&claim_id, &insurer);
+    assert_eq!(TokenClient::new(&env, &token).balance(&holder), 2500);
+}
+
+#[test]
+fn test_dispute_upheld_restores_original_decision() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+
+    let file = |seed: u32| {
+        let claim_id = generate_asset_id(&env, seed);
+        client.file_insurance_claim(&create_test_claim(
+            &env,
+            claim_id.clone(),
+            policy.policy_id.clone(),
+            asset_id.clone(),
+            &holder,
+        ));
+        client.start_claim_review(&claim_id, &insurer);
+        claim_id
+    };
+
+    // Upheld rejection stays rejected
+    let rejected = file(10);
+    client.reject_insurance_claim(&rejected, &insurer, &String::from_str(&env, "Excluded"));
+    client.dispute_insurance_claim(&rejected, &holder);
+    assert_eq!(
+        client.resolve_dispute(&rejected, &insurer, &true, &0i128),
+        ClaimStatus::Rejected
+    );
+
+    // A disputed approval leaves exposure until resolved, and upholding keeps its amount
+    let approved = file(11);
+    client.approve_insurance_claim(&approved, &insurer, &2000i128);
+    assert_eq!(client.global_exposure(), (10000, 1500));
+    client.dispute_insurance_claim(&approved, &holder);
+    assert_eq!(client.global_exposure(), (10000, 0));
+    assert_eq!(
+        client.resolve_dispute(&approved, &insurer, &true, &0i128),
+        ClaimStatus::Approved
+    );
+    assert_eq!(
+        client
+            .get_insurance_claim(&approved)
+            .unwrap()
+            .approved_amount,
+        2000
+    );
+    assert_eq!(client.global_exposure(), (10000, 1500));
+}
+
+#[test]
+fn test_premium_lapse_blocks_claims() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let mut policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    policy.premium_interval = 100;
+    policy.premium_grace_secs = 50;
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    mint_test_tokens(&env, &token, &holder, 200);
+
+    // On-time payment moves the due date and is audited
+    env.ledger().with_mut(|li| li.timestamp = 1_090);
+    client.pay_premium(&policy.policy_id, &holder, &100i128);
+    assert_eq!(
+        client
+            .get_insurance_policy(&policy.policy_id)
+            .unwrap()
+            .last_payment,
+        1_090
+    );
+    let logs = client.get_asset_audit_logs(&asset_id);
+    assert_eq!(
+        logs.get(logs.len() - 1).unwrap().action,
+        String::from_str(&env, "INSURANCE_PREMIUM_PAID")
+    );
+
+    // Due at 1_190; lapsed once past the interval plus the given grace
+    env.ledger().with_mut(|li| li.timestamp = 1_240);
+    assert!(!client.is_policy_lapsed(&policy.policy_id, &50u64));
+    assert!(client.is_policy_lapsed(&policy.policy_id, &10u64));
+    env.ledger().with_mut(|li| li.timestamp = 1_241);
+    assert!(client.is_policy_lapsed(&policy.policy_id, &50u64));
+    assert!(!client.is_policy_lapsed(&generate_asset_id(&env, 2), &0u64));
+
+    // Claims are refused while lapsed, even before the policy is suspended
+    let claim = create_test_claim(
+        &env,
+        generate_asset_id(&env, 10),
+        policy.policy_id.clone(),
+        asset_id,
+        &holder,
+    );
+    assert_eq!(
+        client.try_file_insurance_claim(&claim),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client
+            .get_insurance_policy(&policy.policy_id)
+            .unwrap()
+            .status,
+        PolicyStatus::Active
+    );
+
+    // Catching up on the premium restores cover
+    client.pay_premium(&policy.policy_id, &holder, &100i128);
+    assert!(!client.is_policy_lapsed(&policy.policy_id, &50u64));
+    client.file_insurance_claim(&claim);
+}
+
+/// Assert the last invocation published an `("insurance", name)` event
+fn assert_insurance_event(env: &Env, name: &str) {
+    let topics: Vec<Val> = ("insurance", name).into_val(env);
+    assert!(
+        env.events().all().iter().any(|(_, t, _)| t == topics),
+        "missing insurance event {}",
+        name
+    );
+}
+
+#[test]
+fn test_insurance_lifecycle_emits_events() {
+    let env = create_env();
+    let (admin, holder, insurer, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    assert_insurance_event(&env, "policy_created");
+    let (_, _, data) = env.events().all().last().unwrap();
+    let (policy_id, _, event_holder, event_insurer): (BytesN<32>, BytesN<32>, Address, Address) =
+        data.into_val(&env);
+    assert_eq!(policy_id, policy.policy_id);
+    assert_eq!(event_holder, holder);
+    assert_eq!(event_insurer, insurer);
+
+    mint_test_tokens(&env, &token, &insurer, 5000);
+    client.fund_claim_pool(&token, &insurer, &5000i128);
+
+    let claim_id = generate_asset_id(&env, 10);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id.clone(),
+        &holder,
+    ));
+    assert_insurance_event(&env, "claim_filed");
+    client.start_claim_review(&claim_id, &insurer);
+    client.approve_insurance_claim(&claim_id, &insurer, &1500i128);
+    assert_insurance_event(&env, "claim_approved");
+    client.pay_insurance_claim(&claim_id, &insurer);
+    assert_insurance_event(&env, "claim_paid");
+
+    client.suspend_insurance_policy(&policy.policy_id, &insurer);
+    assert_insurance_event(&env, "policy_suspended");
+
+    env.ledger()
+        .with_mut(|li| li.timestamp = policy.end_date + 1);
+    client.expire_insurance_policy(&policy.policy_id);
+    assert_insurance_event(&env, "policy_expired");
+
+    let new_end_date = policy.end_date + 31536000;
+    client.renew_insurance_policy(&policy.policy_id, &new_end_date, &120i128, &insurer);
+    assert_insurance_event(&env, "policy_renewed");
+
+    client.cancel_insurance_policy(&policy.policy_id, &holder);
+    assert_insurance_event(&env, "policy_cancelled");
+}
+
+#[test]
+fn test_co_insured_claim_payout_splits_by_share() {
+    let env = create_env();
+    let (admin, holder, insurer, co_insurer) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let mut policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+    policy.co_insurers = vec![
+        &env,
+        (insurer.clone(), 6000u32),
+        (co_insurer.clone(), 4000u32),
+    ];
+    let token = policy.premium_token.clone();
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    mint_test_tokens(&env, &token, &insurer, 5000);
+    client.fund_claim_pool(&token, &insurer, &5000i128);
+
+    let claim_id = generate_asset_id(&env, 10);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id.clone(),
+        &holder,
+    ));
+    client.start_claim_review(&claim_id, &insurer);
+    // Pays 1000 after the 500 deductible
+    client.approve_insurance_claim(&claim_id, &insurer, &1500i128);
+    client.pay_insurance_claim(&claim_id, &insurer);
+
+    assert_eq!(TokenClient::new(&env, &token).balance(&holder), 1000);
+    assert_eq!(
+        client.get_claim_insurer_shares(&claim_id),
+        vec![
+            &env,
+            (insurer.clone(), 600i128),
+            (co_insurer.clone(), 400i128)
+        ]
+    );
+    assert_eq!(client.insurer_summary(&insurer).2, 600);
+    assert_eq!(client.insurer_summary(&co_insurer).2, 400);
+}
+
+#[test]
+fn test_co_insurer_shares_must_total_full_policy() {
+    let env = create_env();
+    let (admin, holder, insurer, co_insurer) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let mut policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        generate_asset_id(&env, 100),
+    );
+
+    policy.co_insurers = vec![
+        &env,
+        (insurer.clone(), 6000u32),
+        (co_insurer.clone(), 3000u32),
+    ];
+    assert_eq!(
+        client.try_create_insurance_policy(&policy),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    policy.co_insurers = vec![
+        &env,
+        (insurer.clone(), 10000u32),
+        (co_insurer.clone(), 0u32),
+    ];
+    assert_eq!(
+        client.try_create_insurance_policy(&policy),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    policy.co_insurers = vec![
+        &env,
+        (insurer.clone(), 6000u32),
+        (co_insurer.clone(), 4000u32),
+    ];
+    client.create_insurance_policy(&policy);
+}
+
+#[test]
+fn test_claim_evidence_added_until_review_closes() {
+    let env = create_env();
+    let (admin, holder, insurer, other) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+
+    let claim_id = generate_asset_id(&env, 10);
+    let mut claim = create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id.clone(),
+        &holder,
+    );
+    let photos = BytesN::from_array(&env, &[1u8; 32]);
+    claim.evidence_hash = photos.clone();
+    client.file_insurance_claim(&claim);
+
+    assert_eq!(
+        client.get_insurance_claim(&claim_id).unwrap().evidence_hash,
+        photos
+    );
+    assert_eq!(
+        client.get_claim_evidence(&claim_id),
+        vec![&env, photos.clone()]
+    );
+
+    // A second document while the claim is under review
+    client.start_claim_review(&claim_id, &insurer);
+    let repair_quote = BytesN::from_array(&env, &[2u8; 32]);
+    assert_eq!(
+        client.try_add_claim_evidence(&claim_id, &repair_quote, &other),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.add_claim_evidence(&claim_id, &repair_quote, &holder);
+    assert_eq!(
+        client.get_claim_evidence(&claim_id),
+        vec![&env, photos.clone(), repair_quote.clone()]
+    );
+
+    // Evidence is closed once the insurer has decided
+    client.approve_insurance_claim(&claim_id, &insurer, &1500i128);
+    assert_eq!(
+        client.try_add_claim_evidence(&claim_id, &BytesN::from_array(&env, &[3u8; 32]), &holder),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_claim_review_lifecycle() {
+    let env = create_env();
+    let (admin, holder, insurer, other) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+
+    let file = |seed: u32| {
+        let claim_id = generate_asset_id(&env, seed);
+        client.file_insurance_claim(&create_test_claim(
+            &env,
+            claim_id.clone(),
+            policy.policy_id.clone(),
+            asset_id.clone(),
+            &holder,
+        ));
+        claim_id
+    };
+
+    // Unreviewed claims can be neither approved nor rejected
+    let approved = file(10);
+    assert_eq!(
+        client.try_approve_insurance_claim(&approved, &insurer, &1500i128),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+    assert_eq!(
+        client.try_reject_insurance_claim(&approved, &insurer, &String::from_str(&env, "No")),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+
+    // Only the policy's insurer reviews, and only once
+    assert_eq!(
+        client.try_start_claim_review(&approved, &other),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.start_claim_review(&approved, &insurer);
+    assert_eq!(
+        client.get_insurance_claim(&approved).unwrap().status,
+        ClaimStatus::UnderReview
+    );
+    assert_eq!(
+        client.try_start_claim_review(&approved, &insurer),
+        Err(Ok(Error::InvalidStatusTransition))
+    );
+    client.approve_insurance_claim(&approved, &insurer, &1500i128);
+    assert_eq!(
+        client.get_insurance_claim(&approved).unwrap().status,
+        ClaimStatus::Approved
+    );
+
+    let rejected = file(11);
+    client.start_claim_review(&rejected, &insurer);
+    client.reject_insurance_claim(&rejected, &insurer, &String::from_str(&env, "Excluded"));
+    assert_eq!(
+        client.get_insurance_claim(&rejected).unwrap().status,
+        ClaimStatus::Rejected
+    );
+}
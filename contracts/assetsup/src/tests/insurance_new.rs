@@ -3,7 +3,7 @@
 extern crate std;
 
 use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::{Address, BytesN, Env};
+use soroban_sdk::{Address, BytesN, Env, Vec};
 
 use crate::insurance::{self, InsurancePolicy, PolicyStatus, PolicyType};
 use crate::AssetUpContract;
@@ -30,6 +30,15 @@ fn create_test_policy(
         status: PolicyStatus::Active,
         auto_renew: false,
         last_payment: current_time,
+        excluded_claim_types: Vec::new(env),
+        premium_token: env
+            .register_stellar_asset_contract_v2(Address::generate(env))
+            .address(),
+        premium_interval: 0,
+        claim_waiting_period_secs: 0,
+        premium_grace_secs: 0,
+        coinsurance_bps: 0,
+        co_insurers: Vec::new(env),
     }
 }
 
@@ -44,16 +44,31 @@ fn test_full_tokenization_workflow() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
         .unwrap();
 
         assert_eq!(tokenized.total_supply, 1000_i128);
 
         // Step 2: Transfer tokens to other holders
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder2.clone(), 400)
-            .unwrap();
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder3.clone(), 200)
-            .unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder2.clone(),
+            400,
+            None,
+        )
+        .unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder3.clone(),
+            200,
+            None,
+        )
+        .unwrap();
 
         // Verify balances
         let tokenizer_balance =
@@ -89,8 +104,8 @@ fn test_full_tokenization_workflow() {
         transfer_restrictions::set_transfer_restriction(&env, asset_id, restriction).unwrap();
 
         // Step 5: Enable dividends and distribute
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
-        dividends::distribute_dividends(&env, asset_id, 1000).unwrap();
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
+        dividends::distribute_dividends(&env, asset_id, 1000, tokenizer.clone()).unwrap();
 
         // Verify dividend distribution
         let tokenizer_div =
@@ -114,8 +129,8 @@ fn test_full_tokenization_workflow() {
         let proposal_id = detokenization::propose_detokenization(&env, asset_id, proposer).unwrap();
 
         // Step 8: Vote on detokenization
-        voting::cast_vote(&env, asset_id, proposal_id, tokenizer.clone()).unwrap();
-        voting::cast_vote(&env, asset_id, proposal_id, holder2.clone()).unwrap();
+        voting::cast_vote(&env, asset_id, proposal_id, tokenizer.clone(), true).unwrap();
+        voting::cast_vote(&env, asset_id, proposal_id, holder2.clone(), true).unwrap();
 
         // Step 9: Check vote tally
         let tally = voting::get_vote_tally(&env, asset_id, proposal_id).unwrap();
@@ -160,20 +175,28 @@ fn test_multiple_dividend_distributions() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
         .unwrap();
 
         // Transfer 500 to holder2
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder2.clone(), 500)
-            .unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder2.clone(),
+            500,
+            None,
+        )
+        .unwrap();
 
         // Enable dividends
-        dividends::enable_revenue_sharing(&env, asset_id).unwrap();
+        dividends::set_revenue_sharing(&env, asset_id, true, tokenizer.clone()).unwrap();
 
         // First distribution
-        dividends::distribute_dividends(&env, asset_id, 500).unwrap();
+        dividends::distribute_dividends(&env, asset_id, 500, tokenizer.clone()).unwrap();
         // Second distribution
-        dividends::distribute_dividends(&env, asset_id, 500).unwrap();
+        dividends::distribute_dividends(&env, asset_id, 500, tokenizer.clone()).unwrap();
 
         // Should accumulate
         let u1 = dividends::get_unclaimed_dividends(&env, asset_id, tokenizer.clone()).unwrap();
@@ -218,24 +241,38 @@ fn test_locked_tokens_with_voting() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
         .unwrap();
 
         // Transfer to holder2
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder2.clone(), 600)
-            .unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder2.clone(),
+            600,
+            None,
+        )
+        .unwrap();
 
         // Lock holder2's tokens until timestamp 5000 (tokenizer is the caller/authorizer)
         tokenization::lock_tokens(&env, asset_id, holder2.clone(), 5000, tokenizer.clone())
             .unwrap();
 
         // Try to transfer (should fail because locked)
-        let transfer_blocked =
-            tokenization::transfer_tokens(&env, asset_id, holder2.clone(), tokenizer.clone(), 100)
-                .is_err();
+        let transfer_blocked = tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            holder2.clone(),
+            tokenizer.clone(),
+            100,
+            None,
+        )
+        .is_err();
 
         // But can still vote (locked tokens still count for voting)
-        let vote_ok = voting::cast_vote(&env, asset_id, 1, holder2.clone()).is_ok();
+        let vote_ok = voting::cast_vote(&env, asset_id, 1, holder2.clone(), true).is_ok();
 
         (transfer_blocked, vote_ok)
     });
@@ -251,8 +288,14 @@ fn test_locked_tokens_with_voting() {
     env.as_contract(&contract_id, || {
         // Unlock and try transfer again
         tokenization::unlock_tokens(&env, asset_id, holder2.clone()).unwrap();
-        let transfer_result =
-            tokenization::transfer_tokens(&env, asset_id, holder2.clone(), tokenizer.clone(), 100);
+        let transfer_result = tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            holder2.clone(),
+            tokenizer.clone(),
+            100,
+            None,
+        );
         assert!(transfer_result.is_ok());
     });
 }
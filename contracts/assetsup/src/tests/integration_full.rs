@@ -27,11 +27,12 @@ fn test_full_asset_tokenization_workflow() {
         &String::from_str(&env, "Property Token"),
         &String::from_str(&env, "Tokenized real estate"),
         &AssetType::Physical,
+        &None,
     );
 
     // Step 3: Distribute tokens to investors
-    client.transfer_tokens(&asset_id, &owner, &investor1, &400000i128);
-    client.transfer_tokens(&asset_id, &owner, &investor2, &300000i128);
+    client.transfer_tokens(&asset_id, &owner, &investor1, &400000i128, &None);
+    client.transfer_tokens(&asset_id, &owner, &investor2, &300000i128, &None);
 
     // Verify ownership distribution
     assert_eq!(client.get_token_balance(&asset_id, &owner), 300000);
@@ -39,8 +40,8 @@ fn test_full_asset_tokenization_workflow() {
     assert_eq!(client.get_token_balance(&asset_id, &investor2), 300000);
 
     // Step 4: Enable revenue sharing and distribute dividends
-    client.enable_revenue_sharing(&asset_id);
-    client.distribute_dividends(&asset_id, &10000i128);
+    client.set_revenue_sharing(&asset_id, &true, &owner);
+    client.distribute_dividends(&asset_id, &10000i128, &owner);
 
     // Verify dividend distribution
     assert_eq!(client.get_unclaimed_dividends(&asset_id, &owner), 3000);
@@ -73,17 +74,18 @@ fn test_governance_and_detokenization_workflow() {
         &String::from_str(&env, "Governance Token"),
         &String::from_str(&env, "Token with voting"),
         &AssetType::Physical,
+        &None,
     );
 
     // Distribute tokens
-    client.transfer_tokens(&asset_id, &owner, &investor1, &600000i128);
-    client.transfer_tokens(&asset_id, &owner, &investor2, &200000i128);
+    client.transfer_tokens(&asset_id, &owner, &investor1, &600000i128, &None);
+    client.transfer_tokens(&asset_id, &owner, &investor2, &200000i128, &None);
 
     // Propose detokenization
     let proposal_id = client.propose_detokenization(&asset_id, &owner);
 
     // Vote on proposal
-    client.cast_vote(&asset_id, &proposal_id, &investor1);
+    client.cast_vote(&asset_id, &proposal_id, &investor1, &true);
 
     // Check if proposal passed
     assert!(client.proposal_passed(&asset_id, &proposal_id));
@@ -115,6 +117,7 @@ fn test_transfer_restrictions_workflow() {
         &String::from_str(&env, "Restricted Token"),
         &String::from_str(&env, "Token with restrictions"),
         &AssetType::Physical,
+        &None,
     );
 
     // Set transfer restrictions
@@ -124,7 +127,7 @@ fn test_transfer_restrictions_workflow() {
     client.add_to_whitelist(&asset_id, &investor1);
 
     // Transfer to whitelisted address should succeed
-    client.transfer_tokens(&asset_id, &owner, &investor1, &100000i128);
+    client.transfer_tokens(&asset_id, &owner, &investor1, &100000i128, &None);
     assert_eq!(client.get_token_balance(&asset_id, &investor1), 100000);
 
     // Verify whitelist
@@ -175,9 +178,10 @@ fn test_token_locking_workflow() {
         &String::from_str(&env, "Lockable Token"),
         &String::from_str(&env, "Token with locking"),
         &AssetType::Physical,
+        &None,
     );
 
-    client.transfer_tokens(&asset_id, &owner, &investor, &500000i128);
+    client.transfer_tokens(&asset_id, &owner, &investor, &500000i128, &None);
 
     // Lock investor's tokens
     let lock_until = env.ledger().timestamp() + 1000;
@@ -193,7 +197,7 @@ fn test_token_locking_workflow() {
     assert!(!client.is_tokens_locked(&asset_id, &investor));
 
     // Transfer should now succeed
-    client.transfer_tokens(&asset_id, &investor, &owner, &100000i128);
+    client.transfer_tokens(&asset_id, &investor, &owner, &100000i128, &None);
 }
 
 #[test]
@@ -243,8 +247,9 @@ fn test_admin_operations_workflow() {
     client.unpause_contract();
     assert!(!client.is_paused());
 
-    // Update admin
-    client.update_admin(&new_admin);
+    // Hand over admin
+    client.propose_new_admin(&admin, &new_admin);
+    client.accept_admin(&new_admin);
     assert_eq!(client.get_admin(), new_admin);
 
     // Verify old admin is no longer authorized registrar
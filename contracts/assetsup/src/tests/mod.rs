@@ -6,16 +6,19 @@ mod admin;
 mod asset;
 mod audit_trail;
 mod initialization;
+mod roles;
 
 // Tokenization and ownership tests
 mod detokenization;
 mod dividends;
+mod escrow;
 mod tokenization;
 mod transfer_restrictions;
 mod voting;
 
 // Insurance tests
 mod insurance;
+mod insurance_claims;
 
 // Integration tests
 mod integration_full;
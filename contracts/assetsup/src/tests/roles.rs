@@ -0,0 +1,128 @@
+use crate::error::Error;
+use crate::roles::{RoleScope, INSURER, MINTER, OPERATOR, REGISTRAR};
+use crate::tests::helpers::*;
+
+#[test]
+fn test_grant_and_revoke_minter_role() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    tokenize_test_asset(&env, &client, 2, 1000, &user3);
+    let scope = RoleScope::Asset(1);
+
+    // Without the role only the tokenizer can mint
+    assert_eq!(
+        client.try_mint_tokens(&1u64, &100i128, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Only the asset's tokenizer grants its roles
+    assert_eq!(
+        client.try_grant_scoped_role(&MINTER, &scope, &user2, &admin),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.grant_scoped_role(&MINTER, &scope, &user2, &user1);
+    assert!(client.has_scoped_role(&MINTER, &scope, &user2));
+    assert!(!client.has_scoped_role(&MINTER, &RoleScope::Asset(2), &user2));
+
+    client.mint_tokens(&1u64, &100i128, &user2);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 100);
+    assert_eq!(client.get_tokenized_asset(&1u64).total_supply, 1100);
+
+    // The grant does not reach other assets, nor does a global MINTER role
+    assert_eq!(
+        client.try_mint_tokens(&2u64, &100i128, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.grant_role(&MINTER, &user2, &admin);
+    assert_eq!(
+        client.try_mint_tokens(&2u64, &100i128, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.revoke_scoped_role(&MINTER, &scope, &user2, &user1);
+    assert!(!client.has_scoped_role(&MINTER, &scope, &user2));
+    assert_eq!(
+        client.try_mint_tokens(&1u64, &100i128, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_operator_role_passes_tokenizer_checks() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+
+    assert_eq!(
+        client.try_set_exit_only(&1u64, &true, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.grant_scoped_role(&OPERATOR, &RoleScope::Asset(1), &user2, &user1);
+    client.set_exit_only(&1u64, &true, &user2);
+    assert!(client.is_exit_only(&1u64));
+}
+
+#[test]
+fn test_insurer_role_acts_on_insurer_claims() {
+    let env = create_env();
+    let (admin, holder, insurer, agent) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &holder,
+        &insurer,
+        asset_id.clone(),
+    );
+
+    env.mock_all_auths();
+    client.create_insurance_policy(&policy);
+    let claim_id = generate_asset_id(&env, 10);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id,
+        &holder,
+    ));
+
+    assert_eq!(
+        client.try_start_claim_review(&claim_id, &agent),
+        Err(Ok(Error::Unauthorized))
+    );
+    // Only the insurer can appoint agents for its own book
+    let scope = RoleScope::Insurer(insurer.clone());
+    assert_eq!(
+        client.try_grant_scoped_role(&INSURER, &scope, &agent, &admin),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.grant_scoped_role(&INSURER, &scope, &agent, &insurer);
+    client.start_claim_review(&claim_id, &agent);
+    client.approve_insurance_claim(&claim_id, &agent, &1000i128);
+}
+
+#[test]
+fn test_registrar_role_authorizes_registration() {
+    let env = create_env();
+    let (admin, user1, _, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    assert!(!client.is_authorized_registrar(&user1));
+
+    client.grant_role(&REGISTRAR, &user1, &admin);
+    assert!(client.is_authorized_registrar(&user1));
+
+    let asset = create_test_asset(&env, &user1, generate_asset_id(&env, 1));
+    client.register_asset(&asset, &user1);
+    assert_eq!(client.get_asset(&asset.id).owner, user1);
+}
@@ -1,6 +1,10 @@
+use crate::error::Error;
 use crate::tests::helpers::*;
-use crate::types::AssetType;
-use soroban_sdk::String;
+use crate::tokenization::{asset_id_to_bytes, MAX_VALUATION_HISTORY};
+use crate::types::{AssetType, OwnershipRecord, RegistryRecord, TokenDataKey};
+use crate::AssetUpContractClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env, String, Vec};
 
 #[test]
 fn test_tokenize_asset_success() {
@@ -20,6 +24,7 @@ fn test_tokenize_asset_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     assert_eq!(result.asset_id, 1);
@@ -47,6 +52,7 @@ fn test_tokenize_asset_already_tokenized() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Try to tokenize again - should panic with AssetAlreadyTokenized
@@ -60,11 +66,12 @@ fn test_tokenize_asset_already_tokenized() {
         &String::from_str(&env, "Test Token 2"),
         &String::from_str(&env, "Another test"),
         &AssetType::Physical,
+        &None,
     );
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #12)")]
+#[should_panic(expected = "Error(Contract, #51)")]
 fn test_tokenize_asset_invalid_supply() {
     let env = create_env();
     let (admin, user1, _, _) = create_mock_addresses(&env);
@@ -72,7 +79,7 @@ fn test_tokenize_asset_invalid_supply() {
 
     env.mock_all_auths();
 
-    // Should panic with InvalidTokenSupply error
+    // Should panic with InvalidAmount error
     client.tokenize_asset(
         &1u64,
         &String::from_str(&env, "TST"),
@@ -83,6 +90,7 @@ fn test_tokenize_asset_invalid_supply() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 }
 
@@ -104,6 +112,7 @@ fn test_mint_tokens_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     let result = client.mint_tokens(&1u64, &500000i128, &user1);
@@ -131,6 +140,7 @@ fn test_mint_tokens_unauthorized() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // user2 is not tokenizer - should panic with Unauthorized
@@ -150,6 +160,42 @@ fn test_mint_tokens_not_tokenized() {
     client.mint_tokens(&999u64, &500000i128, &user1);
 }
 
+#[test]
+fn test_mint_rate_limit_window() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1_000_000, &user1);
+
+    assert_eq!(
+        client.try_set_mint_rate_limit(&1u64, &1_000i128, &3_600u64, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_mint_rate_limit(&1u64, &1_000i128, &3_600u64, &user1);
+
+    // Up to the cap within one window
+    client.mint_tokens(&1u64, &600i128, &user1);
+    client.mint_tokens(&1u64, &400i128, &user1);
+
+    // One more token would exceed it
+    assert_eq!(
+        client.try_mint_tokens(&1u64, &1i128, &user1),
+        Err(Ok(Error::LimitExceeded))
+    );
+
+    // A new window restores the allowance
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + 3_600);
+    let result = client.mint_tokens(&1u64, &1_000i128, &user1);
+    assert_eq!(result.total_supply, 1_002_000);
+
+    let limit = client.get_mint_rate_limit(&1u64).unwrap();
+    assert_eq!(limit.window_start, 4_600);
+    assert_eq!(limit.minted_in_window, 1_000);
+}
+
 #[test]
 fn test_burn_tokens_success() {
     let env = create_env();
@@ -168,6 +214,7 @@ fn test_burn_tokens_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     let result = client.burn_tokens(&1u64, &200000i128, &user1);
@@ -195,6 +242,7 @@ fn test_burn_tokens_insufficient_balance() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Should panic with InsufficientBalance error
@@ -219,9 +267,10 @@ fn test_transfer_tokens_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
-    client.transfer_tokens(&1u64, &user1, &user2, &300000i128);
+    client.transfer_tokens(&1u64, &user1, &user2, &300000i128, &None);
 
     // Verify balances
     let balance1 = client.get_token_balance(&1u64, &user1);
@@ -250,10 +299,11 @@ fn test_transfer_tokens_insufficient_balance() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Should panic with InsufficientBalance error
-    client.transfer_tokens(&1u64, &user1, &user2, &2000000i128);
+    client.transfer_tokens(&1u64, &user1, &user2, &2000000i128, &None);
 }
 
 #[test]
@@ -275,6 +325,7 @@ fn test_transfer_tokens_locked() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Lock tokens
@@ -282,7 +333,7 @@ fn test_transfer_tokens_locked() {
     client.lock_tokens(&1u64, &user1, &future_time, &user1);
 
     // Should panic with TokensAreLocked error
-    client.transfer_tokens(&1u64, &user1, &user2, &100000i128);
+    client.transfer_tokens(&1u64, &user1, &user2, &100000i128, &None);
 }
 
 #[test]
@@ -303,6 +354,7 @@ fn test_lock_unlock_tokens() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Initially not locked
@@ -320,6 +372,31 @@ fn test_lock_unlock_tokens() {
     assert!(!client.is_tokens_locked(&1u64, &user1));
 }
 
+#[test]
+fn test_lock_remaining_secs() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1_000_000, &user1);
+
+    // No lock
+    assert_eq!(client.lock_remaining_secs(&1u64, &user2), 0);
+
+    // Active lock
+    client.lock_tokens(&1u64, &user1, &1_500u64, &user1);
+    assert_eq!(client.lock_remaining_secs(&1u64, &user1), 500);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_400);
+    assert_eq!(client.lock_remaining_secs(&1u64, &user1), 100);
+
+    // Expired lock
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert_eq!(client.lock_remaining_secs(&1u64, &user1), 0);
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #8)")]
 fn test_lock_tokens_unauthorized() {
@@ -339,6 +416,7 @@ fn test_lock_tokens_unauthorized() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     let future_time = env.ledger().timestamp() + 1000;
@@ -365,10 +443,11 @@ fn test_get_ownership_percentage() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Transfer 30% to user2
-    client.transfer_tokens(&1u64, &user1, &user2, &300000i128);
+    client.transfer_tokens(&1u64, &user1, &user2, &300000i128, &None);
 
     // Check ownership percentages (in basis points)
     let percentage1 = client.get_ownership_percentage(&1u64, &user1);
@@ -396,6 +475,7 @@ fn test_get_token_holders() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Initially only user1
@@ -403,8 +483,8 @@ fn test_get_token_holders() {
     assert_eq!(holders.len(), 1);
 
     // Transfer to user2 and user3
-    client.transfer_tokens(&1u64, &user1, &user2, &300000i128);
-    client.transfer_tokens(&1u64, &user1, &user3, &200000i128);
+    client.transfer_tokens(&1u64, &user1, &user2, &300000i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user3, &200000i128, &None);
 
     // Now should have 3 holders
     let holders = client.get_token_holders(&1u64);
@@ -429,6 +509,7 @@ fn test_update_valuation() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     client.update_valuation(&1u64, &2000000i128);
@@ -456,8 +537,1425 @@ fn test_update_valuation_invalid() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Should panic with InvalidValuation error
     client.update_valuation(&1u64, &0i128);
 }
+
+#[test]
+fn test_recompute_percentages_in_batches() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(&env, "TST"),
+        &1000000i128,
+        &6u32,
+        &100i128,
+        &user1,
+        &String::from_str(&env, "Test Token"),
+        &String::from_str(&env, "A test tokenized asset"),
+        &AssetType::Physical,
+        &None,
+    );
+    client.transfer_tokens(&1u64, &user1, &user2, &250000i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user3, &150000i128, &None);
+
+    // Corrupt every stored percentage
+    let holders = client.get_token_holders(&1u64);
+    env.as_contract(&client.address, || {
+        let store = env.storage().persistent();
+        for holder in holders.iter() {
+            let key = TokenDataKey::TokenHolder(1u64, holder);
+            let mut record: OwnershipRecord = store.get(&key).unwrap();
+            record.ownership_percentage = 1;
+            store.set(&key, &record);
+        }
+    });
+
+    // Repair in two batches
+    assert_eq!(client.recompute_percentages(&1u64, &0u32, &2u32), 2);
+    assert_eq!(client.recompute_percentages(&1u64, &2u32, &2u32), 1);
+    assert_eq!(client.recompute_percentages(&1u64, &3u32, &2u32), 0);
+
+    env.as_contract(&client.address, || {
+        let store = env.storage().persistent();
+        let pct = |holder: &Address| {
+            let record: OwnershipRecord = store
+                .get(&TokenDataKey::TokenHolder(1u64, holder.clone()))
+                .unwrap();
+            record.ownership_percentage
+        };
+        assert_eq!(pct(&user1), 6000);
+        assert_eq!(pct(&user2), 2500);
+        assert_eq!(pct(&user3), 1500);
+    });
+}
+
+#[test]
+fn test_token_amounts_must_be_positive() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1_000_000, &user1);
+
+    for amount in [0i128, -1i128] {
+        assert_eq!(
+            client.try_mint_tokens(&1u64, &amount, &user1),
+            Err(Ok(Error::InvalidAmount))
+        );
+        assert_eq!(
+            client.try_burn_tokens(&1u64, &amount, &user1),
+            Err(Ok(Error::InvalidAmount))
+        );
+        assert_eq!(
+            client.try_transfer_tokens(&1u64, &user1, &user2, &amount, &None),
+            Err(Ok(Error::InvalidAmount))
+        );
+        assert_eq!(
+            client.try_set_mint_rate_limit(&1u64, &amount, &3_600u64, &user1),
+            Err(Ok(Error::InvalidAmount))
+        );
+    }
+}
+
+#[test]
+fn test_get_asset_roles() {
+    let env = create_env();
+    let (admin, user1, appraiser, authority) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1_000_000, &user1);
+
+    // Only the tokenizer is set initially
+    let roles = client.get_asset_roles(&1u64);
+    assert_eq!(roles.tokenizer, user1);
+    assert_eq!(roles.appraiser, None);
+    assert_eq!(roles.lock_authority, None);
+    assert!(roles.treasury.is_empty());
+
+    assert_eq!(
+        client.try_set_appraiser(&1u64, &Some(appraiser.clone()), &appraiser),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.set_appraiser(&1u64, &Some(appraiser.clone()), &user1);
+    client.set_lock_authority(&1u64, &Some(authority.clone()), &user1);
+    let mut treasury = Vec::new(&env);
+    treasury.push_back(admin.clone());
+    client.set_treasury_addresses(&1u64, &treasury, &user1);
+
+    let roles = client.get_asset_roles(&1u64);
+    assert_eq!(roles.tokenizer, user1);
+    assert_eq!(roles.appraiser, Some(appraiser));
+    assert_eq!(roles.lock_authority, Some(authority.clone()));
+    assert_eq!(roles.treasury, treasury);
+
+    // The lock authority may lock holders' tokens
+    let until = env.ledger().timestamp() + 1000;
+    client.lock_tokens(&1u64, &user1, &until, &authority);
+    assert!(client.is_tokens_locked(&1u64, &user1));
+
+    // Clearing a role removes it
+    client.set_lock_authority(&1u64, &None, &user1);
+    assert_eq!(client.get_asset_roles(&1u64).lock_authority, None);
+    assert_eq!(
+        client.try_lock_tokens(&1u64, &user1, &until, &authority),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_exit_only_mode() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1_000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &400i128, &None);
+
+    let mut treasury = Vec::new(&env);
+    treasury.push_back(user1.clone());
+    client.set_treasury_addresses(&1u64, &treasury, &user1);
+
+    assert_eq!(
+        client.try_set_exit_only(&1u64, &true, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_exit_only(&1u64, &true, &user1);
+    assert!(client.is_exit_only(&1u64));
+
+    // No new holders
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user2, &user3, &100i128, &None),
+        Err(Ok(Error::ExitOnlyMode))
+    );
+    // No increasing an existing non-treasury holder
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user1, &user2, &100i128, &None),
+        Err(Ok(Error::ExitOnlyMode))
+    );
+
+    // Exiting to the treasury is allowed
+    client.transfer_tokens(&1u64, &user2, &user1, &400i128, &None);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 0);
+    assert_eq!(client.get_token_balance(&1u64, &user1), 1_000);
+
+    // Leaving exit-only mode restores normal transfers
+    client.set_exit_only(&1u64, &false, &user1);
+    client.transfer_tokens(&1u64, &user1, &user3, &100i128, &None);
+    assert_eq!(client.get_token_balance(&1u64, &user3), 100);
+}
+
+fn stored_percentage(env: &Env, client: &AssetUpContractClient, holder: &Address) -> i128 {
+    env.as_contract(&client.address, || {
+        let record: OwnershipRecord = env
+            .storage()
+            .persistent()
+            .get(&TokenDataKey::TokenHolder(1u64, holder.clone()))
+            .unwrap();
+        record.ownership_percentage
+    })
+}
+
+fn percentage_sum(env: &Env, client: &AssetUpContractClient) -> i128 {
+    let mut sum = 0;
+    for holder in client.get_token_holders(&1u64).iter() {
+        sum += stored_percentage(env, client, &holder);
+    }
+    sum
+}
+
+#[test]
+fn test_percentages_sum_to_10000_after_transfers() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 999, &user1);
+
+    // Thirds of 999 leave remainders that naive division would drop
+    client.transfer_tokens(&1u64, &user1, &user2, &333i128, &None);
+    assert_eq!(percentage_sum(&env, &client), 10000);
+    client.transfer_tokens(&1u64, &user1, &user3, &333i128, &None);
+    assert_eq!(percentage_sum(&env, &client), 10000);
+    client.transfer_tokens(&1u64, &user3, &user2, &7i128, &None);
+    assert_eq!(percentage_sum(&env, &client), 10000);
+
+    // 333 / 340 / 326 of 999: the leftover point goes to the largest remainder
+    assert_eq!(stored_percentage(&env, &client, &user1), 3333);
+    assert_eq!(stored_percentage(&env, &client, &user2), 3404);
+    assert_eq!(stored_percentage(&env, &client, &user3), 3263);
+}
+
+fn tokenize_with_symbol(
+    env: &Env,
+    client: &AssetUpContractClient,
+    asset_id: u64,
+    symbol: &str,
+    tokenizer: &Address,
+) -> Result<(), Error> {
+    client
+        .try_tokenize_asset(
+            &asset_id,
+            &String::from_str(env, symbol),
+            &1000i128,
+            &6u32,
+            &100i128,
+            tokenizer,
+            &String::from_str(env, "Test Token"),
+            &String::from_str(env, "A test tokenized asset"),
+            &AssetType::Physical,
+            &None,
+        )
+        .map(|_| ())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_duplicate_symbol_allowed_when_not_enforced() {
+    let env = create_env();
+    let (admin, user1, _, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    assert!(!client.is_enforce_unique_symbols());
+    assert_eq!(
+        tokenize_with_symbol(&env, &client, 1, "DUP", &user1),
+        Ok(())
+    );
+    assert_eq!(
+        tokenize_with_symbol(&env, &client, 2, "DUP", &user1),
+        Ok(())
+    );
+
+    // The index keeps the first holder of the symbol
+    assert_eq!(
+        client.get_symbol_asset(&String::from_str(&env, "DUP")),
+        Some(1)
+    );
+}
+
+#[test]
+fn test_enforced_symbol_rejected_until_detokenized() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    client.set_enforce_unique_symbols(&true);
+    assert_eq!(
+        tokenize_with_symbol(&env, &client, 1, "UNQ", &user1),
+        Ok(())
+    );
+    assert_eq!(
+        tokenize_with_symbol(&env, &client, 2, "UNQ", &user2),
+        Err(Error::SymbolTaken)
+    );
+    assert_eq!(
+        tokenize_with_symbol(&env, &client, 2, "OTH", &user2),
+        Ok(())
+    );
+
+    // Detokenizing asset 1 frees its symbol
+    client.transfer_tokens(&1u64, &user1, &user2, &600i128, &None);
+    let proposal_id = client.propose_detokenization(&1u64, &user1);
+    client.cast_vote(&1u64, &proposal_id, &user2, &true);
+    client.execute_detokenization(&1u64, &proposal_id);
+    assert_eq!(
+        client.get_symbol_asset(&String::from_str(&env, "UNQ")),
+        None
+    );
+
+    assert_eq!(
+        tokenize_with_symbol(&env, &client, 3, "UNQ", &user2),
+        Ok(())
+    );
+    assert_eq!(
+        client.get_symbol_asset(&String::from_str(&env, "UNQ")),
+        Some(3)
+    );
+}
+
+#[test]
+fn test_total_value_locked_tracks_valuations_and_pools() {
+    let env = create_env();
+    let (admin, user1, user2, insurer) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    assert_eq!(client.total_value_locked(), 0);
+
+    // Tokenization starts valuation at total supply
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    tokenize_test_asset(&env, &client, 2, 500, &user2);
+    assert_eq!(client.total_value_locked(), 1500);
+
+    client.update_valuation(&1u64, &4000i128);
+    assert_eq!(client.total_value_locked(), 4500);
+
+    // Pool funding and premiums add, payouts drain
+    let asset_id = generate_asset_id(&env, 100);
+    let policy = create_test_policy(
+        &env,
+        generate_asset_id(&env, 1),
+        &user1,
+        &insurer,
+        asset_id.clone(),
+    );
+    let token = policy.premium_token.clone();
+    client.create_insurance_policy(&policy);
+    mint_test_tokens(&env, &token, &insurer, 3000);
+    client.fund_claim_pool(&token, &insurer, &3000i128);
+    assert_eq!(client.total_value_locked(), 7500);
+
+    let claim_id = generate_asset_id(&env, 10);
+    client.file_insurance_claim(&create_test_claim(
+        &env,
+        claim_id.clone(),
+        policy.policy_id.clone(),
+        asset_id,
+        &user1,
+    ));
+    client.start_claim_review(&claim_id, &insurer);
+    // 1500 approved pays 1000 after the 500 deductible
+    client.approve_insurance_claim(&claim_id, &insurer, &1500i128);
+    client.pay_insurance_claim(&claim_id, &insurer);
+    assert_eq!(client.total_value_locked(), 6500);
+
+    // Detokenization removes the asset's valuation
+    let proposal_id = client.propose_detokenization(&2u64, &user2);
+    client.cast_vote(&2u64, &proposal_id, &user2, &true);
+    client.execute_detokenization(&2u64, &proposal_id);
+    assert_eq!(client.total_value_locked(), 6000);
+}
+
+#[test]
+fn test_export_asset_state_bundles_configuration() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let registry_id = asset_id_to_bytes(&env, 1u64);
+    client.register_asset(&create_test_asset(&env, &user1, registry_id), &admin);
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+
+    client.add_to_whitelist(&1u64, &user2);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+    client.lock_tokens(&1u64, &user2, &5_000u64, &user1);
+    client.set_appraiser(&1u64, &Some(user3.clone()), &user1);
+    client.set_treasury_addresses(&1u64, &Vec::from_array(&env, [user1.clone()]), &user1);
+    client.set_transfer_restriction(&1u64, &true);
+    client.set_mint_rate_limit(&1u64, &500i128, &3600u64, &user1);
+    client.set_min_voting_balance(&1u64, &10i128, &user1);
+    client.set_vesting_schedule(&1u64, &user2, &100i128, &1_000u64, &0u64, &1_000u64, &user1);
+    client.self_lock_tokens(&1u64, &user2, &50i128, &4_000u64);
+    client.burn_tokens(&1u64, &40i128, &user1);
+    let burn_address = Address::generate(&env);
+    client.set_burn_address(&1u64, &burn_address, &user1);
+    client.set_reflection_tax(&1u64, &100u32, &user1);
+    client.pause_minting(&1u64, &user1);
+    client.freeze_holder(&1u64, &user2, &user1);
+    client.add_to_blacklist(&1u64, &user3);
+    client.set_exit_only(&1u64, &true, &user1);
+
+    let export = client.export_asset_state(&1u64);
+    match export.asset {
+        RegistryRecord::Registered(asset) => assert_eq!(asset.owner, user1),
+        RegistryRecord::Unregistered => panic!("registry record missing"),
+    }
+    assert_eq!(export.tokenized_asset, client.get_tokenized_asset(&1u64));
+    assert_eq!(export.metadata.name, String::from_str(&env, "Test Token"));
+
+    assert_eq!(export.holder_count, 2);
+    let tokenizer = export.holders.get(0).unwrap();
+    assert_eq!(tokenizer.record.owner, user1);
+    assert_eq!(tokenizer.record.balance, 660);
+    assert_eq!(tokenizer.locked_until, None);
+    assert!(!tokenizer.frozen);
+    assert_eq!(tokenizer.vesting.total_amount, 0);
+    assert_eq!(tokenizer.self_lock.amount, 0);
+    let holder = export.holders.get(1).unwrap();
+    assert_eq!(holder.record.owner, user2);
+    assert_eq!(holder.record.balance, 300);
+    assert_eq!(holder.locked_until, Some(5_000));
+    assert!(holder.frozen);
+    assert_eq!(holder.vesting.total_amount, 100);
+    assert_eq!(holder.self_lock.amount, 50);
+
+    assert_eq!(export.roles, client.get_asset_roles(&1u64));
+    assert_eq!(export.roles.appraiser, Some(user3.clone()));
+    assert_eq!(export.whitelist, Vec::from_array(&env, [user2.clone()]));
+    assert!(export.transfer_restriction.require_accredited);
+    assert!(export.exit_only);
+    assert_eq!(export.mint_rate_limit.max_per_window, 500);
+    assert_eq!(export.min_voting_balance, 10);
+    assert_eq!(export.blacklist, Vec::from_array(&env, [user3]));
+    assert_eq!(export.burn_addresses, Vec::from_array(&env, [burn_address]));
+    assert_eq!(export.burned_total, 40);
+    assert_eq!(export.reflection_tax_bps, 100);
+    assert_eq!(export.reflection_pool, 0);
+    assert!(export.minting_paused);
+
+    // Holders can also be paged individually
+    let page = client.export_asset_holders(&1u64, &1u32, &10u32);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().record.owner, user2);
+}
+
+#[test]
+fn test_reflection_tax_accrued_to_other_holders() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user3, &200i128, &None);
+    client.transfer_tokens(&1u64, &user1, &admin, &200i128, &None);
+
+    let result = client.try_set_reflection_tax(&1u64, &1000u32, &user2);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    let result = client.try_set_reflection_tax(&1u64, &10001u32, &user1);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+    client.set_reflection_tax(&1u64, &1000u32, &user1);
+    assert_eq!(client.get_reflection_tax(&1u64), 1000);
+
+    // 10% of 100 is accrued 300:200 to the two uninvolved holders as dividends
+    client.transfer_tokens(&1u64, &user2, &user3, &100i128, &None);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user1), 6);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &admin), 4);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user2), 0);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user3), 0);
+    assert_eq!(client.get_token_balance(&1u64, &user1), 300);
+    assert_eq!(client.get_token_balance(&1u64, &admin), 200);
+
+    // Sender pays the full amount; the tax is withheld from the recipient into
+    // the reflection pool and supply is unchanged
+    assert_eq!(client.get_token_balance(&1u64, &user2), 200);
+    assert_eq!(client.get_token_balance(&1u64, &user3), 290);
+    assert_eq!(client.get_reflection_pool(&1u64), 10);
+    assert_eq!(client.get_tokenized_asset(&1u64).total_supply, 1000);
+    assert_eq!(client.get_burned_total(&1u64), 0);
+    assert!(client.verify_supply_invariant(&1u64));
+
+    // Frozen holders accrue nothing; the remaining eligible holder takes it all
+    client.freeze_holder(&1u64, &admin, &user1);
+    client.transfer_tokens(&1u64, &user2, &user3, &100i128, &None);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &user1), 16);
+    assert_eq!(client.get_unclaimed_dividends(&1u64, &admin), 4);
+    assert_eq!(client.get_reflection_pool(&1u64), 20);
+    assert!(client.verify_supply_invariant(&1u64));
+}
+
+#[test]
+fn test_valuation_at_historical_timestamps() {
+    let env = create_env();
+    let (admin, user1, _, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+
+    for (timestamp, valuation) in [(100u64, 5000i128), (200, 7000), (300, 6000)] {
+        env.ledger().with_mut(|li| li.timestamp = timestamp);
+        client.update_valuation(&1u64, &valuation);
+    }
+
+    // Before tokenization, then the initial valuation until the first update
+    assert_eq!(client.valuation_at(&1u64, &49u64), 0);
+    assert_eq!(client.valuation_at(&1u64, &50u64), 1000);
+    assert_eq!(client.valuation_at(&1u64, &99u64), 1000);
+    // Exactly at records
+    assert_eq!(client.valuation_at(&1u64, &100u64), 5000);
+    assert_eq!(client.valuation_at(&1u64, &300u64), 6000);
+    // Between records
+    assert_eq!(client.valuation_at(&1u64, &250u64), 7000);
+    assert_eq!(client.valuation_at(&1u64, &10_000u64), 6000);
+}
+
+#[test]
+fn test_valuation_history_records_updates_in_order() {
+    let env = create_env();
+    let (admin, user1, _, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    assert_eq!(
+        client.get_valuation_history(&1u64),
+        vec![&env, (0u64, 1000i128)]
+    );
+
+    for (timestamp, valuation) in [(100u64, 5000i128), (200, 7000), (300, 6000)] {
+        env.ledger().with_mut(|li| li.timestamp = timestamp);
+        client.update_valuation(&1u64, &valuation);
+    }
+
+    assert_eq!(
+        client.get_valuation_history(&1u64),
+        vec![
+            &env,
+            (0u64, 1000i128),
+            (100, 5000),
+            (200, 7000),
+            (300, 6000)
+        ]
+    );
+}
+
+#[test]
+fn test_valuation_history_keeps_most_recent_entries() {
+    let env = create_env();
+    let (admin, user1, _, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+
+    for i in 1..=55u64 {
+        env.ledger().with_mut(|li| li.timestamp = i * 10);
+        client.update_valuation(&1u64, &(i as i128 * 100));
+    }
+
+    let history = client.get_valuation_history(&1u64);
+    assert_eq!(history.len(), MAX_VALUATION_HISTORY);
+    assert_eq!(history.first().unwrap(), (60, 600));
+    assert_eq!(history.last().unwrap(), (550, 5500));
+}
+
+#[test]
+fn test_burn_to_address_keeps_supply_and_blocks_transfers() {
+    let env = create_env();
+    let (admin, user1, user2, burn_address) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &100i128, &None);
+
+    // A funded holder cannot be turned into a burn address
+    let result = client.try_set_burn_address(&1u64, &user2, &user1);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.burn_tokens(&1u64, &50i128, &user1);
+    client.set_burn_address(&1u64, &burn_address, &user1);
+    assert_eq!(client.get_burn_address(&1u64), Some(burn_address.clone()));
+
+    client.burn_tokens(&1u64, &200i128, &user1);
+    client.burn_tokens(&1u64, &100i128, &user1);
+
+    let asset = client.get_tokenized_asset(&1u64);
+    assert_eq!(asset.total_supply, 950);
+    assert_eq!(asset.tokens_in_circulation, 650);
+    assert_eq!(client.get_token_balance(&1u64, &burn_address), 300);
+    assert_eq!(client.get_token_balance(&1u64, &user1), 550);
+    assert_eq!(client.get_burned_total(&1u64), 350);
+
+    // Burned tokens can never leave the burn address
+    let result = client.try_transfer_tokens(&1u64, &burn_address, &user2, &10i128, &None);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_live_ownership_percentage_ignores_stored_value() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &250i128, &None);
+
+    // Corrupt the persisted percentage
+    env.as_contract(&client.address, || {
+        let key = TokenDataKey::TokenHolder(1u64, user2.clone());
+        let mut record: OwnershipRecord = env.storage().persistent().get(&key).unwrap();
+        record.ownership_percentage = 9999;
+        env.storage().persistent().set(&key, &record);
+    });
+    assert_eq!(stored_percentage(&env, &client, &user2), 9999);
+
+    assert_eq!(client.live_ownership_percentage(&1u64, &user2), 2500);
+    assert_eq!(client.live_ownership_percentage(&1u64, &user1), 7500);
+    assert_eq!(client.live_ownership_percentage(&1u64, &user3), 0);
+}
+
+#[test]
+fn test_pause_minting_leaves_transfers_and_burns() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+
+    let result = client.try_pause_minting(&1u64, &user2);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.pause_minting(&1u64, &user1);
+    assert!(client.is_minting_paused(&1u64));
+
+    let result = client.try_mint_tokens(&1u64, &100i128, &user1);
+    assert_eq!(result, Err(Ok(Error::MintingPaused)));
+    client.transfer_tokens(&1u64, &user1, &user2, &100i128, &None);
+    client.burn_tokens(&1u64, &50i128, &user1);
+    assert_eq!(client.get_tokenized_asset(&1u64).total_supply, 950);
+
+    client.resume_minting(&1u64, &user1);
+    assert!(!client.is_minting_paused(&1u64));
+    client.mint_tokens(&1u64, &100i128, &user1);
+    assert_eq!(client.get_tokenized_asset(&1u64).total_supply, 1050);
+}
+
+#[test]
+fn test_self_lock_tokens() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &500i128, &None);
+
+    client.self_lock_tokens(&1u64, &user2, &300i128, &2_000u64);
+    assert_eq!(client.locked_balance(&1u64, &user2), 300);
+
+    // The unlocked portion moves freely, the committed portion does not
+    client.transfer_tokens(&1u64, &user2, &user1, &200i128, &None);
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user2, &user1, &1i128, &None),
+        Err(Ok(Error::TokensAreLocked))
+    );
+
+    // Neither unlocking nor a weaker self-lock lifts the commitment
+    client.unlock_tokens(&1u64, &user2);
+    assert_eq!(
+        client.try_self_lock_tokens(&1u64, &user2, &100i128, &2_000u64),
+        Err(Ok(Error::TokensAreLocked))
+    );
+    assert_eq!(
+        client.try_self_lock_tokens(&1u64, &user2, &300i128, &1_500u64),
+        Err(Ok(Error::TokensAreLocked))
+    );
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user2, &user1, &1i128, &None),
+        Err(Ok(Error::TokensAreLocked))
+    );
+
+    // The lock lapses at expiry
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert_eq!(client.get_self_lock(&1u64, &user2), None);
+    assert_eq!(client.locked_balance(&1u64, &user2), 0);
+    client.transfer_tokens(&1u64, &user2, &user1, &300i128, &None);
+}
+
+#[test]
+fn test_self_lock_tokens_rejects_invalid_lock() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, _, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+
+    assert_eq!(
+        client.try_self_lock_tokens(&1u64, &user1, &1001i128, &2_000u64),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_self_lock_tokens(&1u64, &user1, &100i128, &1_000u64),
+        Err(Ok(Error::InvalidTimestamps))
+    );
+}
+
+#[test]
+fn test_mint_and_burn_refresh_every_holder_percentage() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &250i128, &None);
+
+    let pct = |holder: &Address| {
+        env.as_contract(&client.address, || {
+            let record: OwnershipRecord = env
+                .storage()
+                .persistent()
+                .get(&TokenDataKey::TokenHolder(1u64, holder.clone()))
+                .unwrap();
+            record.ownership_percentage
+        })
+    };
+    assert_eq!(pct(&user2), 2500);
+
+    // Doubling the supply halves the other holder's share
+    client.mint_tokens(&1u64, &1000i128, &user1);
+    assert_eq!(pct(&user2), 1250);
+    assert_eq!(pct(&user1), 8750);
+
+    client.burn_tokens(&1u64, &1500i128, &user1);
+    assert_eq!(pct(&user2), 5000);
+    assert_eq!(pct(&user1), 5000);
+}
+
+#[test]
+fn test_transfer_from_spends_allowance() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+
+    client.approve(&1u64, &user1, &user2, &300i128);
+    assert_eq!(client.get_allowance(&1u64, &user1, &user2), 300);
+
+    // Partial, then exact spend of the remaining allowance
+    client.transfer_from(&1u64, &user2, &user1, &user3, &100i128);
+    assert_eq!(client.get_allowance(&1u64, &user1, &user2), 200);
+    client.transfer_from(&1u64, &user2, &user1, &user3, &200i128);
+    assert_eq!(client.get_allowance(&1u64, &user1, &user2), 0);
+    assert_eq!(client.get_token_balance(&1u64, &user3), 300);
+    assert_eq!(client.get_token_balance(&1u64, &user1), 700);
+
+    assert_eq!(
+        client.try_transfer_from(&1u64, &user2, &user1, &user3, &1i128),
+        Err(Ok(Error::InsufficientAllowance))
+    );
+}
+
+#[test]
+fn test_approve_overwrites_allowance() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+
+    client.approve(&1u64, &user1, &user2, &500i128);
+    client.approve(&1u64, &user1, &user2, &50i128);
+    assert_eq!(client.get_allowance(&1u64, &user1, &user2), 50);
+
+    assert_eq!(
+        client.try_transfer_from(&1u64, &user2, &user1, &user3, &100i128),
+        Err(Ok(Error::InsufficientAllowance))
+    );
+    client.transfer_from(&1u64, &user2, &user1, &user3, &50i128);
+    assert_eq!(client.get_token_balance(&1u64, &user3), 50);
+}
+
+#[test]
+fn test_batch_transfer_tokens() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+
+    client.batch_transfer_tokens(
+        &1u64,
+        &user1,
+        &vec![&env, (user2.clone(), 100i128), (user3.clone(), 250i128)],
+    );
+    assert_eq!(client.get_token_balance(&1u64, &user1), 650);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 100);
+    assert_eq!(client.get_token_balance(&1u64, &user3), 250);
+
+    // The total must fit the sender's balance
+    assert_eq!(
+        client.try_batch_transfer_tokens(
+            &1u64,
+            &user1,
+            &vec![&env, (user2.clone(), 600i128), (user3.clone(), 100i128)],
+        ),
+        Err(Ok(Error::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_batch_transfer_tokens_mid_list_failure_rolls_back() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.add_to_whitelist(&1u64, &user1);
+    client.add_to_whitelist(&1u64, &user2);
+
+    // user3 is not whitelisted
+    assert_eq!(
+        client.try_batch_transfer_tokens(
+            &1u64,
+            &user1,
+            &vec![
+                &env,
+                (user2.clone(), 100i128),
+                (user3.clone(), 100i128),
+                (user2.clone(), 100i128),
+            ],
+        ),
+        Err(Ok(Error::TransferRestrictionFailed))
+    );
+    assert_eq!(
+        client.try_batch_transfer_tokens(
+            &1u64,
+            &user1,
+            &vec![&env, (user2.clone(), 100i128), (user2.clone(), 0i128)],
+        ),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    assert_eq!(client.get_token_balance(&1u64, &user1), 1000);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 0);
+    assert_eq!(client.get_token_balance(&1u64, &user3), 0);
+}
+
+#[test]
+fn test_drained_holders_leave_holders_list() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user3, &200i128, &None);
+    assert_eq!(client.get_tokenized_asset(&1u64).token_holders_count, 3);
+
+    // Full drain removes the holder and their empty record
+    client.transfer_tokens(&1u64, &user2, &user3, &300i128, &None);
+    assert_eq!(
+        client.get_token_holders(&1u64),
+        Vec::from_array(&env, [user1.clone(), user3.clone()])
+    );
+    assert_eq!(client.get_tokenized_asset(&1u64).token_holders_count, 2);
+    env.as_contract(&client.address, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&TokenDataKey::TokenHolder(1u64, user2.clone())));
+    });
+
+    // Re-acquiring puts them back
+    client.transfer_tokens(&1u64, &user3, &user2, &50i128, &None);
+    assert_eq!(client.get_tokenized_asset(&1u64).token_holders_count, 3);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 50);
+
+    // Burning the tokenizer's whole balance drops them too, and minting restores them
+    client.burn_tokens(&1u64, &500i128, &user1);
+    assert_eq!(client.get_tokenized_asset(&1u64).token_holders_count, 2);
+    assert!(!client.get_token_holders(&1u64).contains(&user1));
+    client.mint_tokens(&1u64, &100i128, &user1);
+    assert_eq!(client.get_tokenized_asset(&1u64).token_holders_count, 3);
+    assert_eq!(client.get_token_balance(&1u64, &user1), 100);
+}
+
+#[test]
+fn test_drained_holder_keeps_record_with_unclaimed_dividends() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.set_revenue_sharing(&1u64, &true, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &500i128, &None);
+    client.distribute_dividends(&1u64, &100i128, &user1);
+
+    client.transfer_tokens(&1u64, &user2, &user1, &500i128, &None);
+    assert!(!client.get_token_holders(&1u64).contains(&user2));
+    assert_eq!(client.get_tokenized_asset(&1u64).token_holders_count, 1);
+    assert_eq!(client.claim_dividends(&1u64, &user2), 50);
+}
+
+#[test]
+fn test_get_token_holders_paged() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &100i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user3, &100i128, &None);
+    client.transfer_tokens(&1u64, &user1, &admin, &100i128, &None);
+
+    assert_eq!(
+        client.get_token_holders_paged(&1u64, &0u32, &2u32),
+        vec![&env, user1.clone(), user2.clone()]
+    );
+    assert_eq!(
+        client.get_token_holders_paged(&1u64, &1u32, &2u32),
+        vec![&env, user2.clone(), user3.clone()]
+    );
+
+    // Limit past the end returns what remains
+    assert_eq!(
+        client.get_token_holders_paged(&1u64, &2u32, &10u32),
+        vec![&env, user3.clone(), admin.clone()]
+    );
+    assert_eq!(client.get_token_holders_paged(&1u64, &4u32, &2u32).len(), 0);
+    assert_eq!(
+        client
+            .get_token_holders_paged(&1u64, &u32::MAX, &2u32)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_balance_snapshot_survives_transfers() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &400i128, &None);
+
+    let snapshot = client.take_balance_snapshot(&1u64);
+    assert_eq!(snapshot, 1);
+
+    client.transfer_tokens(&1u64, &user2, &user3, &150i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user2, &100i128, &None);
+
+    assert_eq!(client.get_snapshot_balance(&1u64, &snapshot, &user1), 600);
+    assert_eq!(client.get_snapshot_balance(&1u64, &snapshot, &user2), 400);
+    assert_eq!(client.get_snapshot_balance(&1u64, &snapshot, &user3), 0);
+
+    // A later snapshot sees the new balances
+    let later = client.take_balance_snapshot(&1u64);
+    assert_eq!(later, 2);
+    assert_eq!(client.get_snapshot_balance(&1u64, &later, &user2), 350);
+    assert_eq!(client.get_snapshot_balance(&1u64, &later, &user3), 150);
+    assert_eq!(client.get_snapshot_balance(&1u64, &3u64, &user2), 0);
+}
+
+#[test]
+fn test_mint_respects_max_supply() {
+    let env = create_env();
+    let (admin, user1, _, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    client.tokenize_asset(
+        &1u64,
+        &String::from_str(&env, "CAP"),
+        &1000i128,
+        &6u32,
+        &100i128,
+        &user1,
+        &String::from_str(&env, "Capped Token"),
+        &String::from_str(&env, "A fixed-supply asset"),
+        &AssetType::Physical,
+        &Some(1500i128),
+    );
+    assert_eq!(client.get_remaining_mintable(&1u64), Some(500));
+
+    // Minting up to the cap is allowed, one more is not
+    client.mint_tokens(&1u64, &500i128, &user1);
+    assert_eq!(client.get_remaining_mintable(&1u64), Some(0));
+    assert_eq!(
+        client.try_mint_tokens(&1u64, &1i128, &user1),
+        Err(Ok(Error::LimitExceeded))
+    );
+
+    // Burning frees room under the cap
+    client.burn_tokens(&1u64, &100i128, &user1);
+    assert_eq!(client.get_remaining_mintable(&1u64), Some(100));
+}
+
+#[test]
+fn test_uncapped_supply_and_invalid_cap() {
+    let env = create_env();
+    let (admin, user1, _, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    assert_eq!(client.get_remaining_mintable(&1u64), None);
+    client.mint_tokens(&1u64, &1_000_000i128, &user1);
+    assert_eq!(client.get_tokenized_asset(&1u64).total_supply, 1_001_000);
+
+    let result = client.try_tokenize_asset(
+        &2u64,
+        &String::from_str(&env, "LOW"),
+        &1000i128,
+        &6u32,
+        &100i128,
+        &user1,
+        &String::from_str(&env, "Test Token"),
+        &String::from_str(&env, "A test tokenized asset"),
+        &AssetType::Physical,
+        &Some(999i128),
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+}
+
+#[test]
+fn test_transfer_tracks_average_purchase_price() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 10_000, &user1);
+
+    let avg_price = |holder: &Address| {
+        env.as_contract(&client.address, || {
+            let record: OwnershipRecord = env
+                .storage()
+                .persistent()
+                .get(&TokenDataKey::TokenHolder(1u64, holder.clone()))
+                .unwrap();
+            record.average_purchase_price
+        })
+    };
+
+    // The tokenizer starts at the valuation per token
+    assert_eq!(avg_price(&user1), 1);
+
+    // 100 at 10 then 300 at 30 averages to 25
+    client.transfer_tokens(&1u64, &user1, &user2, &100i128, &Some(10i128));
+    assert_eq!(avg_price(&user2), 10);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &Some(30i128));
+    assert_eq!(avg_price(&user2), 25);
+
+    // Unpriced transfers leave the cost basis alone
+    client.transfer_tokens(&1u64, &user1, &user2, &100i128, &None);
+    assert_eq!(avg_price(&user2), 25);
+
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user1, &user2, &100i128, &Some(0i128)),
+        Err(Ok(Error::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_tokenize_registered_asset_uses_purchase_value() {
+    let env = create_env();
+    let (admin, user1, _, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    let asset = create_test_asset(&env, &user1, asset_id_to_bytes(&env, 1u64));
+    client.register_asset(&asset, &admin);
+    tokenize_test_asset(&env, &client, 1, 100, &user1);
+
+    // A purchase value of 1000 over 100 tokens prices each at 10
+    assert_eq!(client.get_tokenized_asset(&1u64).valuation, 1000);
+    let record: OwnershipRecord = env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get(&TokenDataKey::TokenHolder(1u64, user1.clone()))
+            .unwrap()
+    });
+    assert_eq!(record.average_purchase_price, 10);
+}
+
+#[test]
+fn test_vesting_schedule_releases_linearly() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 10_000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &1_200i128, &None);
+
+    // 1000 tokens vest over 1000s from t=1000 with a 250s cliff
+    assert_eq!(
+        client.try_set_vesting_schedule(
+            &1u64, &user2, &1_000i128, &1_000u64, &250u64, &1_000u64, &user2
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_vesting_schedule(
+        &1u64, &user2, &1_000i128, &1_000u64, &250u64, &1_000u64, &user1,
+    );
+
+    // Pre-cliff: only the unscheduled 200 can move
+    env.ledger().with_mut(|li| li.timestamp = 1_249);
+    assert_eq!(client.vested_amount(&1u64, &user2), 0);
+    assert_eq!(client.locked_balance(&1u64, &user2), 1_000);
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user2, &user3, &201i128, &None),
+        Err(Ok(Error::TokensAreLocked))
+    );
+    client.transfer_tokens(&1u64, &user2, &user3, &200i128, &None);
+
+    // Mid-vest: 40% released
+    env.ledger().with_mut(|li| li.timestamp = 1_400);
+    assert_eq!(client.vested_amount(&1u64, &user2), 400);
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user2, &user3, &401i128, &None),
+        Err(Ok(Error::TokensAreLocked))
+    );
+    client.transfer_tokens(&1u64, &user2, &user3, &400i128, &None);
+
+    // Fully vested
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert_eq!(client.vested_amount(&1u64, &user2), 1_000);
+    assert_eq!(client.locked_balance(&1u64, &user2), 0);
+    client.transfer_tokens(&1u64, &user2, &user3, &600i128, &None);
+    assert_eq!(client.get_token_balance(&1u64, &user3), 1_200);
+}
+
+#[test]
+fn test_near_max_supply_overflows_cleanly() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, i128::MAX - 10, &user1);
+
+    // New supply past i128::MAX
+    assert_eq!(
+        client.try_mint_tokens(&1u64, &11i128, &user1),
+        Err(Ok(Error::ArithmeticOverflow))
+    );
+
+    // Percentage math on a near-max balance
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user1, &user2, &1i128, &None),
+        Err(Ok(Error::ArithmeticOverflow))
+    );
+    assert_eq!(
+        client.try_get_ownership_percentage(&1u64, &user1),
+        Err(Ok(Error::ArithmeticOverflow))
+    );
+    assert_eq!(client.get_token_balance(&1u64, &user1), i128::MAX - 10);
+
+    // Linear vesting of a near-max amount
+    client.set_vesting_schedule(
+        &1u64,
+        &user1,
+        &(i128::MAX - 10),
+        &0u64,
+        &0u64,
+        &1_000u64,
+        &user1,
+    );
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    assert_eq!(
+        client.try_vested_amount(&1u64, &user1),
+        Err(Ok(Error::ArithmeticOverflow))
+    );
+}
+
+#[test]
+fn test_max_holders_blocks_new_holders_at_cap() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &100i128, &None);
+
+    assert_eq!(
+        client.try_set_max_holders(&1u64, &Some(2u32), &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_max_holders(&1u64, &Some(1u32), &user1),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.set_max_holders(&1u64, &Some(2u32), &user1);
+    assert_eq!(client.get_tokenized_asset(&1u64).max_holders, Some(2));
+
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user1, &user3, &100i128, &None),
+        Err(Ok(Error::LimitExceeded))
+    );
+
+    // Topping up an existing holder is always allowed
+    client.transfer_tokens(&1u64, &user1, &user2, &100i128, &None);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 200);
+
+    // A holder who exits frees a slot for a new one
+    client.transfer_tokens(&1u64, &user2, &user3, &200i128, &None);
+    assert_eq!(client.get_token_balance(&1u64, &user3), 200);
+    assert_eq!(client.get_tokenized_asset(&1u64).token_holders_count, 2);
+}
+
+#[test]
+fn test_max_holders_unlimited_by_default() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    assert_eq!(client.get_tokenized_asset(&1u64).max_holders, None);
+
+    client.set_max_holders(&1u64, &Some(1u32), &user1);
+    client.set_max_holders(&1u64, &None, &user1);
+
+    client.transfer_tokens(&1u64, &user1, &user2, &100i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user3, &100i128, &None);
+    assert_eq!(client.get_tokenized_asset(&1u64).token_holders_count, 3);
+}
+
+#[test]
+fn test_transfer_limit_caps_volume_per_window() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &500i128, &None);
+
+    assert_eq!(
+        client.try_set_transfer_limit(&1u64, &user2, &100i128, &3_600u64, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_transfer_limit(&1u64, &user2, &100i128, &3_600u64, &user1);
+
+    // Within the limit
+    client.transfer_tokens(&1u64, &user2, &user3, &60i128, &None);
+    assert_eq!(
+        client
+            .get_transfer_limit(&1u64, &user2)
+            .unwrap()
+            .transferred_in_window,
+        60
+    );
+
+    // Exceeding the remaining allowance fails
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user2, &user3, &41i128, &None),
+        Err(Ok(Error::LimitExceeded))
+    );
+    client.transfer_tokens(&1u64, &user2, &user3, &40i128, &None);
+
+    // Other holders are unaffected
+    client.transfer_tokens(&1u64, &user1, &user3, &300i128, &None);
+
+    // A new window restores the full allowance
+    env.ledger().with_mut(|li| li.timestamp = 4_600);
+    client.transfer_tokens(&1u64, &user2, &user3, &100i128, &None);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 300);
+}
+
+#[test]
+fn test_force_transfer_claws_back_locked_tokens() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+
+    let future_time = env.ledger().timestamp() + 1000;
+    client.lock_tokens(&1u64, &user2, &future_time, &user1);
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user2, &user3, &100i128, &None),
+        Err(Ok(Error::TokensAreLocked))
+    );
+
+    client.force_transfer(&1u64, &user2, &user3, &200i128, &user1);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 100);
+    assert_eq!(client.get_token_balance(&1u64, &user3), 200);
+
+    let logs = client.get_asset_audit_logs(&asset_id_to_bytes(&env, 1));
+    let last = logs.get(logs.len() - 1).unwrap();
+    assert_eq!(last.action, String::from_str(&env, "FORCE_TRANSFER"));
+    assert_eq!(last.actor, user1);
+
+    // A blacklisted destination is still refused
+    client.add_to_blacklist(&1u64, &admin);
+    assert_eq!(
+        client.try_force_transfer(&1u64, &user2, &admin, &100i128, &user1),
+        Err(Ok(Error::AddressBlacklisted))
+    );
+
+    // So is a frozen one, while a frozen source can still be cleared out
+    client.freeze_holder(&1u64, &user3, &user1);
+    assert_eq!(
+        client.try_force_transfer(&1u64, &user2, &user3, &100i128, &user1),
+        Err(Ok(Error::HolderFrozen))
+    );
+    client.force_transfer(&1u64, &user3, &user2, &200i128, &user1);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 300);
+}
+
+#[test]
+fn test_force_transfer_rejects_non_tokenizer() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+
+    assert_eq!(
+        client.try_force_transfer(&1u64, &user2, &user3, &100i128, &user3),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_force_transfer(&1u64, &user2, &user3, &100i128, &admin),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_token_balance(&1u64, &user2), 300);
+}
+
+#[test]
+fn test_supply_invariant_holds_and_detects_corruption() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    assert!(client.verify_supply_invariant(&1u64));
+
+    client.mint_tokens(&1u64, &500i128, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+    client.burn_tokens(&1u64, &200i128, &user1);
+    assert!(client.verify_supply_invariant(&1u64));
+
+    // Tokens parked at a burn address stay in supply but leave circulation
+    client.set_burn_address(&1u64, &admin, &user1);
+    client.burn_tokens(&1u64, &100i128, &user1);
+    assert!(client.verify_supply_invariant(&1u64));
+
+    // Escrowed tokens are outside every balance until released
+    let release_after = env.ledger().timestamp();
+    let escrow_id = client.escrow_transfer(
+        &1u64,
+        &user2,
+        &50i128,
+        &vec![&env, (user3.clone(), 50i128)],
+        &release_after,
+    );
+    assert!(client.verify_supply_invariant(&1u64));
+    client.claim_escrow(&escrow_id);
+    assert!(client.verify_supply_invariant(&1u64));
+
+    // A holder record edited behind the counters' back breaks the invariant
+    env.as_contract(&client.address, || {
+        let key = TokenDataKey::TokenHolder(1, user2.clone());
+        let mut ownership: OwnershipRecord = env.storage().persistent().get(&key).unwrap();
+        ownership.balance += 1;
+        env.storage().persistent().set(&key, &ownership);
+    });
+    assert!(!client.verify_supply_invariant(&1u64));
+}
+
+#[test]
+fn test_force_transfer_rejects_burn_address_and_dividend_freeze() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+    client.set_burn_address(&1u64, &admin, &user1);
+    client.burn_tokens(&1u64, &500i128, &user1);
+
+    // Burned tokens cannot be forced back into circulation
+    assert_eq!(
+        client.try_force_transfer(&1u64, &admin, &user3, &500i128, &user1),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert!(client.verify_supply_invariant(&1u64));
+
+    // A dividend declaration freeze holds for forced moves too
+    client.set_revenue_sharing(&1u64, &true, &user1);
+    client.declare_dividend(&1u64, &100i128, &3_600u64, &user1);
+    assert_eq!(
+        client.try_force_transfer(&1u64, &user2, &user3, &100i128, &user1),
+        Err(Ok(Error::DistributionInProgress))
+    );
+}
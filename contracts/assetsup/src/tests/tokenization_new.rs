@@ -32,6 +32,7 @@ fn setup_tokenized(env: &Env, asset_id: u64, tokenizer: &Address) {
             accredited_investor_required: false,
             geographic_restrictions: soroban_sdk::Vec::new(env),
         },
+        None,
     )
     .unwrap();
 }
@@ -69,6 +70,7 @@ fn test_tokenize_asset() {
             min_voting_threshold,
             tokenizer.clone(),
             metadata,
+            None,
         )
         .unwrap()
     });
@@ -106,6 +108,7 @@ fn test_tokenize_asset_invalid_supply() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
     });
 
@@ -141,6 +144,7 @@ fn test_mint_tokens() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
         .unwrap();
 
@@ -183,6 +187,7 @@ fn test_burn_tokens() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
         .unwrap();
 
@@ -224,6 +229,7 @@ fn test_transfer_tokens() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
         .unwrap();
 
@@ -233,6 +239,7 @@ fn test_transfer_tokens() {
             tokenizer.clone(),
             recipient.clone(),
             transfer_amount,
+            None,
         )
         .unwrap();
 
@@ -274,6 +281,7 @@ fn test_lock_tokens() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
         .unwrap();
 
@@ -287,6 +295,7 @@ fn test_lock_tokens() {
             tokenizer.clone(),
             recipient.clone(),
             100,
+            None,
         );
         assert!(result.is_err());
     });
@@ -302,6 +311,7 @@ fn test_lock_tokens() {
             tokenizer.clone(),
             recipient.clone(),
             100,
+            None,
         );
         assert!(result.is_ok());
     });
@@ -334,6 +344,7 @@ fn test_ownership_percentage() {
                 accredited_investor_required: false,
                 geographic_restrictions: soroban_sdk::Vec::new(&env),
             },
+            None,
         )
         .unwrap();
 
@@ -403,6 +414,7 @@ fn test_is_tokens_locked_after_expiry() {
             tokenizer.clone(),
             recipient.clone(),
             100,
+            None,
         );
         assert!(result.is_ok());
     });
@@ -447,6 +459,7 @@ fn test_unlock_tokens_clears_lock_regardless_of_timestamp() {
             tokenizer.clone(),
             recipient.clone(),
             100,
+            None,
         );
         assert!(result.is_ok());
     });
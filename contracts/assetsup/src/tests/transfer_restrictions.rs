@@ -1,6 +1,7 @@
+use crate::error::Error;
 use crate::tests::helpers::*;
-use crate::types::AssetType;
-use soroban_sdk::String;
+use crate::types::{AssetType, TransferDecision};
+use soroban_sdk::{vec, String};
 
 #[test]
 fn test_add_to_whitelist() {
@@ -20,6 +21,7 @@ fn test_add_to_whitelist() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Initially not whitelisted
@@ -49,6 +51,7 @@ fn test_remove_from_whitelist() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Add to whitelist
@@ -78,6 +81,7 @@ fn test_get_whitelist() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Add multiple addresses to whitelist
@@ -106,6 +110,7 @@ fn test_add_duplicate_to_whitelist() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Add to whitelist twice
@@ -135,6 +140,7 @@ fn test_set_transfer_restriction() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Set transfer restriction
@@ -161,13 +167,14 @@ fn test_transfer_with_whitelist() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Add user2 to whitelist
     client.add_to_whitelist(&1u64, &user2);
 
     // Transfer should succeed
-    client.transfer_tokens(&1u64, &user1, &user2, &100000i128);
+    client.transfer_tokens(&1u64, &user1, &user2, &100000i128, &None);
 
     let balance = client.get_token_balance(&1u64, &user2);
     assert_eq!(balance, 100000);
@@ -191,6 +198,7 @@ fn test_empty_whitelist() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     let whitelist = client.get_whitelist(&1u64);
@@ -218,13 +226,14 @@ fn test_transfer_to_non_whitelisted_fails() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Only user2 is whitelisted
     client.add_to_whitelist(&2u64, &user2);
 
     // Transfer to user3 (not whitelisted) should panic with TransferRestricted
-    client.transfer_tokens(&2u64, &user1, &user3, &100000i128);
+    client.transfer_tokens(&2u64, &user1, &user3, &100000i128, &None);
 }
 
 #[test]
@@ -245,9 +254,302 @@ fn test_empty_whitelist_allows_transfer() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // No whitelist — transfer should succeed
-    client.transfer_tokens(&3u64, &user1, &user2, &100000i128);
+    client.transfer_tokens(&3u64, &user1, &user2, &100000i128, &None);
     assert_eq!(client.get_token_balance(&3u64, &user2), 100000);
 }
+
+#[test]
+fn test_transfer_restriction_reason_allowed() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1u64, 1000000, &user1);
+
+    let decision = client.transfer_restriction_reason(&1u64, &user1, &user2, &1000i128);
+    assert_eq!(decision, TransferDecision::Allowed);
+}
+
+#[test]
+fn test_transfer_restriction_reason_restricted() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1u64, 1000000, &user1);
+
+    // Whitelist is active and user3 is not on it
+    client.add_to_whitelist(&1u64, &user2);
+
+    let decision = client.transfer_restriction_reason(&1u64, &user1, &user3, &1000i128);
+    assert_eq!(decision, TransferDecision::Restricted);
+
+    let decision = client.transfer_restriction_reason(&1u64, &user1, &user2, &1000i128);
+    assert_eq!(decision, TransferDecision::Allowed);
+}
+
+#[test]
+fn test_transfer_restriction_reason_locked() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1u64, 1000000, &user1);
+
+    let until = env.ledger().timestamp() + 1000;
+    client.lock_tokens(&1u64, &user1, &until, &user1);
+
+    let decision = client.transfer_restriction_reason(&1u64, &user1, &user2, &1000i128);
+    assert_eq!(decision, TransferDecision::Locked);
+}
+
+#[test]
+fn test_transfer_restriction_reason_insufficient_balance() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1u64, 1000000, &user1);
+
+    let decision = client.transfer_restriction_reason(&1u64, &user1, &user2, &2000000i128);
+    assert_eq!(decision, TransferDecision::InsufficientBalance);
+
+    // Dry-run must not have changed anything
+    assert_eq!(client.get_token_balance(&1u64, &user1), 1000000);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 0);
+}
+
+#[test]
+fn test_transfer_restriction_reason_frozen() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1u64, 1000000, &user1);
+
+    client.freeze_holder(&1u64, &user2, &user1);
+    let decision = client.transfer_restriction_reason(&1u64, &user1, &user2, &1000i128);
+    assert_eq!(decision, TransferDecision::Frozen);
+}
+
+#[test]
+fn test_transfer_restriction_reason_paused() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1u64, 1000000, &user1);
+
+    client.pause_contract();
+    let decision = client.transfer_restriction_reason(&1u64, &user1, &user2, &1000i128);
+    assert_eq!(decision, TransferDecision::Paused);
+}
+
+#[test]
+fn test_transfer_restriction_reason_cooldown() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1u64, 1000000, &user1);
+    client.set_revenue_sharing(&1u64, &true, &user1);
+
+    client.declare_dividend(&1u64, &100i128, &3_600u64, &user1);
+    let decision = client.transfer_restriction_reason(&1u64, &user1, &user2, &1000i128);
+    assert_eq!(decision, TransferDecision::Cooldown);
+}
+
+#[test]
+fn test_transfer_restriction_reason_velocity_exceeded() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1u64, 1000000, &user1);
+
+    client.set_transfer_limit(&1u64, &user1, &1500i128, &3_600u64, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &1000i128, &None);
+
+    let decision = client.transfer_restriction_reason(&1u64, &user1, &user2, &1000i128);
+    assert_eq!(decision, TransferDecision::VelocityExceeded);
+    let decision = client.transfer_restriction_reason(&1u64, &user1, &user2, &500i128);
+    assert_eq!(decision, TransferDecision::Allowed);
+}
+
+#[test]
+fn test_transfer_restriction_reason_limit_violation() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1u64, 1000000, &user1);
+
+    client.set_max_holders(&1u64, &Some(2u32), &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &1000i128, &None);
+
+    let decision = client.transfer_restriction_reason(&1u64, &user1, &user3, &1000i128);
+    assert_eq!(decision, TransferDecision::LimitViolation);
+
+    // Draining user2 frees the slot user3 would take
+    let decision = client.transfer_restriction_reason(&1u64, &user2, &user3, &1000i128);
+    assert_eq!(decision, TransferDecision::Allowed);
+}
+
+#[test]
+fn test_whitelist_request_flow() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+
+    client.request_whitelist(&1u64, &user2);
+    client.request_whitelist(&1u64, &user3);
+    // Repeat requests are not duplicated
+    client.request_whitelist(&1u64, &user2);
+    assert_eq!(
+        client.get_pending_whitelist_requests(&1u64),
+        vec![&env, user2.clone(), user3.clone()]
+    );
+
+    // Only the tokenizer can act on requests
+    let result = client.try_approve_whitelist_request(&1u64, &user2, &user3);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.approve_whitelist_request(&1u64, &user2, &user1);
+    client.reject_whitelist_request(&1u64, &user3, &user1);
+
+    assert!(client.is_whitelisted(&1u64, &user2));
+    assert!(!client.is_whitelisted(&1u64, &user3));
+    assert_eq!(client.get_pending_whitelist_requests(&1u64).len(), 0);
+
+    // A request can only be acted on once
+    let result = client.try_reject_whitelist_request(&1u64, &user3, &user1);
+    assert_eq!(result, Err(Ok(Error::WhitelistRequestNotFound)));
+}
+
+#[test]
+fn test_blacklist_blocks_sender_and_recipient() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+
+    client.add_to_blacklist(&1u64, &user2);
+    assert!(client.is_blacklisted(&1u64, &user2));
+    assert!(!client.is_blacklisted(&1u64, &user3));
+
+    // No whitelist is active, yet the blacklisted address is blocked both ways
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user2, &user3, &100i128, &None),
+        Err(Ok(Error::AddressBlacklisted))
+    );
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user1, &user2, &100i128, &None),
+        Err(Ok(Error::AddressBlacklisted))
+    );
+
+    // The blacklist is checked before the whitelist
+    client.add_to_whitelist(&1u64, &user2);
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user1, &user2, &100i128, &None),
+        Err(Ok(Error::AddressBlacklisted))
+    );
+
+    client.remove_from_blacklist(&1u64, &user2);
+    assert!(!client.is_blacklisted(&1u64, &user2));
+    client.transfer_tokens(&1u64, &user1, &user2, &100i128, &None);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 400);
+}
+
+#[test]
+fn test_blacklist_allows_unrelated_transfers() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.add_to_blacklist(&1u64, &user3);
+
+    client.transfer_tokens(&1u64, &user1, &user2, &250i128, &None);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 250);
+    assert_eq!(
+        client.transfer_restriction_reason(&1u64, &user1, &user3, &10i128),
+        TransferDecision::Restricted
+    );
+}
+
+#[test]
+fn test_frozen_holder_cannot_send_or_receive() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+
+    // Only the tokenizer can freeze
+    assert_eq!(
+        client.try_freeze_holder(&1u64, &user2, &user3),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.freeze_holder(&1u64, &user2, &user1);
+    assert!(client.is_holder_frozen(&1u64, &user2));
+
+    // Frozen sender is blocked
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user2, &user3, &100i128, &None),
+        Err(Ok(Error::HolderFrozen))
+    );
+    // Frozen recipient is blocked
+    assert_eq!(
+        client.try_transfer_tokens(&1u64, &user1, &user2, &100i128, &None),
+        Err(Ok(Error::HolderFrozen))
+    );
+    // Other holders are unaffected
+    client.transfer_tokens(&1u64, &user1, &user3, &100i128, &None);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 300);
+}
+
+#[test]
+fn test_unfreeze_holder_restores_transfers() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+    client.freeze_holder(&1u64, &user2, &user1);
+
+    assert_eq!(
+        client.try_unfreeze_holder(&1u64, &user2, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.unfreeze_holder(&1u64, &user2, &user1);
+    assert!(!client.is_holder_frozen(&1u64, &user2));
+
+    client.transfer_tokens(&1u64, &user2, &user3, &100i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user2, &50i128, &None);
+    assert_eq!(client.get_token_balance(&1u64, &user2), 250);
+    assert_eq!(client.get_token_balance(&1u64, &user3), 100);
+}
@@ -1,6 +1,8 @@
+use crate::error::Error;
 use crate::tests::helpers::*;
 use crate::types::AssetType;
-use soroban_sdk::String;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{vec, String};
 
 #[test]
 fn test_cast_vote_success() {
@@ -21,10 +23,11 @@ fn test_cast_vote_success() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Cast vote
-    client.cast_vote(&1u64, &1u64, &user1);
+    client.cast_vote(&1u64, &1u64, &user1, &true);
 
     // Verify vote was recorded
     assert!(client.has_voted(&1u64, &1u64, &user1));
@@ -53,12 +56,13 @@ fn test_cast_vote_already_voted() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
-    client.cast_vote(&1u64, &1u64, &user1);
+    client.cast_vote(&1u64, &1u64, &user1, &true);
 
     // Try to vote again - should panic with AlreadyVoted
-    client.cast_vote(&1u64, &1u64, &user1);
+    client.cast_vote(&1u64, &1u64, &user1, &true);
 }
 
 #[test]
@@ -81,13 +85,14 @@ fn test_cast_vote_insufficient_voting_power() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Transfer small amount to user2
-    client.transfer_tokens(&1u64, &user1, &user2, &10000i128);
+    client.transfer_tokens(&1u64, &user1, &user2, &10000i128, &None);
 
     // user2 doesn't have enough tokens - should panic with InsufficientVotingPower
-    client.cast_vote(&1u64, &1u64, &user2);
+    client.cast_vote(&1u64, &1u64, &user2, &true);
 }
 
 #[test]
@@ -108,13 +113,14 @@ fn test_proposal_passed() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Transfer 60% to user2
-    client.transfer_tokens(&1u64, &user1, &user2, &600000i128);
+    client.transfer_tokens(&1u64, &user1, &user2, &600000i128, &None);
 
     // user2 votes (60% of supply)
-    client.cast_vote(&1u64, &1u64, &user2);
+    client.cast_vote(&1u64, &1u64, &user2, &true);
 
     // Proposal should pass (>50% threshold)
     assert!(client.proposal_passed(&1u64, &1u64));
@@ -138,13 +144,14 @@ fn test_proposal_not_passed() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Transfer 40% to user2
-    client.transfer_tokens(&1u64, &user1, &user2, &400000i128);
+    client.transfer_tokens(&1u64, &user1, &user2, &400000i128, &None);
 
     // user2 votes (40% of supply)
-    client.cast_vote(&1u64, &1u64, &user2);
+    client.cast_vote(&1u64, &1u64, &user2, &true);
 
     // Proposal should not pass (<50% threshold)
     assert!(!client.proposal_passed(&1u64, &1u64));
@@ -168,15 +175,16 @@ fn test_multiple_voters() {
         &String::from_str(&env, "Test Token"),
         &String::from_str(&env, "A test tokenized asset"),
         &AssetType::Physical,
+        &None,
     );
 
     // Distribute tokens
-    client.transfer_tokens(&1u64, &user1, &user2, &300000i128);
-    client.transfer_tokens(&1u64, &user1, &user3, &200000i128);
+    client.transfer_tokens(&1u64, &user1, &user2, &300000i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user3, &200000i128, &None);
 
     // Multiple users vote
-    client.cast_vote(&1u64, &1u64, &user1); // 500000
-    client.cast_vote(&1u64, &1u64, &user2); // 300000
+    client.cast_vote(&1u64, &1u64, &user1, &true); // 500000
+    client.cast_vote(&1u64, &1u64, &user2, &true); // 300000
 
     // Total tally should be 800000
     let tally = client.get_vote_tally(&1u64, &1u64);
@@ -185,3 +193,436 @@ fn test_multiple_voters() {
     // Proposal should pass (80% > 50%)
     assert!(client.proposal_passed(&1u64, &1u64));
 }
+
+#[test]
+fn test_min_voting_balance_filters_dust_holders() {
+    let env = create_env();
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &500i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user3, &5000i128, &None);
+
+    assert_eq!(client.get_min_voting_balance(&1u64), 0);
+    client.set_min_voting_balance(&1u64, &1000i128, &user1);
+    assert_eq!(client.get_min_voting_balance(&1u64), 1000);
+
+    // Dust holder clears the proposer threshold but not the voting minimum
+    let result = client.try_cast_vote(&1u64, &1u64, &user2, &true);
+    assert_eq!(result, Err(Ok(Error::InsufficientVotingPower)));
+    assert!(!client.has_voted(&1u64, &1u64, &user2));
+
+    client.cast_vote(&1u64, &1u64, &user3, &true);
+    assert_eq!(client.get_vote_tally(&1u64, &1u64), 5000);
+}
+
+#[test]
+fn test_min_voting_balance_tokenizer_only() {
+    let env = create_env();
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000000, &user1);
+
+    let result = client.try_set_min_voting_balance(&1u64, &1000i128, &user2);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    let result = client.try_set_min_voting_balance(&1u64, &-1i128, &user1);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+}
+
+#[test]
+fn test_lock_vote_multiplier_boosts_self_locked_tokens() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &400i128, &None);
+    client.self_lock_tokens(&1u64, &user2, &200i128, &2_000u64);
+
+    // Without a multiplier locked tokens count once
+    assert_eq!(client.get_lock_vote_multiplier(&1u64), 10000);
+    assert_eq!(client.effective_voting_power(&1u64, &user2), 400);
+
+    assert_eq!(
+        client.try_set_lock_vote_multiplier(&1u64, &9_999u32, &user1),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_lock_vote_multiplier(&1u64, &15_000u32, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_lock_vote_multiplier(&1u64, &15_000u32, &user1);
+    assert_eq!(client.effective_voting_power(&1u64, &user2), 500);
+    assert_eq!(client.effective_voting_power(&1u64, &user1), 600);
+
+    client.cast_vote(&1u64, &1u64, &user2, &true);
+    assert_eq!(client.get_vote_tally(&1u64, &1u64), 500);
+
+    // The bonus ends with the lock
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert_eq!(client.effective_voting_power(&1u64, &user2), 400);
+    client.cast_vote(&1u64, &2u64, &user2, &true);
+    assert_eq!(client.get_vote_tally(&1u64, &2u64), 400);
+}
+
+#[test]
+fn test_lock_vote_bonus_excludes_unvested_tokens() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &400i128, &None);
+    client.set_vesting_schedule(
+        &1u64, &user2, &300i128, &1_000u64, &500u64, &1_000u64, &user1,
+    );
+    client.set_lock_vote_multiplier(&1u64, &15_000u32, &user1);
+
+    // Only 100 of the 200 self-locked tokens are vested, so only they earn the bonus
+    client.self_lock_tokens(&1u64, &user2, &200i128, &5_000u64);
+    assert_eq!(client.effective_voting_power(&1u64, &user2), 450);
+
+    // Once everything vests the whole self-lock counts
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert_eq!(client.effective_voting_power(&1u64, &user2), 500);
+}
+
+#[test]
+fn test_governance_proposal_passes_with_quorum() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &400i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user3, &200i128, &None);
+
+    let description = String::from_str(&env, "Replace the property manager");
+    let proposal_id = client.create_proposal(&1u64, &user2, &description, &2_000u64);
+    assert_eq!(proposal_id, 1);
+    assert_eq!(
+        client.create_proposal(&1u64, &user2, &description, &3_000u64),
+        2
+    );
+
+    client.vote_on_proposal(&1u64, &proposal_id, &user1, &true);
+    client.vote_on_proposal(&1u64, &proposal_id, &user2, &true);
+    client.vote_on_proposal(&1u64, &proposal_id, &user3, &false);
+    assert_eq!(
+        client.try_vote_on_proposal(&1u64, &proposal_id, &user3, &true),
+        Err(Ok(Error::AlreadyVoted))
+    );
+
+    assert_eq!(client.tally_proposal(&1u64, &proposal_id), (800, 200));
+    assert!(client.governance_proposal_passed(&1u64, &proposal_id));
+
+    let proposal = client.get_governance_proposal(&1u64, &proposal_id);
+    assert_eq!(proposal.proposer, user2);
+    assert_eq!(proposal.description, description);
+    assert_eq!(proposal.voting_deadline, 2_000);
+}
+
+#[test]
+fn test_governance_proposal_fails_without_quorum() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &400i128, &None);
+
+    let description = String::from_str(&env, "Sell the asset");
+    let proposal_id = client.create_proposal(&1u64, &user2, &description, &2_000u64);
+    client.vote_on_proposal(&1u64, &proposal_id, &user2, &true);
+
+    // 400 of 1000 in circulation is below the default 50% quorum
+    assert_eq!(client.get_proposal_quorum(&1u64), 5000);
+    assert_eq!(client.tally_proposal(&1u64, &proposal_id), (400, 0));
+    assert!(!client.governance_proposal_passed(&1u64, &proposal_id));
+
+    assert_eq!(
+        client.try_set_proposal_quorum(&1u64, &3000u32, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_proposal_quorum(&1u64, &10_001u32, &user1),
+        Err(Ok(Error::InvalidAmount))
+    );
+    client.set_proposal_quorum(&1u64, &3000u32, &user1);
+    assert!(client.governance_proposal_passed(&1u64, &proposal_id));
+}
+
+#[test]
+fn test_governance_vote_after_deadline_rejected() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &50i128, &None);
+
+    let description = String::from_str(&env, "Refinance");
+    // Holders below min_voting_threshold cannot propose
+    assert_eq!(
+        client.try_create_proposal(&1u64, &user2, &description, &2_000u64),
+        Err(Ok(Error::InsufficientVotingPower))
+    );
+    assert_eq!(
+        client.try_create_proposal(&1u64, &user1, &description, &1_000u64),
+        Err(Ok(Error::InvalidTimestamps))
+    );
+    assert_eq!(
+        client.try_vote_on_proposal(&1u64, &1u64, &user1, &true),
+        Err(Ok(Error::ProposalNotFound))
+    );
+
+    let proposal_id = client.create_proposal(&1u64, &user1, &description, &2_000u64);
+    env.ledger().with_mut(|li| li.timestamp = 2_001);
+    assert_eq!(
+        client.try_vote_on_proposal(&1u64, &proposal_id, &user1, &true),
+        Err(Ok(Error::VotingPeriodEnded))
+    );
+    assert_eq!(
+        client.try_vote_on_proposal(&1u64, &proposal_id, &user3, &true),
+        Err(Ok(Error::VotingPeriodEnded))
+    );
+    assert_eq!(client.tally_proposal(&1u64, &proposal_id), (0, 0));
+}
+
+#[test]
+fn test_vote_receipt_blocks_double_voting() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+
+    let description = String::from_str(&env, "Upgrade the roof");
+    let proposal_id = client.create_proposal(&1u64, &user1, &description, &2_000u64);
+    assert_eq!(client.get_vote_receipt(&1u64, &proposal_id, &user1), None);
+
+    client.vote_on_proposal(&1u64, &proposal_id, &user1, &true);
+    let receipt = client
+        .get_vote_receipt(&1u64, &proposal_id, &user1)
+        .unwrap();
+    assert!(receipt.support);
+    assert_eq!(receipt.weight, 700);
+    assert_eq!(receipt.cast_at, 1_000);
+
+    // Re-casting is rejected on either side
+    assert_eq!(
+        client.try_vote_on_proposal(&1u64, &proposal_id, &user1, &true),
+        Err(Ok(Error::AlreadyVoted))
+    );
+    assert_eq!(
+        client.try_vote_on_proposal(&1u64, &proposal_id, &user1, &false),
+        Err(Ok(Error::AlreadyVoted))
+    );
+
+    // A different voter still gets their own vote
+    client.vote_on_proposal(&1u64, &proposal_id, &user2, &false);
+    assert_eq!(client.tally_proposal(&1u64, &proposal_id), (700, 300));
+}
+
+#[test]
+fn test_change_vote_moves_recorded_weight() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+
+    let description = String::from_str(&env, "Upgrade the roof");
+    let proposal_id = client.create_proposal(&1u64, &user1, &description, &2_000u64);
+    assert_eq!(
+        client.try_change_vote(&1u64, &proposal_id, &user2, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.vote_on_proposal(&1u64, &proposal_id, &user2, &false);
+    assert_eq!(
+        client.try_change_vote(&1u64, &proposal_id, &user2, &false),
+        Err(Ok(Error::AlreadyVoted))
+    );
+
+    // Tokens moved after voting do not change the recorded weight
+    client.transfer_tokens(&1u64, &user2, &user3, &200i128, &None);
+    client.change_vote(&1u64, &proposal_id, &user2, &true);
+    assert_eq!(client.tally_proposal(&1u64, &proposal_id), (300, 0));
+
+    env.ledger().with_mut(|li| li.timestamp = 2_001);
+    assert_eq!(
+        client.try_change_vote(&1u64, &proposal_id, &user2, &false),
+        Err(Ok(Error::VotingPeriodEnded))
+    );
+}
+
+#[test]
+fn test_votes_weighted_by_proposal_snapshot() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, _) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+
+    // Governance: tokens moved after voting cannot be voted again
+    let description = String::from_str(&env, "Upgrade the roof");
+    let proposal_id = client.create_proposal(&1u64, &user1, &description, &2_000u64);
+    client.vote_on_proposal(&1u64, &proposal_id, &user1, &true);
+    client.transfer_tokens(&1u64, &user1, &user2, &600i128, &None);
+    assert_eq!(
+        client.try_vote_on_proposal(&1u64, &proposal_id, &user2, &true),
+        Err(Ok(Error::InsufficientVotingPower))
+    );
+    assert_eq!(client.tally_proposal(&1u64, &proposal_id), (1000, 0));
+
+    // cast_vote: the snapshot is taken when the proposal gets its first vote
+    client.cast_vote(&1u64, &7u64, &user1, &false);
+    assert_eq!(client.get_vote_tally(&1u64, &7u64), 0);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+    client.cast_vote(&1u64, &7u64, &user2, &true);
+    assert_eq!(client.get_vote_tally(&1u64, &7u64), 600);
+    assert_eq!(
+        client.try_cast_vote(&1u64, &7u64, &user1, &true),
+        Err(Ok(Error::AlreadyVoted))
+    );
+}
+
+#[test]
+fn test_change_vote_updates_delegator_receipts() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user3, &200i128, &None);
+    client.delegate_votes(&1u64, &user2, &user3);
+
+    let description = String::from_str(&env, "Hire a new manager");
+    let proposal_id = client.create_proposal(&1u64, &user1, &description, &2_000u64);
+    client.vote_on_proposal(&1u64, &proposal_id, &user3, &false);
+    let receipt = client
+        .get_vote_receipt(&1u64, &proposal_id, &user3)
+        .unwrap();
+    assert_eq!(receipt.weight, 500);
+    assert_eq!(receipt.delegators, vec![&env, user2.clone()]);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_500);
+    client.change_vote(&1u64, &proposal_id, &user3, &true);
+    assert_eq!(client.tally_proposal(&1u64, &proposal_id), (500, 0));
+    let delegated = client
+        .get_vote_receipt(&1u64, &proposal_id, &user2)
+        .unwrap();
+    assert!(delegated.support);
+    assert_eq!(delegated.weight, 0);
+    assert_eq!(delegated.cast_at, 1_500);
+}
+
+#[test]
+fn test_delegated_power_counts_for_delegatee() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user3, &200i128, &None);
+
+    client.delegate_votes(&1u64, &user2, &user3);
+    assert_eq!(client.get_delegate(&1u64, &user2), Some(user3.clone()));
+    assert_eq!(client.total_voting_power(&1u64, &user3), 500);
+
+    // Detokenization votes carry the delegated power
+    client.cast_vote(&1u64, &1u64, &user3, &true);
+    assert_eq!(client.get_vote_tally(&1u64, &1u64), 500);
+    assert!(client.has_voted(&1u64, &1u64, &user2));
+
+    // So do governance votes
+    let description = String::from_str(&env, "Hire a new manager");
+    let proposal_id = client.create_proposal(&1u64, &user1, &description, &2_000u64);
+    client.vote_on_proposal(&1u64, &proposal_id, &user3, &true);
+    assert_eq!(client.tally_proposal(&1u64, &proposal_id), (500, 0));
+
+    // Revoking after the delegatee voted does not let the power be cast twice
+    client.revoke_delegation(&1u64, &user2);
+    assert_eq!(client.get_delegate(&1u64, &user2), None);
+    assert_eq!(client.total_voting_power(&1u64, &user3), 200);
+    assert_eq!(
+        client.try_vote_on_proposal(&1u64, &proposal_id, &user2, &false),
+        Err(Ok(Error::AlreadyVoted))
+    );
+}
+
+#[test]
+fn test_delegator_blocked_from_direct_voting() {
+    let env = create_env();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (admin, user1, user2, user3) = create_mock_addresses(&env);
+    let client = initialize_contract(&env, &admin);
+
+    env.mock_all_auths();
+    tokenize_test_asset(&env, &client, 1, 1000, &user1);
+    client.transfer_tokens(&1u64, &user1, &user2, &300i128, &None);
+    client.transfer_tokens(&1u64, &user1, &user3, &200i128, &None);
+
+    assert_eq!(
+        client.try_delegate_votes(&1u64, &user2, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.delegate_votes(&1u64, &user2, &user3);
+    // No delegation chains, in either direction
+    assert_eq!(
+        client.try_delegate_votes(&1u64, &user1, &user2),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_delegate_votes(&1u64, &user3, &user1),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let description = String::from_str(&env, "Hire a new manager");
+    let proposal_id = client.create_proposal(&1u64, &user1, &description, &2_000u64);
+    assert_eq!(
+        client.try_cast_vote(&1u64, &1u64, &user2, &true),
+        Err(Ok(Error::VotesDelegated))
+    );
+    assert_eq!(
+        client.try_vote_on_proposal(&1u64, &proposal_id, &user2, &true),
+        Err(Ok(Error::VotesDelegated))
+    );
+
+    client.revoke_delegation(&1u64, &user2);
+    assert_eq!(
+        client.try_revoke_delegation(&1u64, &user2),
+        Err(Ok(Error::HolderNotFound))
+    );
+    client.vote_on_proposal(&1u64, &proposal_id, &user2, &true);
+    assert_eq!(client.tally_proposal(&1u64, &proposal_id), (300, 0));
+}
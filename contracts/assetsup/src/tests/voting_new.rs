@@ -29,6 +29,7 @@ fn setup_tokenized_asset(env: &Env, asset_id: u64, tokenizer: &Address) {
             accredited_investor_required: false,
             geographic_restrictions: soroban_sdk::Vec::new(env),
         },
+        None,
     )
     .unwrap();
 }
@@ -46,7 +47,7 @@ fn test_cast_vote() {
 
     let (cast_ok, has_voted) = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        let result = voting::cast_vote(&env, asset_id, 1, tokenizer.clone());
+        let result = voting::cast_vote(&env, asset_id, 1, tokenizer.clone(), true);
         let voted = voting::has_voted(&env, asset_id, 1, tokenizer.clone()).unwrap();
         (result.is_ok(), voted)
     });
@@ -65,9 +66,9 @@ fn test_double_vote_prevention() {
     let second_vote_err = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
         // Cast first vote
-        voting::cast_vote(&env, asset_id, 1, tokenizer.clone()).unwrap();
+        voting::cast_vote(&env, asset_id, 1, tokenizer.clone(), true).unwrap();
         // Try to vote again
-        voting::cast_vote(&env, asset_id, 1, tokenizer.clone()).is_err()
+        voting::cast_vote(&env, asset_id, 1, tokenizer.clone(), true).is_err()
     });
 
     assert!(second_vote_err);
@@ -82,8 +83,8 @@ fn test_double_vote_returns_already_voted_error() {
 
     let err = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        voting::cast_vote(&env, asset_id, 1, tokenizer.clone()).unwrap();
-        voting::cast_vote(&env, asset_id, 1, tokenizer.clone()).unwrap_err()
+        voting::cast_vote(&env, asset_id, 1, tokenizer.clone(), true).unwrap();
+        voting::cast_vote(&env, asset_id, 1, tokenizer.clone(), true).unwrap_err()
     });
 
     assert_eq!(err, crate::error::Error::AlreadyVoted);
@@ -101,11 +102,18 @@ fn test_insufficient_voting_power() {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
 
         // Transfer 50 tokens to new_holder (below 100 threshold)
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), new_holder.clone(), 50)
-            .unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            new_holder.clone(),
+            50,
+            None,
+        )
+        .unwrap();
 
         // new_holder has 50 tokens (below 100 threshold), should not be able to vote
-        voting::cast_vote(&env, asset_id, 1, new_holder.clone()).is_err()
+        voting::cast_vote(&env, asset_id, 1, new_holder.clone(), true).is_err()
     });
 
     assert!(vote_err);
@@ -121,9 +129,16 @@ fn test_insufficient_voting_power_returns_correct_error() {
 
     let err = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), new_holder.clone(), 50)
-            .unwrap();
-        voting::cast_vote(&env, asset_id, 1, new_holder.clone()).unwrap_err()
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            new_holder.clone(),
+            50,
+            None,
+        )
+        .unwrap();
+        voting::cast_vote(&env, asset_id, 1, new_holder.clone(), true).unwrap_err()
     });
 
     assert_eq!(err, crate::error::Error::InsufficientVotingPower);
@@ -137,7 +152,7 @@ fn test_cast_vote_on_asset_not_tokenized() {
     let asset_id = 999u64;
 
     let err = env.as_contract(&contract_id, || {
-        voting::cast_vote(&env, asset_id, 1, voter.clone()).unwrap_err()
+        voting::cast_vote(&env, asset_id, 1, voter.clone(), true).unwrap_err()
     });
 
     assert_eq!(err, crate::error::Error::AssetNotTokenized);
@@ -153,7 +168,7 @@ fn test_cast_vote_holder_not_found() {
 
     let err = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        voting::cast_vote(&env, asset_id, 1, non_holder.clone()).unwrap_err()
+        voting::cast_vote(&env, asset_id, 1, non_holder.clone(), true).unwrap_err()
     });
 
     assert_eq!(err, crate::error::Error::HolderNotFound);
@@ -171,11 +186,11 @@ fn test_vote_weight_equals_token_balance() {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
 
         // Transfer 400 tokens to holder
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder.clone(), 400)
+        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder.clone(), 400, None)
             .unwrap();
 
         let before = voting::get_vote_tally(&env, asset_id, 1).unwrap();
-        voting::cast_vote(&env, asset_id, 1, holder.clone()).unwrap();
+        voting::cast_vote(&env, asset_id, 1, holder.clone(), true).unwrap();
         let after = voting::get_vote_tally(&env, asset_id, 1).unwrap();
         (before, after)
     });
@@ -197,10 +212,10 @@ fn test_vote_at_exact_threshold() {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
 
         // Transfer exactly 100 tokens (the minimum threshold)
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder.clone(), 100)
+        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder.clone(), 100, None)
             .unwrap();
 
-        voting::cast_vote(&env, asset_id, 1, holder.clone()).is_ok()
+        voting::cast_vote(&env, asset_id, 1, holder.clone(), true).is_ok()
     });
 
     assert!(cast_ok);
@@ -215,8 +230,8 @@ fn test_same_voter_different_proposals() {
 
     let (vote1_ok, vote2_ok) = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        let v1 = voting::cast_vote(&env, asset_id, 1, tokenizer.clone()).is_ok();
-        let v2 = voting::cast_vote(&env, asset_id, 2, tokenizer.clone()).is_ok();
+        let v1 = voting::cast_vote(&env, asset_id, 1, tokenizer.clone(), true).is_ok();
+        let v2 = voting::cast_vote(&env, asset_id, 2, tokenizer.clone(), true).is_ok();
         (v1, v2)
     });
 
@@ -236,14 +251,28 @@ fn test_different_voters_same_proposal() {
 
     let (v1, v2, v3) = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder2.clone(), 200)
-            .unwrap();
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder3.clone(), 200)
-            .unwrap();
-
-        let v1 = voting::cast_vote(&env, asset_id, 1, tokenizer.clone()).is_ok();
-        let v2 = voting::cast_vote(&env, asset_id, 1, holder2.clone()).is_ok();
-        let v3 = voting::cast_vote(&env, asset_id, 1, holder3.clone()).is_ok();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder2.clone(),
+            200,
+            None,
+        )
+        .unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder3.clone(),
+            200,
+            None,
+        )
+        .unwrap();
+
+        let v1 = voting::cast_vote(&env, asset_id, 1, tokenizer.clone(), true).is_ok();
+        let v2 = voting::cast_vote(&env, asset_id, 1, holder2.clone(), true).is_ok();
+        let v3 = voting::cast_vote(&env, asset_id, 1, holder3.clone(), true).is_ok();
         (v1, v2, v3)
     });
 
@@ -268,12 +297,19 @@ fn test_vote_tally() {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
 
         // Transfer some tokens to second holder
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder2.clone(), 300)
-            .unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder2.clone(),
+            300,
+            None,
+        )
+        .unwrap();
 
         // Cast votes
-        voting::cast_vote(&env, asset_id, 1, tokenizer.clone()).unwrap();
-        voting::cast_vote(&env, asset_id, 1, holder2.clone()).unwrap();
+        voting::cast_vote(&env, asset_id, 1, tokenizer.clone(), true).unwrap();
+        voting::cast_vote(&env, asset_id, 1, holder2.clone(), true).unwrap();
 
         // Check tally
         voting::get_vote_tally(&env, asset_id, 1).unwrap()
@@ -323,14 +359,28 @@ fn test_vote_tally_accumulates_multiple_voters() {
     let tally = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
         // tokenizer has 1000, give 300 each to holders (tokenizer retains 400)
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder2.clone(), 300)
-            .unwrap();
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder3.clone(), 300)
-            .unwrap();
-
-        voting::cast_vote(&env, asset_id, 1, tokenizer.clone()).unwrap();
-        voting::cast_vote(&env, asset_id, 1, holder2.clone()).unwrap();
-        voting::cast_vote(&env, asset_id, 1, holder3.clone()).unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder2.clone(),
+            300,
+            None,
+        )
+        .unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder3.clone(),
+            300,
+            None,
+        )
+        .unwrap();
+
+        voting::cast_vote(&env, asset_id, 1, tokenizer.clone(), true).unwrap();
+        voting::cast_vote(&env, asset_id, 1, holder2.clone(), true).unwrap();
+        voting::cast_vote(&env, asset_id, 1, holder3.clone(), true).unwrap();
 
         voting::get_vote_tally(&env, asset_id, 1).unwrap()
     });
@@ -349,12 +399,19 @@ fn test_vote_tally_isolated_per_proposal() {
 
     let (tally1, tally2) = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder2.clone(), 300)
-            .unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder2.clone(),
+            300,
+            None,
+        )
+        .unwrap();
 
         // tokenizer votes on proposal 1, holder2 votes on proposal 2
-        voting::cast_vote(&env, asset_id, 1, tokenizer.clone()).unwrap();
-        voting::cast_vote(&env, asset_id, 2, holder2.clone()).unwrap();
+        voting::cast_vote(&env, asset_id, 1, tokenizer.clone(), true).unwrap();
+        voting::cast_vote(&env, asset_id, 2, holder2.clone(), true).unwrap();
 
         let t1 = voting::get_vote_tally(&env, asset_id, 1).unwrap();
         let t2 = voting::get_vote_tally(&env, asset_id, 2).unwrap();
@@ -393,7 +450,7 @@ fn test_has_voted_returns_true_after_voting() {
 
     let voted = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        voting::cast_vote(&env, asset_id, 1, tokenizer.clone()).unwrap();
+        voting::cast_vote(&env, asset_id, 1, tokenizer.clone(), true).unwrap();
         voting::has_voted(&env, asset_id, 1, tokenizer.clone()).unwrap()
     });
 
@@ -423,7 +480,7 @@ fn test_has_voted_independent_per_proposal() {
 
     let (voted_p1, voted_p2) = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        voting::cast_vote(&env, asset_id, 1, tokenizer.clone()).unwrap();
+        voting::cast_vote(&env, asset_id, 1, tokenizer.clone(), true).unwrap();
 
         let v1 = voting::has_voted(&env, asset_id, 1, tokenizer.clone()).unwrap();
         let v2 = voting::has_voted(&env, asset_id, 2, tokenizer.clone()).unwrap();
@@ -450,11 +507,18 @@ fn test_proposal_passed() {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
 
         // Transfer 600 tokens to holder2 (>50% of 1000)
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder2.clone(), 600)
-            .unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder2.clone(),
+            600,
+            None,
+        )
+        .unwrap();
 
         // Holder2 votes (600 votes)
-        voting::cast_vote(&env, asset_id, 1, holder2.clone()).unwrap();
+        voting::cast_vote(&env, asset_id, 1, holder2.clone(), true).unwrap();
 
         // Check if proposal passed
         voting::proposal_passed(&env, asset_id, 1).unwrap()
@@ -475,11 +539,18 @@ fn test_proposal_failed() {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
 
         // Transfer 400 tokens to holder2 (<50% of 1000)
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder2.clone(), 400)
-            .unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder2.clone(),
+            400,
+            None,
+        )
+        .unwrap();
 
         // Holder2 votes with 400 tokens (40% — below threshold)
-        voting::cast_vote(&env, asset_id, 1, holder2.clone()).unwrap();
+        voting::cast_vote(&env, asset_id, 1, holder2.clone(), true).unwrap();
 
         // Check if proposal failed
         voting::proposal_passed(&env, asset_id, 1).unwrap()
@@ -528,7 +599,7 @@ fn test_proposal_passed_respects_min_voting_threshold() {
     // All 1000 tokens voting should definitely pass
     let passed = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
-        voting::cast_vote(&env, asset_id, 1, tokenizer.clone()).unwrap();
+        voting::cast_vote(&env, asset_id, 1, tokenizer.clone(), true).unwrap();
         voting::proposal_passed(&env, asset_id, 1).unwrap()
     });
 
@@ -548,17 +619,31 @@ fn test_proposal_passed_multiple_voters_crossing_threshold() {
     let (before_threshold, after_threshold) = env.as_contract(&contract_id, || {
         setup_tokenized_asset(&env, asset_id, &tokenizer);
         // Distribute: tokenizer=400, holder2=300, holder3=300
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder2.clone(), 300)
-            .unwrap();
-        tokenization::transfer_tokens(&env, asset_id, tokenizer.clone(), holder3.clone(), 300)
-            .unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder2.clone(),
+            300,
+            None,
+        )
+        .unwrap();
+        tokenization::transfer_tokens(
+            &env,
+            asset_id,
+            tokenizer.clone(),
+            holder3.clone(),
+            300,
+            None,
+        )
+        .unwrap();
 
         // Only holder2 votes (300/1000 = 30%) — should not pass
-        voting::cast_vote(&env, asset_id, 1, holder2.clone()).unwrap();
+        voting::cast_vote(&env, asset_id, 1, holder2.clone(), true).unwrap();
         let before = voting::proposal_passed(&env, asset_id, 1).unwrap();
 
         // holder3 also votes (600/1000 = 60%) — should now pass
-        voting::cast_vote(&env, asset_id, 1, holder3.clone()).unwrap();
+        voting::cast_vote(&env, asset_id, 1, holder3.clone(), true).unwrap();
         let after = voting::proposal_passed(&env, asset_id, 1).unwrap();
 
         (before, after)
@@ -1,10 +1,20 @@
+use crate::asset::{self, Asset};
 use crate::audit;
+use crate::dividends;
 use crate::error::Error;
-use crate::types::{OwnershipRecord, TokenDataKey, TokenMetadata, TokenizedAsset};
-use soroban_sdk::{Address, BytesN, Env, String, Vec};
+use crate::escrow;
+use crate::roles;
+use crate::transfer_restrictions;
+use crate::types::{
+    AssetRoles, AssetStateExport, AssetStatus, HolderExport, MintRateLimit, OwnershipRecord,
+    RegistryRecord, SelfLock, TokenDataKey, TokenMetadata, TokenizedAsset, TransferLimit,
+    TransferRestriction, VestingSchedule,
+};
+use crate::voting;
+use soroban_sdk::{Address, BytesN, Env, Map, String, Vec};
 
 /// Helper function to convert u64 asset_id to BytesN<32> for audit logging
-fn asset_id_to_bytes(env: &Env, asset_id: u64) -> BytesN<32> {
+pub(crate) fn asset_id_to_bytes(env: &Env, asset_id: u64) -> BytesN<32> {
     let mut bytes = [0u8; 32];
     let id_bytes = asset_id.to_be_bytes();
     // Place the u64 bytes at the end of the 32-byte array
@@ -12,6 +22,34 @@ fn asset_id_to_bytes(env: &Env, asset_id: u64) -> BytesN<32> {
     BytesN::from_array(env, &bytes)
 }
 
+/// Inverse of `asset_id_to_bytes`; None when the bytes are not an encoded u64 ID
+pub(crate) fn bytes_to_asset_id(bytes: &BytesN<32>) -> Option<u64> {
+    let bytes = bytes.to_array();
+    if bytes[..24].iter().any(|b| *b != 0) {
+        return None;
+    }
+    let mut id_bytes = [0u8; 8];
+    id_bytes.copy_from_slice(&bytes[24..32]);
+    Some(u64::from_be_bytes(id_bytes))
+}
+
+/// Sync the registry status of the asset backing a tokenization, if it is registered
+pub(crate) fn set_registry_status(env: &Env, asset_id: u64, status: AssetStatus) {
+    let store = env.storage().persistent();
+    let key = asset::DataKey::Asset(asset_id_to_bytes(env, asset_id));
+    if let Some(mut registered) = store.get::<_, Asset>(&key) {
+        registered.status = status;
+        store.set(&key, &registered);
+    }
+}
+
+/// The registry record of the asset backing a tokenization, if it is registered
+fn registered_asset(env: &Env, asset_id: u64) -> Option<Asset> {
+    env.storage()
+        .persistent()
+        .get(&asset::DataKey::Asset(asset_id_to_bytes(env, asset_id)))
+}
+
 /// Initialize tokenization by creating tokenized asset
 /// Only contract admin or asset owner can tokenize
 #[allow(clippy::too_many_arguments)]
@@ -24,10 +62,14 @@ pub fn tokenize_asset(
     min_voting_threshold: i128,
     tokenizer: Address,
     metadata: TokenMetadata,
+    max_supply: Option<i128>,
 ) -> Result<TokenizedAsset, Error> {
     // Validate inputs
     if total_supply <= 0 {
-        return Err(Error::InvalidTokenSupply);
+        return Err(Error::InvalidAmount);
+    }
+    if max_supply.is_some_and(|cap| cap < total_supply) {
+        return Err(Error::InvalidAmount);
     }
 
     // Check if asset is already tokenized
@@ -37,6 +79,35 @@ pub fn tokenize_asset(
         return Err(Error::AssetAlreadyTokenized);
     }
 
+    // Only registered assets in use can be tokenized; suspended or retired ones
+    // must be reactivated first
+    let registered = registered_asset(env, asset_id);
+    if let Some(asset) = &registered {
+        if !matches!(asset.status, AssetStatus::Active | AssetStatus::Transferred) {
+            return Err(Error::InvalidStatusTransition);
+        }
+    }
+
+    // Registered assets are valued at their purchase value; otherwise each
+    // token starts out worth one unit
+    let valuation = registered
+        .as_ref()
+        .map(|asset| asset.purchase_value)
+        .filter(|value| *value > 0)
+        .unwrap_or(total_supply);
+    let prior_status = registered.map(|asset| asset.status);
+
+    // The index keeps the first asset to claim a symbol; reuse is only
+    // rejected while uniqueness is enforced
+    let symbol_key = TokenDataKey::SymbolIndex(symbol.clone());
+    if store.has(&symbol_key) {
+        if is_enforce_unique_symbols(env) {
+            return Err(Error::SymbolTaken);
+        }
+    } else {
+        store.set(&symbol_key, &asset_id);
+    }
+
     // Create tokenized asset
     let timestamp = env.ledger().timestamp();
     let tokenized_asset = TokenizedAsset {
@@ -46,17 +117,21 @@ pub fn tokenize_asset(
         decimals,
         locked_tokens: 0,
         tokenizer: tokenizer.clone(),
-        valuation: total_supply,
+        valuation,
         token_holders_count: 1,
         tokens_in_circulation: total_supply,
         min_voting_threshold,
         revenue_sharing_enabled: false,
         tokenization_timestamp: timestamp,
         detokenize_threshold: 50, // 50% majority
+        max_supply,
+        max_holders: None,
     };
 
     // Store tokenized asset
     store.set(&key, &tokenized_asset);
+    adjust_total_value_locked(env, tokenized_asset.valuation)?;
+    record_valuation(env, asset_id, valuation);
 
     // Store metadata
     let metadata_key = TokenDataKey::TokenMetadata(asset_id);
@@ -67,7 +142,8 @@ pub fn tokenize_asset(
         owner: tokenizer.clone(),
         balance: total_supply,
         acquisition_timestamp: timestamp,
-        average_purchase_price: 1,
+        // Cost basis starts at the tokenization valuation per token
+        average_purchase_price: tokenized_asset.valuation / total_supply,
         voting_power: total_supply,
         dividend_entitlement: total_supply,
         unclaimed_dividends: 0,
@@ -83,6 +159,13 @@ pub fn tokenize_asset(
     let holders_list_key = TokenDataKey::TokenHoldersList(asset_id);
     store.set(&holders_list_key, &holders);
 
+    // Reflect tokenization in the asset registry, remembering the status to
+    // restore on detokenization
+    if let Some(status) = prior_status {
+        store.set(&TokenDataKey::PreTokenizationStatus(asset_id), &status);
+        set_registry_status(env, asset_id, AssetStatus::Tokenized);
+    }
+
     // Append audit log (convert u64 asset_id to BytesN<32>)
     let asset_id_bytes = asset_id_to_bytes(env, asset_id);
     audit::append_audit_log(
@@ -103,7 +186,7 @@ pub fn tokenize_asset(
 }
 
 /// Mint additional tokens
-/// Only tokenizer can mint
+/// Only the tokenizer or a `MINTER` role holder can mint
 pub fn mint_tokens(
     env: &Env,
     asset_id: u64,
@@ -111,7 +194,7 @@ pub fn mint_tokens(
     minter: Address,
 ) -> Result<TokenizedAsset, Error> {
     if amount <= 0 {
-        return Err(Error::InvalidTokenSupply);
+        return Err(Error::InvalidAmount);
     }
 
     let store = env.storage().persistent();
@@ -120,28 +203,49 @@ pub fn mint_tokens(
     // Get tokenized asset
     let mut tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
 
-    // Only tokenizer can mint
-    if tokenized_asset.tokenizer != minter {
+    // Only the tokenizer or a holder of the asset's MINTER role can mint
+    if tokenized_asset.tokenizer != minter
+        && !roles::has_scoped_role(
+            env,
+            roles::MINTER,
+            roles::RoleScope::Asset(asset_id),
+            minter.clone(),
+        )
+    {
         return Err(Error::Unauthorized);
     }
 
-    // Update total supply
-    tokenized_asset.total_supply += amount;
-    tokenized_asset.tokens_in_circulation += amount;
+    if is_minting_paused(env, asset_id) {
+        return Err(Error::MintingPaused);
+    }
 
-    // Update tokenizer's ownership
-    let holder_key = TokenDataKey::TokenHolder(asset_id, minter.clone());
-    let mut ownership: OwnershipRecord = store.get(&holder_key).ok_or(Error::HolderNotFound)?;
+    if let Some(cap) = tokenized_asset.max_supply {
+        let new_supply = tokenized_asset
+            .total_supply
+            .checked_add(amount)
+            .ok_or(Error::ArithmeticOverflow)?;
+        if new_supply > cap {
+            return Err(Error::LimitExceeded);
+        }
+    }
 
-    ownership.balance += amount;
-    ownership.voting_power = ownership.balance;
-    ownership.dividend_entitlement = ownership.balance;
+    consume_mint_allowance(env, asset_id, amount)?;
 
-    // Recalculate ownership percentage
-    ownership.ownership_percentage = (ownership.balance * 10000) / tokenized_asset.total_supply;
+    // Update total supply
+    tokenized_asset.total_supply = tokenized_asset
+        .total_supply
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticOverflow)?;
+    tokenized_asset.tokens_in_circulation = tokenized_asset
+        .tokens_in_circulation
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticOverflow)?;
+    store.set(&key, &tokenized_asset);
 
-    store.set(&holder_key, &ownership);
-    store.set(&key, &tokenized_asset.clone());
+    // Update tokenizer's ownership; a tokenizer who had sold out rejoins the holders
+    credit_holder(env, asset_id, &minter, amount, tokenized_asset.total_supply)?;
+    recalculate_all_ownership_percentages(env, asset_id)?;
+    let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
 
     // Append audit log
     let asset_id_bytes = asset_id_to_bytes(env, asset_id);
@@ -162,6 +266,45 @@ pub fn mint_tokens(
     Ok(tokenized_asset)
 }
 
+/// Tokens that can still be minted under the supply cap (None when uncapped)
+pub fn get_remaining_mintable(env: &Env, asset_id: u64) -> Option<i128> {
+    let tokenized_asset: TokenizedAsset = env
+        .storage()
+        .persistent()
+        .get(&TokenDataKey::TokenizedAsset(asset_id))?;
+    tokenized_asset
+        .max_supply
+        .map(|cap| (cap - tokenized_asset.total_supply).max(0))
+}
+
+/// Cap the number of distinct holders, or lift the cap with `None` (only
+/// tokenizer can call). The cap cannot be set below the current holder count.
+pub fn set_max_holders(
+    env: &Env,
+    asset_id: u64,
+    max_holders: Option<u32>,
+    caller: Address,
+) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let key = TokenDataKey::TokenizedAsset(asset_id);
+    let mut tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+
+    if tokenized_asset.tokenizer != caller {
+        return Err(Error::Unauthorized);
+    }
+    if max_holders.is_some_and(|cap| cap < tokenized_asset.token_holders_count) {
+        return Err(Error::InvalidAmount);
+    }
+
+    tokenized_asset.max_holders = max_holders;
+    store.set(&key, &tokenized_asset);
+
+    env.events()
+        .publish(("token", "max_holders_set"), (asset_id, max_holders));
+
+    Ok(())
+}
+
 /// Burn tokens
 /// Only tokenizer can burn, and only from their own account
 pub fn burn_tokens(
@@ -171,7 +314,7 @@ pub fn burn_tokens(
     burner: Address,
 ) -> Result<TokenizedAsset, Error> {
     if amount <= 0 {
-        return Err(Error::InvalidTokenSupply);
+        return Err(Error::InvalidAmount);
     }
 
     let store = env.storage().persistent();
@@ -186,26 +329,43 @@ pub fn burn_tokens(
     }
 
     // Get burner's balance
-    let holder_key = TokenDataKey::TokenHolder(asset_id, burner.clone());
-    let mut ownership: OwnershipRecord = store.get(&holder_key).ok_or(Error::HolderNotFound)?;
-
-    if ownership.balance < amount {
+    if get_token_balance(env, asset_id, burner.clone())? < amount {
         return Err(Error::InsufficientBalance);
     }
 
-    // Update balances
-    ownership.balance -= amount;
-    ownership.voting_power = ownership.balance;
-    ownership.dividend_entitlement = ownership.balance;
-
-    // Recalculate ownership percentage
-    ownership.ownership_percentage = (ownership.balance * 10000) / tokenized_asset.total_supply;
+    tokenized_asset.tokens_in_circulation = tokenized_asset
+        .tokens_in_circulation
+        .checked_sub(amount)
+        .ok_or(Error::ArithmeticOverflow)?;
+    if let Some(burn_address) = get_burn_address(env, asset_id) {
+        // Burned tokens stay in supply, parked at the non-transferable burn address
+        store.set(&key, &tokenized_asset);
+        debit_holder(env, asset_id, &burner, amount, tokenized_asset.total_supply)?;
+        credit_holder(
+            env,
+            asset_id,
+            &burn_address,
+            amount,
+            tokenized_asset.total_supply,
+        )?;
+    } else {
+        tokenized_asset.total_supply = tokenized_asset
+            .total_supply
+            .checked_sub(amount)
+            .ok_or(Error::ArithmeticOverflow)?;
+        store.set(&key, &tokenized_asset);
+        debit_holder(env, asset_id, &burner, amount, tokenized_asset.total_supply)?;
+    }
 
-    tokenized_asset.total_supply -= amount;
-    tokenized_asset.tokens_in_circulation -= amount;
+    recalculate_all_ownership_percentages(env, asset_id)?;
+    let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
 
-    store.set(&holder_key, &ownership);
-    store.set(&key, &tokenized_asset.clone());
+    let burned_key = TokenDataKey::BurnedTotal(asset_id);
+    let burned: i128 = store.get(&burned_key).unwrap_or(0);
+    let burned = burned
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticOverflow)?;
+    store.set(&burned_key, &burned);
 
     // Append audit log
     let asset_id_bytes = asset_id_to_bytes(env, asset_id);
@@ -226,16 +386,18 @@ pub fn burn_tokens(
     Ok(tokenized_asset)
 }
 
-/// Transfer tokens from one address to another
+/// Transfer tokens from one address to another. When `price_per_token` is given the
+/// recipient's average purchase price absorbs the tokens received at that price.
 pub fn transfer_tokens(
     env: &Env,
     asset_id: u64,
     from: Address,
     to: Address,
     amount: i128,
+    price_per_token: Option<i128>,
 ) -> Result<(), Error> {
-    if amount <= 0 {
-        return Err(Error::InvalidTokenSupply);
+    if amount <= 0 || price_per_token.is_some_and(|price| price <= 0) {
+        return Err(Error::InvalidAmount);
     }
 
     let store = env.storage().persistent();
@@ -244,69 +406,38 @@ pub fn transfer_tokens(
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
 
-    // Check if from address has locked tokens
-    let lock_key = TokenDataKey::TokenLockedUntil(asset_id, from.clone());
-    if let Some(lock_time) = store.get::<_, u64>(&lock_key) {
-        if env.ledger().timestamp() < lock_time {
-            return Err(Error::TokensAreLocked);
-        }
-    }
-
-    // Get from balance
-    let from_holder_key = TokenDataKey::TokenHolder(asset_id, from.clone());
-    let mut from_ownership: OwnershipRecord =
-        store.get(&from_holder_key).ok_or(Error::HolderNotFound)?;
+    check_exit_only(env, asset_id, &to)?;
+    check_not_burn_address(env, asset_id, &from)?;
 
-    if from_ownership.balance < amount {
-        return Err(Error::InsufficientBalance);
+    if dividends::is_distribution_frozen(env, asset_id) {
+        return Err(Error::DistributionInProgress);
     }
 
-    // Get to balance (or create new holder)
-    let to_holder_key = TokenDataKey::TokenHolder(asset_id, to.clone());
-    let mut to_ownership: OwnershipRecord = match store.get(&to_holder_key) {
-        Some(ownership) => ownership,
-        None => {
-            // Create new holder
-            let timestamp = env.ledger().timestamp();
-            OwnershipRecord {
-                owner: to.clone(),
-                balance: 0,
-                acquisition_timestamp: timestamp,
-                average_purchase_price: 1,
-                voting_power: 0,
-                dividend_entitlement: 0,
-                unclaimed_dividends: 0,
-                ownership_percentage: 0,
-            }
-        }
-    };
-
-    // Update balances
-    from_ownership.balance -= amount;
-    from_ownership.voting_power = from_ownership.balance;
-    from_ownership.dividend_entitlement = from_ownership.balance;
-    from_ownership.ownership_percentage =
-        (from_ownership.balance * 10000) / tokenized_asset.total_supply;
+    // Check the transfer leaves any locked tokens in place
+    check_unlocked(env, asset_id, &from, amount)?;
+    consume_transfer_allowance(env, asset_id, &from, amount)?;
 
-    to_ownership.balance += amount;
-    to_ownership.voting_power = to_ownership.balance;
-    to_ownership.dividend_entitlement = to_ownership.balance;
-    to_ownership.ownership_percentage =
-        (to_ownership.balance * 10000) / tokenized_asset.total_supply;
+    // Any reflection tax is withheld from the recipient into the reflection pool
+    // and accrued to the other holders as dividends
+    let tax = accrue_reflection_tax(env, asset_id, &from, &to, amount)?;
 
-    store.set(&from_holder_key, &from_ownership);
-    store.set(&to_holder_key, &to_ownership);
+    debit_holder(env, asset_id, &from, amount, tokenized_asset.total_supply)?;
+    credit_holder(
+        env,
+        asset_id,
+        &to,
+        amount - tax,
+        tokenized_asset.total_supply,
+    )?;
+    if let Some(price) = price_per_token {
+        record_purchase_price(env, asset_id, &to, amount - tax, price)?;
+    }
 
-    // Add to holder list if new
-    let holders_list_key = TokenDataKey::TokenHoldersList(asset_id);
-    let mut holders: Vec<Address> = store
-        .get(&holders_list_key)
+    let holders: Vec<Address> = store
+        .get(&TokenDataKey::TokenHoldersList(asset_id))
         .ok_or(Error::AssetNotTokenized)?;
-
-    let is_new_holder = !holders.iter().any(|h| h == to);
-    if is_new_holder {
-        holders.push_back(to.clone());
-        store.set(&holders_list_key, &holders);
+    if holders.len() <= AUTO_NORMALIZE_MAX_HOLDERS {
+        normalize_percentages(env, asset_id)?;
     }
 
     // Append audit log
@@ -328,144 +459,1619 @@ pub fn transfer_tokens(
     Ok(())
 }
 
-/// Get token balance for an address
-pub fn get_token_balance(env: &Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
+/// Move tokens out of a holder's account without their consent, e.g. under a
+/// court order or to recover lost keys (only tokenizer can call). Locks, freezes
+/// and transfer limits on `from` do not apply, but a blacklisted or frozen
+/// destination, a burn address source and a dividend declaration freeze are
+/// still refused.
+pub fn force_transfer(
+    env: &Env,
+    asset_id: u64,
+    from: Address,
+    to: Address,
+    amount: i128,
+    caller: Address,
+) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    require_tokenizer(env, asset_id, &caller)?;
+    if transfer_restrictions::is_blacklisted(env, asset_id, to.clone()) {
+        return Err(Error::AddressBlacklisted);
+    }
+    if transfer_restrictions::is_holder_frozen(env, asset_id, &to) {
+        return Err(Error::HolderFrozen);
+    }
+    // Burned tokens never re-enter circulation, and a dividend snapshot holds
+    check_not_burn_address(env, asset_id, &from)?;
+    if dividends::is_distribution_frozen(env, asset_id) {
+        return Err(Error::DistributionInProgress);
+    }
+
     let store = env.storage().persistent();
-    let key = TokenDataKey::TokenHolder(asset_id, holder);
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
 
-    match store.get::<_, OwnershipRecord>(&key) {
-        Some(ownership) => Ok(ownership.balance),
-        None => Ok(0),
+    debit_holder(env, asset_id, &from, amount, tokenized_asset.total_supply)?;
+    credit_holder(env, asset_id, &to, amount, tokenized_asset.total_supply)?;
+
+    let holders: Vec<Address> = store
+        .get(&TokenDataKey::TokenHoldersList(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+    if holders.len() <= AUTO_NORMALIZE_MAX_HOLDERS {
+        normalize_percentages(env, asset_id)?;
     }
-}
 
-/// Get all token holders for an asset
-pub fn get_token_holders(env: &Env, asset_id: u64) -> Result<Vec<Address>, Error> {
-    let store = env.storage().persistent();
-    let key = TokenDataKey::TokenHoldersList(asset_id);
+    audit::append_audit_log(
+        env,
+        &asset_id_to_bytes(env, asset_id),
+        String::from_str(env, "FORCE_TRANSFER"),
+        caller.clone(),
+        String::from_str(env, "Tokens force-transferred by tokenizer"),
+    );
 
-    store.get(&key).ok_or(Error::AssetNotTokenized)
+    // Emit event: (asset_id, from, to, amount)
+    env.events()
+        .publish(("token", "force_transfer"), (asset_id, from, to, amount));
+
+    Ok(())
 }
 
-/// Lock tokens until a specific timestamp.
-/// Only the tokenizer of the asset can lock a holder's tokens.
-pub fn lock_tokens(
+/// Fold `received` tokens bought at `price` into the holder's average purchase
+/// price, weighted against the balance they already held
+fn record_purchase_price(
     env: &Env,
     asset_id: u64,
-    holder: Address,
-    until_timestamp: u64,
-    caller: Address,
+    holder: &Address,
+    received: i128,
+    price: i128,
 ) -> Result<(), Error> {
-    // Verify asset is tokenized
     let store = env.storage().persistent();
-    let key = TokenDataKey::TokenizedAsset(asset_id);
-    let _: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
-
-    // Only tokenizer can lock
-    let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
-
-    if tokenized_asset.tokenizer != caller {
-        return Err(Error::Unauthorized);
+    let holder_key = TokenDataKey::TokenHolder(asset_id, holder.clone());
+    let mut ownership: OwnershipRecord = store.get(&holder_key).ok_or(Error::HolderNotFound)?;
+    if ownership.balance <= 0 {
+        return Ok(());
     }
 
-    let lock_key = TokenDataKey::TokenLockedUntil(asset_id, holder.clone());
-    store.set(&lock_key, &until_timestamp);
-
-    // Emit event: (asset_id, holder, until_timestamp)
-    env.events().publish(
-        ("token", "tokens_locked"),
-        (asset_id, holder, until_timestamp),
-    );
+    let previous = ownership.balance - received;
+    let cost = previous
+        .checked_mul(ownership.average_purchase_price)
+        .and_then(|c| c.checked_add(received.checked_mul(price)?))
+        .ok_or(Error::ArithmeticOverflow)?;
+    ownership.average_purchase_price = cost / ownership.balance;
 
+    store.set(&holder_key, &ownership);
     Ok(())
 }
 
-/// Unlock tokens (remove lock)
-pub fn unlock_tokens(env: &Env, asset_id: u64, holder: Address) -> Result<(), Error> {
-    let store = env.storage().persistent();
-
-    // Verify asset is tokenized
-    let key = TokenDataKey::TokenizedAsset(asset_id);
-    let _: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+/// Allow `spender` to transfer up to `amount` of the owner's tokens.
+/// Each approval replaces the previous allowance; zero revokes it. Allowances are
+/// scoped to the current tokenization round and lapse on detokenization.
+pub fn approve(
+    env: &Env,
+    asset_id: u64,
+    owner: Address,
+    spender: Address,
+    amount: i128,
+) -> Result<(), Error> {
+    if amount < 0 {
+        return Err(Error::InvalidAmount);
+    }
 
-    let lock_key = TokenDataKey::TokenLockedUntil(asset_id, holder.clone());
+    let store = env.storage().persistent();
+    let _: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
 
-    // Remove lock record
-    if store.has(&lock_key) {
-        store.remove(&lock_key);
+    let key = allowance_key(env, asset_id, &owner, &spender);
+    if amount == 0 {
+        store.remove(&key);
+    } else {
+        store.set(&key, &amount);
     }
 
-    // Emit event: (asset_id, holder)
     env.events()
-        .publish(("token", "tokens_unlocked"), (asset_id, holder));
+        .publish(("token", "approval"), (asset_id, owner, spender, amount));
 
     Ok(())
 }
 
-/// Returns true if the holder's tokens are currently locked (lock timestamp is in the future).
-pub fn is_tokens_locked(env: &Env, asset_id: u64, holder: Address) -> bool {
+/// Get how many of the owner's tokens `spender` may still transfer
+pub fn get_allowance(env: &Env, asset_id: u64, owner: Address, spender: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&allowance_key(env, asset_id, &owner, &spender))
+        .unwrap_or(0)
+}
+
+/// Storage key of an allowance in the asset's current tokenization round
+fn allowance_key(env: &Env, asset_id: u64, owner: &Address, spender: &Address) -> TokenDataKey {
+    TokenDataKey::Allowance(
+        asset_id,
+        tokenization_round(env, asset_id),
+        owner.clone(),
+        spender.clone(),
+    )
+}
+
+/// Number of times the asset has been detokenized
+pub(crate) fn tokenization_round(env: &Env, asset_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::TokenizationRound(asset_id))
+        .unwrap_or(0)
+}
+
+/// Transfer `amount` of `from`'s tokens on their behalf, spending the spender's allowance
+pub fn transfer_from(
+    env: &Env,
+    asset_id: u64,
+    spender: Address,
+    from: Address,
+    to: Address,
+    amount: i128,
+) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let allowance = get_allowance(env, asset_id, from.clone(), spender.clone());
+    if allowance < amount {
+        return Err(Error::InsufficientAllowance);
+    }
+
     let store = env.storage().persistent();
-    let lock_key = TokenDataKey::TokenLockedUntil(asset_id, holder);
-    match store.get::<_, u64>(&lock_key) {
-        Some(lock_until) => env.ledger().timestamp() < lock_until,
-        None => false,
+    let key = allowance_key(env, asset_id, &from, &spender);
+    if allowance == amount {
+        store.remove(&key);
+    } else {
+        store.set(&key, &(allowance - amount));
     }
+
+    transfer_tokens(env, asset_id, from, to, amount, None)
 }
 
-/// Calculate ownership percentage for a holder (in basis points)
-pub fn calculate_ownership_percentage(
+/// Transfer tokens from one sender to many recipients atomically. Every recipient
+/// is validated and the total checked against the sender's unlocked balance before
+/// any transfer is applied; a failure anywhere rejects the whole batch.
+pub fn batch_transfer_tokens(
     env: &Env,
     asset_id: u64,
-    holder: Address,
-) -> Result<i128, Error> {
-    let store = env.storage().persistent();
+    from: Address,
+    recipients: Vec<(Address, i128)>,
+) -> Result<(), Error> {
+    if recipients.is_empty() {
+        return Err(Error::InvalidAmount);
+    }
 
-    // Get asset
-    let key = TokenDataKey::TokenizedAsset(asset_id);
-    let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    let mut total: i128 = 0;
+    for (to, amount) in recipients.iter() {
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        total = total.checked_add(amount).ok_or(Error::ArithmeticOverflow)?;
+        transfer_restrictions::validate_transfer(env, asset_id, from.clone(), to.clone())?;
+        check_exit_only(env, asset_id, &to)?;
+    }
 
-    // Get holder balance
-    let holder_key = TokenDataKey::TokenHolder(asset_id, holder);
-    let ownership: OwnershipRecord = store.get(&holder_key).ok_or(Error::HolderNotFound)?;
+    if get_token_balance(env, asset_id, from.clone())? < total {
+        return Err(Error::InsufficientBalance);
+    }
+    check_unlocked(env, asset_id, &from, total)?;
 
-    // Calculate percentage: (balance / total_supply) * 10000
-    if tokenized_asset.total_supply <= 0 {
-        return Ok(0);
+    for (to, amount) in recipients.iter() {
+        transfer_tokens(env, asset_id, from.clone(), to, amount, None)?;
     }
 
-    Ok((ownership.balance * 10000) / tokenized_asset.total_supply)
+    env.events().publish(
+        ("token", "tokens_batch_transferred"),
+        (asset_id, from, recipients.len()),
+    );
+
+    Ok(())
 }
 
-/// Get tokenized asset details
-pub fn get_tokenized_asset(env: &Env, asset_id: u64) -> Result<TokenizedAsset, Error> {
+/// Remove `amount` from a holder's balance and refresh their derived fields
+pub(crate) fn debit_holder(
+    env: &Env,
+    asset_id: u64,
+    holder: &Address,
+    amount: i128,
+    total_supply: i128,
+) -> Result<(), Error> {
     let store = env.storage().persistent();
-    let key = TokenDataKey::TokenizedAsset(asset_id);
+    let holder_key = TokenDataKey::TokenHolder(asset_id, holder.clone());
+    let mut ownership: OwnershipRecord = store.get(&holder_key).ok_or(Error::HolderNotFound)?;
 
-    store.get(&key).ok_or(Error::AssetNotTokenized)
-}
+    if ownership.balance < amount {
+        return Err(Error::InsufficientBalance);
+    }
 
-/// Get token metadata
-#[allow(dead_code)]
-pub fn get_token_metadata(env: &Env, asset_id: u64) -> Result<TokenMetadata, Error> {
-    let store = env.storage().persistent();
-    let key = TokenDataKey::TokenMetadata(asset_id);
+    ownership.balance -= amount;
+    ownership.voting_power = ownership.balance;
+    ownership.dividend_entitlement = ownership.balance;
+    ownership.ownership_percentage = percentage_of(ownership.balance, total_supply)?;
 
-    store.get(&key).ok_or(Error::AssetNotTokenized)
+    if ownership.balance > 0 {
+        store.set(&holder_key, &ownership);
+        return Ok(());
+    }
+
+    // Drained holders leave the holders list; the record stays only while it
+    // still carries dividends to claim
+    remove_holder(env, asset_id, holder)?;
+    if ownership.unclaimed_dividends > 0 {
+        store.set(&holder_key, &ownership);
+    } else {
+        store.remove(&holder_key);
+    }
+    Ok(())
 }
 
-/// Update asset valuation
-pub fn update_valuation(env: &Env, asset_id: u64, new_valuation: i128) -> Result<(), Error> {
-    if new_valuation <= 0 {
-        return Err(Error::InvalidValuation);
+/// `balance` as basis points of `total_supply`, or zero for an empty supply
+fn percentage_of(balance: i128, total_supply: i128) -> Result<i128, Error> {
+    if total_supply <= 0 {
+        return Ok(0);
     }
+    balance
+        .checked_mul(10000)
+        .and_then(|scaled| scaled.checked_div(total_supply))
+        .ok_or(Error::ArithmeticOverflow)
+}
 
+/// Drop a holder from the holders list and the asset's holder count
+fn remove_holder(env: &Env, asset_id: u64, holder: &Address) -> Result<(), Error> {
     let store = env.storage().persistent();
-    let key = TokenDataKey::TokenizedAsset(asset_id);
+    let holders_list_key = TokenDataKey::TokenHoldersList(asset_id);
+    let mut holders: Vec<Address> = store
+        .get(&holders_list_key)
+        .ok_or(Error::AssetNotTokenized)?;
+
+    if let Some(index) = holders.first_index_of(holder) {
+        holders.remove(index);
+        store.set(&holders_list_key, &holders);
+        set_holder_count(env, asset_id, holders.len())?;
+    }
+    Ok(())
+}
+
+/// Keep `token_holders_count` in step with the holders list
+fn set_holder_count(env: &Env, asset_id: u64, count: u32) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let key = TokenDataKey::TokenizedAsset(asset_id);
+    let mut tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    tokenized_asset.token_holders_count = count;
+    store.set(&key, &tokenized_asset);
+    Ok(())
+}
+
+/// True if moving `amount` from `from` to `to` would add a holder past the
+/// asset's `max_holders` cap, counting the slot `from` frees when drained
+pub(crate) fn would_exceed_max_holders(
+    env: &Env,
+    asset_id: u64,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<bool, Error> {
+    let store = env.storage().persistent();
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+    let cap = match tokenized_asset.max_holders {
+        Some(cap) => cap,
+        None => return Ok(false),
+    };
+    let holders: Vec<Address> = store
+        .get(&TokenDataKey::TokenHoldersList(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+    if holders.contains(to) {
+        return Ok(false);
+    }
+    let mut count = holders.len();
+    if from != to && get_token_balance(env, asset_id, from.clone())? == amount {
+        count -= 1;
+    }
+    Ok(count >= cap)
+}
+
+/// Add `amount` to a holder's balance, creating the holder record and holder-list
+/// entry if they are new
+pub(crate) fn credit_holder(
+    env: &Env,
+    asset_id: u64,
+    holder: &Address,
+    amount: i128,
+    total_supply: i128,
+) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let holder_key = TokenDataKey::TokenHolder(asset_id, holder.clone());
+    let mut ownership: OwnershipRecord = match store.get(&holder_key) {
+        Some(ownership) => ownership,
+        None => {
+            // Create new holder
+            let timestamp = env.ledger().timestamp();
+            OwnershipRecord {
+                owner: holder.clone(),
+                balance: 0,
+                acquisition_timestamp: timestamp,
+                average_purchase_price: 1,
+                voting_power: 0,
+                dividend_entitlement: 0,
+                unclaimed_dividends: 0,
+                ownership_percentage: 0,
+            }
+        }
+    };
+
+    ownership.balance = ownership
+        .balance
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticOverflow)?;
+    ownership.voting_power = ownership.balance;
+    ownership.dividend_entitlement = ownership.balance;
+    ownership.ownership_percentage = percentage_of(ownership.balance, total_supply)?;
+
+    store.set(&holder_key, &ownership);
+
+    // Add to holder list if new
+    let holders_list_key = TokenDataKey::TokenHoldersList(asset_id);
+    let mut holders: Vec<Address> = store
+        .get(&holders_list_key)
+        .ok_or(Error::AssetNotTokenized)?;
+
+    if !holders.contains(holder) {
+        let tokenized_asset: TokenizedAsset = store
+            .get(&TokenDataKey::TokenizedAsset(asset_id))
+            .ok_or(Error::AssetNotTokenized)?;
+        if tokenized_asset
+            .max_holders
+            .is_some_and(|cap| holders.len() >= cap)
+        {
+            return Err(Error::LimitExceeded);
+        }
+        holders.push_back(holder.clone());
+        store.set(&holders_list_key, &holders);
+        set_holder_count(env, asset_id, holders.len())?;
+    }
+
+    Ok(())
+}
+
+/// Get token balance for an address
+pub fn get_token_balance(env: &Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
+    let store = env.storage().persistent();
+    let key = TokenDataKey::TokenHolder(asset_id, holder);
+
+    match store.get::<_, OwnershipRecord>(&key) {
+        Some(ownership) => Ok(ownership.balance),
+        None => Ok(0),
+    }
+}
+
+/// Get all token holders for an asset.
+/// Unbounded: the whole list is loaded and returned, so assets with many holders
+/// should use `get_token_holders_paged` instead.
+pub fn get_token_holders(env: &Env, asset_id: u64) -> Result<Vec<Address>, Error> {
+    let store = env.storage().persistent();
+    let key = TokenDataKey::TokenHoldersList(asset_id);
+
+    store.get(&key).ok_or(Error::AssetNotTokenized)
+}
+
+/// Record every current holder's balance and return the new snapshot id (from 1).
+/// Dividends and votes can then weigh holders by the snapshot instead of live
+/// balances. Walks the whole holders list, so cost grows with the holder count.
+pub fn take_balance_snapshot(env: &Env, asset_id: u64) -> Result<u64, Error> {
+    let store = env.storage().persistent();
+    let holders = get_token_holders(env, asset_id)?;
+
+    let mut balances: Map<Address, i128> = Map::new(env);
+    for holder in holders.iter() {
+        let balance = get_token_balance(env, asset_id, holder.clone())?;
+        balances.set(holder, balance);
+    }
+
+    let count_key = TokenDataKey::SnapshotCount(asset_id);
+    let snapshot_id: u64 = store.get(&count_key).unwrap_or(0) + 1;
+    store.set(&count_key, &snapshot_id);
+    store.set(&TokenDataKey::Snapshot(asset_id, snapshot_id), &balances);
+
+    env.events().publish(
+        ("token", "snapshot_taken"),
+        (asset_id, snapshot_id, balances.len()),
+    );
+
+    Ok(snapshot_id)
+}
+
+/// A holder's balance as recorded in a snapshot; 0 if they held nothing then or
+/// the snapshot does not exist
+pub fn get_snapshot_balance(env: &Env, asset_id: u64, snapshot_id: u64, holder: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get::<_, Map<Address, i128>>(&TokenDataKey::Snapshot(asset_id, snapshot_id))
+        .and_then(|balances| balances.get(holder))
+        .unwrap_or(0)
+}
+
+/// Maximum number of holders returned by a single `get_token_holders_paged` call
+pub const MAX_HOLDERS_PAGE: u32 = 100;
+
+/// Get holders `[start, start + limit)` of the holders list (limit capped at
+/// `MAX_HOLDERS_PAGE`); empty once `start` is past the end
+pub fn get_token_holders_paged(env: &Env, asset_id: u64, start: u32, limit: u32) -> Vec<Address> {
+    let holders: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&TokenDataKey::TokenHoldersList(asset_id))
+        .unwrap_or_else(|| Vec::new(env));
+
+    if start >= holders.len() {
+        return Vec::new(env);
+    }
+    let end = start
+        .saturating_add(limit.min(MAX_HOLDERS_PAGE))
+        .min(holders.len());
+    holders.slice(start..end)
+}
+
+/// Lock tokens until a specific timestamp.
+/// Only the tokenizer or the asset's lock authority can lock a holder's tokens.
+pub fn lock_tokens(
+    env: &Env,
+    asset_id: u64,
+    holder: Address,
+    until_timestamp: u64,
+    caller: Address,
+) -> Result<(), Error> {
+    // Verify asset is tokenized
+    let store = env.storage().persistent();
+    let key = TokenDataKey::TokenizedAsset(asset_id);
+    let _: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+
+    // Only tokenizer or lock authority can lock
+    let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+    let lock_authority: Option<Address> = store.get(&TokenDataKey::LockAuthority(asset_id));
+
+    if tokenized_asset.tokenizer != caller && lock_authority != Some(caller) {
+        return Err(Error::Unauthorized);
+    }
+
+    let lock_key = TokenDataKey::TokenLockedUntil(asset_id, holder.clone());
+    store.set(&lock_key, &until_timestamp);
+
+    // Emit event: (asset_id, holder, until_timestamp)
+    env.events().publish(
+        ("token", "tokens_locked"),
+        (asset_id, holder, until_timestamp),
+    );
+
+    Ok(())
+}
+
+/// Unlock tokens (remove lock)
+pub fn unlock_tokens(env: &Env, asset_id: u64, holder: Address) -> Result<(), Error> {
+    let store = env.storage().persistent();
+
+    // Verify asset is tokenized
+    let key = TokenDataKey::TokenizedAsset(asset_id);
+    let _: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+
+    let lock_key = TokenDataKey::TokenLockedUntil(asset_id, holder.clone());
+
+    // Remove lock record
+    if store.has(&lock_key) {
+        store.remove(&lock_key);
+    }
+
+    // Emit event: (asset_id, holder)
+    env.events()
+        .publish(("token", "tokens_unlocked"), (asset_id, holder));
+
+    Ok(())
+}
+
+/// Returns true if the holder's tokens are currently locked (lock timestamp is in the future).
+pub fn is_tokens_locked(env: &Env, asset_id: u64, holder: Address) -> bool {
+    let store = env.storage().persistent();
+    let lock_key = TokenDataKey::TokenLockedUntil(asset_id, holder);
+    match store.get::<_, u64>(&lock_key) {
+        Some(lock_until) => env.ledger().timestamp() < lock_until,
+        None => false,
+    }
+}
+
+/// Seconds until the holder's tokens unlock; zero when expired or no lock exists.
+pub fn lock_remaining_secs(env: &Env, asset_id: u64, holder: Address) -> u64 {
+    let store = env.storage().persistent();
+    let lock_key = TokenDataKey::TokenLockedUntil(asset_id, holder);
+    match store.get::<_, u64>(&lock_key) {
+        Some(lock_until) => lock_until.saturating_sub(env.ledger().timestamp()),
+        None => 0,
+    }
+}
+
+/// Commit part of the holder's own balance until `until`. Unlike tokenizer locks a
+/// self-lock cannot be removed, and a new one may only extend an active commitment.
+pub fn self_lock_tokens(
+    env: &Env,
+    asset_id: u64,
+    holder: Address,
+    amount: i128,
+    until: u64,
+) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if until <= env.ledger().timestamp() {
+        return Err(Error::InvalidTimestamps);
+    }
+
+    let store = env.storage().persistent();
+    let _: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    if get_token_balance(env, asset_id, holder.clone())? < amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    if let Some(current) = get_self_lock(env, asset_id, holder.clone()) {
+        if amount < current.amount || until < current.until {
+            return Err(Error::TokensAreLocked);
+        }
+    }
+
+    store.set(
+        &TokenDataKey::SelfLock(asset_id, holder.clone()),
+        &SelfLock { amount, until },
+    );
+
+    env.events()
+        .publish(("token", "self_locked"), (asset_id, holder, amount, until));
+
+    Ok(())
+}
+
+/// The holder's self-lock, if one is still in effect
+pub fn get_self_lock(env: &Env, asset_id: u64, holder: Address) -> Option<SelfLock> {
+    let lock: SelfLock = env
+        .storage()
+        .persistent()
+        .get(&TokenDataKey::SelfLock(asset_id, holder))?;
+    if env.ledger().timestamp() < lock.until {
+        Some(lock)
+    } else {
+        None
+    }
+}
+
+/// Put `total_amount` of a holder's tokens on a linear vesting schedule. `cliff` and
+/// `duration` are seconds after `start`. Only the tokenizer or the asset's lock
+/// authority can set a schedule; a new schedule replaces the previous one.
+#[allow(clippy::too_many_arguments)]
+pub fn set_vesting_schedule(
+    env: &Env,
+    asset_id: u64,
+    holder: Address,
+    total_amount: i128,
+    start: u64,
+    cliff: u64,
+    duration: u64,
+    caller: Address,
+) -> Result<(), Error> {
+    if total_amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if duration == 0 || cliff > duration {
+        return Err(Error::InvalidTimestamps);
+    }
+
+    let store = env.storage().persistent();
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+    let lock_authority: Option<Address> = store.get(&TokenDataKey::LockAuthority(asset_id));
+    if tokenized_asset.tokenizer != caller && lock_authority != Some(caller) {
+        return Err(Error::Unauthorized);
+    }
+
+    if get_token_balance(env, asset_id, holder.clone())? < total_amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    store.set(
+        &TokenDataKey::Vesting(asset_id, holder.clone()),
+        &VestingSchedule {
+            total_amount,
+            start,
+            cliff,
+            duration,
+        },
+    );
+
+    env.events().publish(
+        ("token", "vesting_set"),
+        (asset_id, holder, total_amount, start, duration),
+    );
+
+    Ok(())
+}
+
+/// Get a holder's vesting schedule, if any
+pub fn get_vesting_schedule(env: &Env, asset_id: u64, holder: Address) -> Option<VestingSchedule> {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::Vesting(asset_id, holder))
+}
+
+/// Tokens released so far under the holder's vesting schedule (0 without one)
+pub fn vested_amount(env: &Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
+    let schedule = match get_vesting_schedule(env, asset_id, holder) {
+        Some(s) => s,
+        None => return Ok(0),
+    };
+
+    let now = env.ledger().timestamp();
+    if now < schedule.start.saturating_add(schedule.cliff) {
+        return Ok(0);
+    }
+    let elapsed = now - schedule.start;
+    if elapsed >= schedule.duration {
+        return Ok(schedule.total_amount);
+    }
+    Ok(schedule
+        .total_amount
+        .checked_mul(elapsed as i128)
+        .ok_or(Error::ArithmeticOverflow)?
+        / schedule.duration as i128)
+}
+
+/// Tokens the holder cannot currently move: the whole balance under a tokenizer
+/// lock, otherwise the larger of any active self-lock and the still-unvested amount
+pub fn locked_balance(env: &Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
+    let balance = get_token_balance(env, asset_id, holder.clone()).unwrap_or(0);
+    if is_tokens_locked(env, asset_id, holder.clone()) {
+        return Ok(balance);
+    }
+    let self_locked = get_self_lock(env, asset_id, holder.clone()).map_or(0, |lock| lock.amount);
+    let unvested = unvested_amount(env, asset_id, holder)?;
+    Ok(self_locked.max(unvested).min(balance))
+}
+
+/// Tokens still held back by the holder's vesting schedule (0 without one)
+pub(crate) fn unvested_amount(env: &Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
+    match get_vesting_schedule(env, asset_id, holder.clone()) {
+        Some(schedule) => Ok(schedule.total_amount - vested_amount(env, asset_id, holder)?),
+        None => Ok(0),
+    }
+}
+
+/// Fail with `TokensAreLocked` if moving `amount` would dip into locked tokens
+pub(crate) fn check_unlocked(
+    env: &Env,
+    asset_id: u64,
+    holder: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    let locked = locked_balance(env, asset_id, holder.clone())?;
+    if locked == 0 {
+        return Ok(());
+    }
+    let balance = get_token_balance(env, asset_id, holder.clone())?;
+    if balance - amount < locked {
+        return Err(Error::TokensAreLocked);
+    }
+    Ok(())
+}
+
+/// Calculate ownership percentage for a holder (in basis points)
+pub fn calculate_ownership_percentage(
+    env: &Env,
+    asset_id: u64,
+    holder: Address,
+) -> Result<i128, Error> {
+    let store = env.storage().persistent();
+
+    // Get asset
+    let key = TokenDataKey::TokenizedAsset(asset_id);
+    let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+
+    // Get holder balance
+    let holder_key = TokenDataKey::TokenHolder(asset_id, holder);
+    let ownership: OwnershipRecord = store.get(&holder_key).ok_or(Error::HolderNotFound)?;
+
+    // Calculate percentage: (balance / total_supply) * 10000
+    percentage_of(ownership.balance, tokenized_asset.total_supply)
+}
+
+/// Ownership percentage in basis points computed from the holder's current balance
+/// and the current total supply, ignoring the stored `ownership_percentage`.
+/// Addresses holding no tokens get zero.
+pub fn live_ownership_percentage(env: &Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
+    let tokenized_asset = get_tokenized_asset(env, asset_id)?;
+    let balance = get_token_balance(env, asset_id, holder)?;
+    percentage_of(balance, tokenized_asset.total_supply)
+}
+
+/// Maximum number of holders processed by a single `recompute_percentages` call
+pub const MAX_RECOMPUTE_BATCH: u32 = 100;
+
+/// Recompute stored ownership percentages for a page of holders.
+/// Processes holders `[start, start + limit)` of the holders list (limit capped at
+/// `MAX_RECOMPUTE_BATCH`) and returns how many records were updated, so callers can
+/// loop until it returns 0.
+pub fn recompute_percentages(
+    env: &Env,
+    asset_id: u64,
+    start: u32,
+    limit: u32,
+) -> Result<u32, Error> {
+    let store = env.storage().persistent();
+
+    let key = TokenDataKey::TokenizedAsset(asset_id);
+    let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+
+    let holders_list_key = TokenDataKey::TokenHoldersList(asset_id);
+    let holders: Vec<Address> = store
+        .get(&holders_list_key)
+        .ok_or(Error::AssetNotTokenized)?;
+
+    let end = start
+        .saturating_add(limit.min(MAX_RECOMPUTE_BATCH))
+        .min(holders.len());
+
+    let mut updated: u32 = 0;
+    for i in start..end {
+        let holder = holders.get_unchecked(i);
+        if refresh_ownership_percentage(env, asset_id, holder, tokenized_asset.total_supply)? {
+            updated += 1;
+        }
+    }
+
+    Ok(updated)
+}
+
+/// Rewrite every holder's stored percentage against the current total supply.
+/// Called after mint and burn, which change the supply under all holders at once.
+fn recalculate_all_ownership_percentages(env: &Env, asset_id: u64) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+    let holders: Vec<Address> = store
+        .get(&TokenDataKey::TokenHoldersList(asset_id))
+        .unwrap_or_else(|| Vec::new(env));
+
+    for holder in holders.iter() {
+        refresh_ownership_percentage(env, asset_id, holder, tokenized_asset.total_supply)?;
+    }
+
+    Ok(())
+}
+
+/// Recompute one holder's stored percentage; returns false if they have no record
+fn refresh_ownership_percentage(
+    env: &Env,
+    asset_id: u64,
+    holder: Address,
+    total_supply: i128,
+) -> Result<bool, Error> {
+    let store = env.storage().persistent();
+    let holder_key = TokenDataKey::TokenHolder(asset_id, holder);
+    match store.get::<_, OwnershipRecord>(&holder_key) {
+        Some(mut ownership) => {
+            ownership.ownership_percentage = percentage_of(ownership.balance, total_supply)?;
+            store.set(&holder_key, &ownership);
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Holder sets up to this size are normalized automatically on every transfer
+pub const AUTO_NORMALIZE_MAX_HOLDERS: u32 = 20;
+
+/// Re-derive every holder's ownership percentage so they sum to exactly 10000 basis
+/// points, using the largest-remainder method over current holder balances.
+///
+/// `transfer_tokens` calls this automatically while the asset has at most
+/// `AUTO_NORMALIZE_MAX_HOLDERS` holders. Above that, stored percentages are advisory
+/// (each is computed independently and may not sum to 10000) until this is called.
+pub fn normalize_percentages(env: &Env, asset_id: u64) -> Result<(), Error> {
+    let store = env.storage().persistent();
+
+    let holders: Vec<Address> = store
+        .get(&TokenDataKey::TokenHoldersList(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    let mut records: Vec<OwnershipRecord> = Vec::new(env);
+    let mut total: i128 = 0;
+    for holder in holders.iter() {
+        if let Some(ownership) =
+            store.get::<_, OwnershipRecord>(&TokenDataKey::TokenHolder(asset_id, holder))
+        {
+            total = total
+                .checked_add(ownership.balance)
+                .ok_or(Error::ArithmeticOverflow)?;
+            records.push_back(ownership);
+        }
+    }
+
+    // Floor shares first, keeping the remainders to hand out the leftover points
+    let mut remainders: Vec<i128> = Vec::new(env);
+    let mut assigned: i128 = 0;
+    for i in 0..records.len() {
+        let mut ownership = records.get_unchecked(i);
+        let (share, remainder) = if total > 0 {
+            let scaled = ownership
+                .balance
+                .checked_mul(10000)
+                .ok_or(Error::ArithmeticOverflow)?;
+            (scaled / total, scaled % total)
+        } else {
+            (0, 0)
+        };
+        ownership.ownership_percentage = share;
+        assigned += share;
+        records.set(i, ownership);
+        remainders.push_back(remainder);
+    }
+
+    let mut leftover = if total > 0 { 10000 - assigned } else { 0 };
+    while leftover > 0 {
+        // Largest remainder wins; ties go to the earlier holder
+        let mut best: u32 = 0;
+        for i in 1..remainders.len() {
+            if remainders.get_unchecked(i) > remainders.get_unchecked(best) {
+                best = i;
+            }
+        }
+        let mut ownership = records.get_unchecked(best);
+        ownership.ownership_percentage += 1;
+        records.set(best, ownership);
+        remainders.set(best, -1);
+        leftover -= 1;
+    }
+
+    for ownership in records.iter() {
+        store.set(
+            &TokenDataKey::TokenHolder(asset_id, ownership.owner.clone()),
+            &ownership,
+        );
+    }
+
+    Ok(())
+}
+
+/// Commit to a maximum mint per time window (only tokenizer can call).
+/// Progress in the current window is kept when the limit is changed.
+pub fn set_mint_rate_limit(
+    env: &Env,
+    asset_id: u64,
+    max_per_window: i128,
+    window_secs: u64,
+    caller: Address,
+) -> Result<(), Error> {
+    if max_per_window <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if window_secs == 0 {
+        return Err(Error::InvalidTimestamps);
+    }
+
+    let store = env.storage().persistent();
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    if tokenized_asset.tokenizer != caller {
+        return Err(Error::Unauthorized);
+    }
+
+    let key = TokenDataKey::MintRateLimit(asset_id);
+    let (window_start, minted_in_window) = match store.get::<_, MintRateLimit>(&key) {
+        Some(existing) => (existing.window_start, existing.minted_in_window),
+        None => (env.ledger().timestamp(), 0),
+    };
+
+    store.set(
+        &key,
+        &MintRateLimit {
+            max_per_window,
+            window_secs,
+            window_start,
+            minted_in_window,
+        },
+    );
+
+    env.events().publish(
+        ("token", "mint_rate_limit_set"),
+        (asset_id, max_per_window, window_secs),
+    );
+
+    Ok(())
+}
+
+/// Get the mint rate limit for an asset, if one is set
+pub fn get_mint_rate_limit(env: &Env, asset_id: u64) -> Option<MintRateLimit> {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::MintRateLimit(asset_id))
+}
+
+/// Count `amount` against the current mint window, starting a new window once the
+/// previous one has elapsed.
+fn consume_mint_allowance(env: &Env, asset_id: u64, amount: i128) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let key = TokenDataKey::MintRateLimit(asset_id);
+    let mut limit: MintRateLimit = match store.get(&key) {
+        Some(limit) => limit,
+        None => return Ok(()),
+    };
+
+    let now = env.ledger().timestamp();
+    if now >= limit.window_start.saturating_add(limit.window_secs) {
+        limit.window_start = now;
+        limit.minted_in_window = 0;
+    }
+
+    let minted = limit
+        .minted_in_window
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticOverflow)?;
+    if minted > limit.max_per_window {
+        return Err(Error::LimitExceeded);
+    }
+
+    limit.minted_in_window = minted;
+    store.set(&key, &limit);
+    Ok(())
+}
+
+/// Cap how many tokens `holder` may transfer out per time window (only
+/// tokenizer can call). Progress in the current window is kept when the limit
+/// is changed.
+pub fn set_transfer_limit(
+    env: &Env,
+    asset_id: u64,
+    holder: Address,
+    max_per_window: i128,
+    window_secs: u64,
+    caller: Address,
+) -> Result<(), Error> {
+    if max_per_window <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if window_secs == 0 {
+        return Err(Error::InvalidTimestamps);
+    }
+    require_tokenizer(env, asset_id, &caller)?;
+
+    let store = env.storage().persistent();
+    let key = TokenDataKey::TransferLimit(asset_id, holder.clone());
+    let (window_start, transferred_in_window) = match store.get::<_, TransferLimit>(&key) {
+        Some(existing) => (existing.window_start, existing.transferred_in_window),
+        None => (env.ledger().timestamp(), 0),
+    };
+
+    store.set(
+        &key,
+        &TransferLimit {
+            max_per_window,
+            window_secs,
+            window_start,
+            transferred_in_window,
+        },
+    );
+
+    env.events().publish(
+        ("token", "transfer_limit_set"),
+        (asset_id, holder, max_per_window, window_secs),
+    );
+
+    Ok(())
+}
+
+/// Get a holder's transfer limit, if one is set
+pub fn get_transfer_limit(env: &Env, asset_id: u64, holder: Address) -> Option<TransferLimit> {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::TransferLimit(asset_id, holder))
+}
+
+/// Count `amount` against the holder's current transfer window, starting a new
+/// window once the previous one has elapsed.
+pub(crate) fn consume_transfer_allowance(
+    env: &Env,
+    asset_id: u64,
+    holder: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    if let Some(limit) = charge_transfer_window(env, asset_id, holder, amount)? {
+        env.storage().persistent().set(
+            &TokenDataKey::TransferLimit(asset_id, holder.clone()),
+            &limit,
+        );
+    }
+    Ok(())
+}
+
+/// The holder's transfer limit with `amount` counted against the current window,
+/// without storing it; None when the holder has no limit
+pub(crate) fn charge_transfer_window(
+    env: &Env,
+    asset_id: u64,
+    holder: &Address,
+    amount: i128,
+) -> Result<Option<TransferLimit>, Error> {
+    let mut limit: TransferLimit = match env
+        .storage()
+        .persistent()
+        .get(&TokenDataKey::TransferLimit(asset_id, holder.clone()))
+    {
+        Some(limit) => limit,
+        None => return Ok(None),
+    };
+
+    let now = env.ledger().timestamp();
+    if now >= limit.window_start.saturating_add(limit.window_secs) {
+        limit.window_start = now;
+        limit.transferred_in_window = 0;
+    }
+
+    let transferred = limit
+        .transferred_in_window
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticOverflow)?;
+    if transferred > limit.max_per_window {
+        return Err(Error::LimitExceeded);
+    }
+
+    limit.transferred_in_window = transferred;
+    Ok(Some(limit))
+}
+
+/// Passes for the asset's tokenizer or a holder of its `OPERATOR` role
+pub(crate) fn require_tokenizer(env: &Env, asset_id: u64, caller: &Address) -> Result<(), Error> {
+    let tokenized_asset: TokenizedAsset = env
+        .storage()
+        .persistent()
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+    if tokenized_asset.tokenizer != *caller
+        && !roles::has_scoped_role(
+            env,
+            roles::OPERATOR,
+            roles::RoleScope::Asset(asset_id),
+            caller.clone(),
+        )
+    {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Set or clear the appraiser for an asset (only tokenizer can call)
+pub fn set_appraiser(
+    env: &Env,
+    asset_id: u64,
+    appraiser: Option<Address>,
+    caller: Address,
+) -> Result<(), Error> {
+    require_tokenizer(env, asset_id, &caller)?;
+    set_optional_role(env, TokenDataKey::Appraiser(asset_id), appraiser.clone());
+
+    env.events()
+        .publish(("token", "appraiser_set"), (asset_id, appraiser));
+
+    Ok(())
+}
+
+/// Set or clear the lock authority for an asset (only tokenizer can call)
+pub fn set_lock_authority(
+    env: &Env,
+    asset_id: u64,
+    authority: Option<Address>,
+    caller: Address,
+) -> Result<(), Error> {
+    require_tokenizer(env, asset_id, &caller)?;
+    set_optional_role(
+        env,
+        TokenDataKey::LockAuthority(asset_id),
+        authority.clone(),
+    );
+
+    env.events()
+        .publish(("token", "lock_authority_set"), (asset_id, authority));
+
+    Ok(())
+}
+
+/// Replace the treasury addresses for an asset (only tokenizer can call)
+pub fn set_treasury_addresses(
+    env: &Env,
+    asset_id: u64,
+    treasury: Vec<Address>,
+    caller: Address,
+) -> Result<(), Error> {
+    require_tokenizer(env, asset_id, &caller)?;
+    env.storage()
+        .persistent()
+        .set(&TokenDataKey::Treasury(asset_id), &treasury);
+
+    env.events()
+        .publish(("token", "treasury_set"), (asset_id, treasury.len()));
+
+    Ok(())
+}
+
+fn set_optional_role(env: &Env, key: TokenDataKey, addr: Option<Address>) {
+    let store = env.storage().persistent();
+    match addr {
+        Some(addr) => store.set(&key, &addr),
+        None => store.remove(&key),
+    }
+}
+
+/// Put an asset into (or out of) exit-only mode for wind-down (only tokenizer can call).
+/// While set, tokens may only be transferred to the asset's treasury addresses.
+pub fn set_exit_only(
+    env: &Env,
+    asset_id: u64,
+    exit_only: bool,
+    caller: Address,
+) -> Result<(), Error> {
+    require_tokenizer(env, asset_id, &caller)?;
+    env.storage()
+        .persistent()
+        .set(&TokenDataKey::ExitOnly(asset_id), &exit_only);
+
+    env.events()
+        .publish(("token", "exit_only_set"), (asset_id, exit_only));
+
+    Ok(())
+}
+
+/// Route future burns to `burn_address` instead of reducing total supply (only
+/// tokenizer can call). The address must hold no tokens; it and every earlier
+/// burn address can never transfer tokens out.
+pub fn set_burn_address(
+    env: &Env,
+    asset_id: u64,
+    burn_address: Address,
+    caller: Address,
+) -> Result<(), Error> {
+    require_tokenizer(env, asset_id, &caller)?;
+    if get_token_balance(env, asset_id, burn_address.clone())? > 0 {
+        return Err(Error::Unauthorized);
+    }
+
+    let store = env.storage().persistent();
+    let key = TokenDataKey::BurnAddresses(asset_id);
+    let mut burn_addresses: Vec<Address> = store.get(&key).unwrap_or_else(|| Vec::new(env));
+    if let Some(index) = burn_addresses.first_index_of(&burn_address) {
+        burn_addresses.remove(index);
+    }
+    burn_addresses.push_back(burn_address.clone());
+    store.set(&key, &burn_addresses);
+
+    env.events()
+        .publish(("token", "burn_address_set"), (asset_id, burn_address));
+
+    Ok(())
+}
+
+/// Get the active burn address, if burns are routed to one
+pub fn get_burn_address(env: &Env, asset_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get::<_, Vec<Address>>(&TokenDataKey::BurnAddresses(asset_id))
+        .and_then(|burn_addresses| burn_addresses.last())
+}
+
+/// Get the total tokens burned for an asset, in either burn mode
+pub fn get_burned_total(env: &Env, asset_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::BurnedTotal(asset_id))
+        .unwrap_or(0)
+}
+
+/// Diagnostic check that the supply counters agree with holder balances. Every
+/// token sits in a holder balance, a pending escrow or the reflection pool; those
+/// outside burn addresses must add up to `tokens_in_circulation`, and all of them
+/// to `total_supply - locked_tokens`.
+pub fn verify_supply_invariant(env: &Env, asset_id: u64) -> Result<bool, Error> {
+    let store = env.storage().persistent();
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+    let holders: Vec<Address> = store
+        .get(&TokenDataKey::TokenHoldersList(asset_id))
+        .unwrap_or_else(|| Vec::new(env));
+
+    let mut circulating = escrow::escrowed_amount(env, asset_id)
+        .checked_add(get_reflection_pool(env, asset_id))
+        .ok_or(Error::ArithmeticOverflow)?;
+    let mut parked: i128 = 0;
+    for holder in holders.iter() {
+        let balance = store
+            .get::<_, OwnershipRecord>(&TokenDataKey::TokenHolder(asset_id, holder.clone()))
+            .map(|ownership| ownership.balance)
+            .unwrap_or(0);
+        if is_burn_address(env, asset_id, &holder) {
+            parked = parked
+                .checked_add(balance)
+                .ok_or(Error::ArithmeticOverflow)?;
+        } else {
+            circulating = circulating
+                .checked_add(balance)
+                .ok_or(Error::ArithmeticOverflow)?;
+        }
+    }
+
+    Ok(circulating == tokenized_asset.tokens_in_circulation
+        && circulating + parked == tokenized_asset.total_supply - tokenized_asset.locked_tokens)
+}
+
+/// Whether `address` is a current or former burn address of the asset
+pub(crate) fn is_burn_address(env: &Env, asset_id: u64, address: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get::<_, Vec<Address>>(&TokenDataKey::BurnAddresses(asset_id))
+        .is_some_and(|burn_addresses| burn_addresses.contains(address))
+}
+
+/// Reject moving tokens out of a current or former burn address
+pub(crate) fn check_not_burn_address(
+    env: &Env,
+    asset_id: u64,
+    from: &Address,
+) -> Result<(), Error> {
+    if is_burn_address(env, asset_id, from) {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Stop further minting for an asset without affecting transfers or burns (only
+/// tokenizer can call)
+pub fn pause_minting(env: &Env, asset_id: u64, caller: Address) -> Result<(), Error> {
+    set_minting_paused(env, asset_id, true, caller)
+}
+
+/// Allow minting again after `pause_minting` (only tokenizer can call)
+pub fn resume_minting(env: &Env, asset_id: u64, caller: Address) -> Result<(), Error> {
+    set_minting_paused(env, asset_id, false, caller)
+}
+
+fn set_minting_paused(
+    env: &Env,
+    asset_id: u64,
+    paused: bool,
+    caller: Address,
+) -> Result<(), Error> {
+    require_tokenizer(env, asset_id, &caller)?;
+    env.storage()
+        .persistent()
+        .set(&TokenDataKey::MintingPaused(asset_id), &paused);
+
+    env.events()
+        .publish(("token", "minting_paused_set"), (asset_id, paused));
+
+    Ok(())
+}
+
+/// Returns true if minting is paused for the asset
+pub fn is_minting_paused(env: &Env, asset_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::MintingPaused(asset_id))
+        .unwrap_or(false)
+}
+
+/// Set the reflection tax charged on transfers, in basis points (only tokenizer
+/// can call). Zero disables it.
+pub fn set_reflection_tax(
+    env: &Env,
+    asset_id: u64,
+    tax_bps: u32,
+    caller: Address,
+) -> Result<(), Error> {
+    if tax_bps > 10000 {
+        return Err(Error::InvalidAmount);
+    }
+    require_tokenizer(env, asset_id, &caller)?;
+    env.storage()
+        .persistent()
+        .set(&TokenDataKey::ReflectionTax(asset_id), &tax_bps);
+
+    env.events()
+        .publish(("token", "reflection_tax_set"), (asset_id, tax_bps));
+
+    Ok(())
+}
+
+/// Get the reflection tax rate in basis points (0 when unset)
+pub fn get_reflection_tax(env: &Env, asset_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::ReflectionTax(asset_id))
+        .unwrap_or(0)
+}
+
+/// Tokens withheld as reflection tax, held outside any holder balance
+pub fn get_reflection_pool(env: &Env, asset_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::ReflectionPool(asset_id))
+        .unwrap_or(0)
+}
+
+/// Accrue the reflection tax on a transfer pro rata by balance into the
+/// `unclaimed_dividends` of every holder other than the sender, the recipient,
+/// burn addresses and frozen or blacklisted holders. The taxed tokens move into
+/// the asset's reflection pool, which backs those dividends, so supply is
+/// unchanged. Returns the amount actually accrued; rounding dust is not taxed,
+/// and nothing is taxed when there are no eligible holders.
+fn accrue_reflection_tax(
+    env: &Env,
+    asset_id: u64,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<i128, Error> {
+    let tax = amount
+        .checked_mul(get_reflection_tax(env, asset_id) as i128)
+        .ok_or(Error::ArithmeticOverflow)?
+        / 10000;
+    if tax == 0 {
+        return Ok(0);
+    }
+
+    let store = env.storage().persistent();
+    let holders: Vec<Address> = store
+        .get(&TokenDataKey::TokenHoldersList(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    let mut eligible = Vec::new(env);
+    let mut eligible_balance: i128 = 0;
+    for holder in holders.iter() {
+        if holder == *from
+            || holder == *to
+            || is_burn_address(env, asset_id, &holder)
+            || transfer_restrictions::is_holder_frozen(env, asset_id, &holder)
+            || transfer_restrictions::is_blacklisted(env, asset_id, holder.clone())
+        {
+            continue;
+        }
+        let holder_key = TokenDataKey::TokenHolder(asset_id, holder.clone());
+        if let Some(ownership) = store.get::<_, OwnershipRecord>(&holder_key) {
+            if ownership.balance > 0 {
+                eligible_balance = eligible_balance
+                    .checked_add(ownership.balance)
+                    .ok_or(Error::ArithmeticOverflow)?;
+                eligible.push_back((holder_key, ownership));
+            }
+        }
+    }
+    if eligible_balance == 0 {
+        return Ok(0);
+    }
+
+    let mut accrued: i128 = 0;
+    for (holder_key, mut ownership) in eligible.iter() {
+        let share = ownership
+            .balance
+            .checked_mul(tax)
+            .ok_or(Error::ArithmeticOverflow)?
+            / eligible_balance;
+        if share > 0 {
+            ownership.unclaimed_dividends = ownership
+                .unclaimed_dividends
+                .checked_add(share)
+                .ok_or(Error::ArithmeticOverflow)?;
+            store.set(&holder_key, &ownership);
+            accrued += share;
+        }
+    }
+
+    let pool = get_reflection_pool(env, asset_id)
+        .checked_add(accrued)
+        .ok_or(Error::ArithmeticOverflow)?;
+    store.set(&TokenDataKey::ReflectionPool(asset_id), &pool);
+
+    // Emit event: (asset_id, from, accrued)
+    env.events().publish(
+        ("token", "reflection_tax"),
+        (asset_id, from.clone(), accrued),
+    );
+
+    Ok(accrued)
+}
+
+/// Returns true if the asset is in exit-only mode
+pub fn is_exit_only(env: &Env, asset_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::ExitOnly(asset_id))
+        .unwrap_or(false)
+}
+
+/// In exit-only mode, reject a transfer that would add to a non-treasury balance
+pub(crate) fn check_exit_only(env: &Env, asset_id: u64, to: &Address) -> Result<(), Error> {
+    if !is_exit_only(env, asset_id) {
+        return Ok(());
+    }
+    let treasury: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&TokenDataKey::Treasury(asset_id))
+        .unwrap_or_else(|| Vec::new(env));
+    if !treasury.contains(to) {
+        return Err(Error::ExitOnlyMode);
+    }
+    Ok(())
+}
+
+/// Get every configured role holder for an asset
+pub fn get_asset_roles(env: &Env, asset_id: u64) -> Result<AssetRoles, Error> {
+    let store = env.storage().persistent();
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    Ok(AssetRoles {
+        tokenizer: tokenized_asset.tokenizer,
+        appraiser: store.get(&TokenDataKey::Appraiser(asset_id)),
+        lock_authority: store.get(&TokenDataKey::LockAuthority(asset_id)),
+        treasury: store
+            .get(&TokenDataKey::Treasury(asset_id))
+            .unwrap_or_else(|| Vec::new(env)),
+    })
+}
+
+/// Maximum holders included per export page
+pub const MAX_EXPORT_HOLDERS: u32 = 50;
+
+/// Export an asset's full state in one struct, with the first page of holders
+pub fn export_asset_state(env: &Env, asset_id: u64) -> Result<AssetStateExport, Error> {
+    let store = env.storage().persistent();
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+    let metadata: TokenMetadata = store
+        .get(&TokenDataKey::TokenMetadata(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+    let holders: Vec<Address> = store
+        .get(&TokenDataKey::TokenHoldersList(asset_id))
+        .unwrap_or_else(|| Vec::new(env));
+
+    let asset =
+        match store.get::<_, Asset>(&asset::DataKey::Asset(asset_id_to_bytes(env, asset_id))) {
+            Some(registered) => RegistryRecord::Registered(registered),
+            None => RegistryRecord::Unregistered,
+        };
+
+    Ok(AssetStateExport {
+        asset,
+        tokenized_asset,
+        metadata,
+        holder_count: holders.len(),
+        holders: export_asset_holders(env, asset_id, 0, MAX_EXPORT_HOLDERS)?,
+        roles: get_asset_roles(env, asset_id)?,
+        whitelist: store
+            .get::<_, Option<Vec<Address>>>(&TokenDataKey::Whitelist(asset_id))
+            .flatten()
+            .unwrap_or_else(|| Vec::new(env)),
+        transfer_restriction: store
+            .get::<_, Option<TransferRestriction>>(&TokenDataKey::TransferRestriction(asset_id))
+            .flatten()
+            .unwrap_or_else(|| TransferRestriction {
+                require_accredited: false,
+                geographic_allowed: Vec::new(env),
+            }),
+        exit_only: is_exit_only(env, asset_id),
+        mint_rate_limit: get_mint_rate_limit(env, asset_id).unwrap_or(MintRateLimit {
+            max_per_window: 0,
+            window_secs: 0,
+            window_start: 0,
+            minted_in_window: 0,
+        }),
+        min_voting_balance: voting::get_min_voting_balance(env, asset_id),
+        blacklist: transfer_restrictions::get_blacklist(env, asset_id),
+        burn_addresses: store
+            .get(&TokenDataKey::BurnAddresses(asset_id))
+            .unwrap_or_else(|| Vec::new(env)),
+        burned_total: get_burned_total(env, asset_id),
+        reflection_tax_bps: get_reflection_tax(env, asset_id),
+        reflection_pool: get_reflection_pool(env, asset_id),
+        minting_paused: is_minting_paused(env, asset_id),
+    })
+}
+
+/// Export holders `[start, start + limit)` of the holders list with their lock,
+/// freeze and vesting records (limit capped at `MAX_EXPORT_HOLDERS`)
+pub fn export_asset_holders(
+    env: &Env,
+    asset_id: u64,
+    start: u32,
+    limit: u32,
+) -> Result<Vec<HolderExport>, Error> {
+    let store = env.storage().persistent();
+    let holders: Vec<Address> = store
+        .get(&TokenDataKey::TokenHoldersList(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    let end = start
+        .saturating_add(limit.min(MAX_EXPORT_HOLDERS))
+        .min(holders.len());
+    let mut exported = Vec::new(env);
+    for i in start..end {
+        let holder = holders.get(i).unwrap();
+        if let Some(record) =
+            store.get::<_, OwnershipRecord>(&TokenDataKey::TokenHolder(asset_id, holder.clone()))
+        {
+            exported.push_back(HolderExport {
+                record,
+                locked_until: store.get(&TokenDataKey::TokenLockedUntil(asset_id, holder.clone())),
+                frozen: transfer_restrictions::is_holder_frozen(env, asset_id, &holder),
+                vesting: get_vesting_schedule(env, asset_id, holder.clone()).unwrap_or(
+                    VestingSchedule {
+                        total_amount: 0,
+                        start: 0,
+                        cliff: 0,
+                        duration: 0,
+                    },
+                ),
+                self_lock: get_self_lock(env, asset_id, holder).unwrap_or(SelfLock {
+                    amount: 0,
+                    until: 0,
+                }),
+            });
+        }
+    }
+    Ok(exported)
+}
+
+/// Get tokenized asset details
+pub fn get_tokenized_asset(env: &Env, asset_id: u64) -> Result<TokenizedAsset, Error> {
+    let store = env.storage().persistent();
+    let key = TokenDataKey::TokenizedAsset(asset_id);
+
+    store.get(&key).ok_or(Error::AssetNotTokenized)
+}
+
+/// Get token metadata
+#[allow(dead_code)]
+pub fn get_token_metadata(env: &Env, asset_id: u64) -> Result<TokenMetadata, Error> {
+    let store = env.storage().persistent();
+    let key = TokenDataKey::TokenMetadata(asset_id);
+
+    store.get(&key).ok_or(Error::AssetNotTokenized)
+}
+
+/// Most recent valuation updates kept in an asset's history
+pub const MAX_VALUATION_HISTORY: u32 = 50;
+
+/// Update asset valuation, appending it to the asset's valuation history
+pub fn update_valuation(env: &Env, asset_id: u64, new_valuation: i128) -> Result<(), Error> {
+    if new_valuation <= 0 {
+        return Err(Error::InvalidValuation);
+    }
+
+    let store = env.storage().persistent();
+    let key = TokenDataKey::TokenizedAsset(asset_id);
 
     let mut tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
 
+    adjust_total_value_locked(env, new_valuation - tokenized_asset.valuation)?;
     tokenized_asset.valuation = new_valuation;
     store.set(&key, &tokenized_asset);
+    record_valuation(env, asset_id, new_valuation);
 
     // Emit event: (asset_id, new_valuation)
     env.events()
@@ -473,3 +2079,94 @@ pub fn update_valuation(env: &Env, asset_id: u64, new_valuation: i128) -> Result
 
     Ok(())
 }
+
+/// Append a valuation to the asset's history at the current timestamp, dropping
+/// the oldest entries beyond `MAX_VALUATION_HISTORY`
+fn record_valuation(env: &Env, asset_id: u64, valuation: i128) {
+    let store = env.storage().persistent();
+    let history_key = TokenDataKey::ValuationHistory(asset_id);
+    let mut history: Vec<(u64, i128)> = store.get(&history_key).unwrap_or_else(|| Vec::new(env));
+    history.push_back((env.ledger().timestamp(), valuation));
+    while history.len() > MAX_VALUATION_HISTORY {
+        history.pop_front();
+    }
+    store.set(&history_key, &history);
+}
+
+/// Get the recorded `(timestamp, valuation)` updates for an asset, oldest first.
+/// Only the most recent `MAX_VALUATION_HISTORY` updates are kept.
+pub fn get_valuation_history(env: &Env, asset_id: u64) -> Vec<(u64, i128)> {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::ValuationHistory(asset_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Get the valuation in effect at `timestamp`: the latest valuation recorded at
+/// or before it (tokenization records the first), or zero if the earliest
+/// retained entry is later
+pub fn valuation_at(env: &Env, asset_id: u64, timestamp: u64) -> i128 {
+    let history = get_valuation_history(env, asset_id);
+
+    for i in (0..history.len()).rev() {
+        let (recorded_at, valuation) = history.get(i).unwrap();
+        if recorded_at <= timestamp {
+            return valuation;
+        }
+    }
+    0
+}
+
+/// Total value locked: the valuations of all tokenized assets plus every insurance
+/// claim pool balance. This is a running total rather than a scan; it is adjusted
+/// by each valuation change (tokenize, `update_valuation`, detokenize) and each pool
+/// credit or debit (premiums, pool funding, claim payouts).
+pub fn total_value_locked(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::TotalValueLocked)
+        .unwrap_or(0)
+}
+
+/// Apply a change to the running total value locked
+pub(crate) fn adjust_total_value_locked(env: &Env, delta: i128) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let total: i128 = store.get(&TokenDataKey::TotalValueLocked).unwrap_or(0);
+    let total = total.checked_add(delta).ok_or(Error::ArithmeticOverflow)?;
+    store.set(&TokenDataKey::TotalValueLocked, &total);
+    Ok(())
+}
+
+/// Turn global token symbol uniqueness on or off (authorization checked by caller)
+pub fn set_enforce_unique_symbols(env: &Env, enforce: bool) {
+    env.storage()
+        .persistent()
+        .set(&TokenDataKey::EnforceUniqueSymbols, &enforce);
+
+    env.events()
+        .publish(("token", "unique_symbols_set"), enforce);
+}
+
+/// Returns true if tokenization rejects symbols already held by another asset
+pub fn is_enforce_unique_symbols(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::EnforceUniqueSymbols)
+        .unwrap_or(false)
+}
+
+/// Get the asset currently holding a token symbol
+pub fn get_symbol_asset(env: &Env, symbol: String) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::SymbolIndex(symbol))
+}
+
+/// Free a symbol from the index if it is held by `asset_id`
+pub(crate) fn release_symbol(env: &Env, asset_id: u64, symbol: String) {
+    let store = env.storage().persistent();
+    let symbol_key = TokenDataKey::SymbolIndex(symbol);
+    if store.get::<_, u64>(&symbol_key) == Some(asset_id) {
+        store.remove(&symbol_key);
+    }
+}
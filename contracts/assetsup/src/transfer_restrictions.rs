@@ -1,5 +1,7 @@
+use crate::dividends;
 use crate::error::Error;
-use crate::types::{TokenDataKey, TransferRestriction};
+use crate::tokenization;
+use crate::types::{TokenDataKey, TokenizedAsset, TransferDecision, TransferRestriction};
 use soroban_sdk::{Address, Env, Vec};
 
 /// Set transfer restrictions for an asset
@@ -83,15 +85,213 @@ pub fn get_whitelist(env: &Env, asset_id: u64) -> Result<Vec<Address>, Error> {
     Ok(store.get(&key).flatten().unwrap_or_else(|| Vec::new(env)))
 }
 
+/// Block an address from sending or receiving the asset's tokens, whether or
+/// not a whitelist is active (requires the tokenizer's authorization)
+pub fn add_to_blacklist(env: &Env, asset_id: u64, address: Address) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+    tokenized_asset.tokenizer.require_auth();
+
+    let mut blacklist = get_blacklist(env, asset_id);
+    if blacklist.contains(&address) {
+        return Ok(());
+    }
+
+    blacklist.push_back(address.clone());
+    store.set(&TokenDataKey::Blacklist(asset_id), &blacklist);
+
+    // Emit event: (asset_id, address)
+    env.events()
+        .publish(("transfer", "blacklist_added"), (asset_id, address));
+
+    Ok(())
+}
+
+/// Remove an address from the blacklist (requires the tokenizer's authorization)
+pub fn remove_from_blacklist(env: &Env, asset_id: u64, address: Address) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+    tokenized_asset.tokenizer.require_auth();
+
+    let mut blacklist = get_blacklist(env, asset_id);
+    if let Some(index) = blacklist.first_index_of(&address) {
+        blacklist.remove(index);
+        store.set(&TokenDataKey::Blacklist(asset_id), &blacklist);
+
+        // Emit event: (asset_id, address)
+        env.events()
+            .publish(("transfer", "blacklist_removed"), (asset_id, address));
+    }
+
+    Ok(())
+}
+
+/// Check if an address is blacklisted
+pub fn is_blacklisted(env: &Env, asset_id: u64, address: Address) -> bool {
+    get_blacklist(env, asset_id).contains(&address)
+}
+
+/// Get blacklist for an asset
+pub fn get_blacklist(env: &Env, asset_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::Blacklist(asset_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Freeze a holder so they can neither send nor receive the asset's tokens
+/// (only tokenizer can call)
+pub fn freeze_holder(
+    env: &Env,
+    asset_id: u64,
+    holder: Address,
+    caller: Address,
+) -> Result<(), Error> {
+    tokenization::require_tokenizer(env, asset_id, &caller)?;
+
+    env.storage()
+        .persistent()
+        .set(&TokenDataKey::HolderFrozen(asset_id, holder.clone()), &true);
+
+    // Emit event: (asset_id, holder)
+    env.events()
+        .publish(("transfer", "holder_frozen"), (asset_id, holder));
+
+    Ok(())
+}
+
+/// Lift a holder freeze (only tokenizer can call)
+pub fn unfreeze_holder(
+    env: &Env,
+    asset_id: u64,
+    holder: Address,
+    caller: Address,
+) -> Result<(), Error> {
+    tokenization::require_tokenizer(env, asset_id, &caller)?;
+
+    env.storage()
+        .persistent()
+        .remove(&TokenDataKey::HolderFrozen(asset_id, holder.clone()));
+
+    // Emit event: (asset_id, holder)
+    env.events()
+        .publish(("transfer", "holder_unfrozen"), (asset_id, holder));
+
+    Ok(())
+}
+
+/// Check if a holder is frozen
+pub fn is_holder_frozen(env: &Env, asset_id: u64, holder: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&TokenDataKey::HolderFrozen(asset_id, holder.clone()))
+}
+
+/// Record a pending request from a prospective holder to be whitelisted
+pub fn request_whitelist(env: &Env, asset_id: u64, requester: Address) -> Result<(), Error> {
+    let store = env.storage().persistent();
+
+    // Verify asset is tokenized
+    let _: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    // Nothing to request if already whitelisted or pending
+    if is_whitelisted(env, asset_id, requester.clone())? {
+        return Ok(());
+    }
+    let key = TokenDataKey::WhitelistRequests(asset_id);
+    let mut requests: Vec<Address> = store.get(&key).unwrap_or_else(|| Vec::new(env));
+    if requests.contains(&requester) {
+        return Ok(());
+    }
+
+    requests.push_back(requester.clone());
+    store.set(&key, &requests);
+
+    // Emit event: (asset_id, requester)
+    env.events()
+        .publish(("transfer", "whitelist_requested"), (asset_id, requester));
+
+    Ok(())
+}
+
+/// Approve a pending whitelist request, moving the requester into the whitelist
+/// (only tokenizer can call)
+pub fn approve_whitelist_request(
+    env: &Env,
+    asset_id: u64,
+    requester: Address,
+    caller: Address,
+) -> Result<(), Error> {
+    tokenization::require_tokenizer(env, asset_id, &caller)?;
+    take_whitelist_request(env, asset_id, &requester)?;
+    add_to_whitelist(env, asset_id, requester)
+}
+
+/// Reject a pending whitelist request (only tokenizer can call)
+pub fn reject_whitelist_request(
+    env: &Env,
+    asset_id: u64,
+    requester: Address,
+    caller: Address,
+) -> Result<(), Error> {
+    tokenization::require_tokenizer(env, asset_id, &caller)?;
+    take_whitelist_request(env, asset_id, &requester)?;
+
+    // Emit event: (asset_id, requester)
+    env.events()
+        .publish(("transfer", "whitelist_rejected"), (asset_id, requester));
+
+    Ok(())
+}
+
+/// Get addresses with pending whitelist requests, oldest first
+pub fn get_pending_whitelist_requests(env: &Env, asset_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::WhitelistRequests(asset_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Remove a requester from the pending list
+fn take_whitelist_request(env: &Env, asset_id: u64, requester: &Address) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let key = TokenDataKey::WhitelistRequests(asset_id);
+    let mut requests: Vec<Address> = store.get(&key).unwrap_or_else(|| Vec::new(env));
+
+    let index = requests
+        .first_index_of(requester)
+        .ok_or(Error::WhitelistRequestNotFound)?;
+    requests.remove(index);
+    store.set(&key, &requests);
+    Ok(())
+}
+
 /// Validate if a transfer is allowed based on restrictions
 pub fn validate_transfer(
     env: &Env,
     asset_id: u64,
-    _from: Address,
+    from: Address,
     to: Address,
 ) -> Result<bool, Error> {
     let store = env.storage().persistent();
 
+    // Blacklisted addresses can neither send nor receive
+    let blacklist = get_blacklist(env, asset_id);
+    if blacklist.contains(&from) || blacklist.contains(&to) {
+        return Err(Error::AddressBlacklisted);
+    }
+
+    // Frozen holders can neither send nor receive
+    if is_holder_frozen(env, asset_id, &from) || is_holder_frozen(env, asset_id, &to) {
+        return Err(Error::HolderFrozen);
+    }
+
     // Check whitelist: if non-empty, `to` must be whitelisted
     let whitelist_key = TokenDataKey::Whitelist(asset_id);
     let whitelist: Vec<Address> = store
@@ -120,13 +320,69 @@ pub fn validate_transfer(
     if restriction.require_accredited {
         let is_listed = whitelist.iter().any(|a| a == to);
         if !is_listed {
-            return Err(Error::AccreditedInvestorRequired);
+            return Err(Error::TransferRestrictionFailed);
         }
     }
 
     Ok(true)
 }
 
+/// Explain whether a prospective transfer would be allowed, without mutating state.
+/// Mirrors the checks `transfer_tokens` performs after the contract pause check,
+/// in the same order.
+pub fn transfer_restriction_reason(
+    env: &Env,
+    asset_id: u64,
+    from: Address,
+    to: Address,
+    amount: i128,
+) -> Result<TransferDecision, Error> {
+    let store = env.storage().persistent();
+
+    // Verify asset is tokenized
+    let key = TokenDataKey::TokenizedAsset(asset_id);
+    let _: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
+
+    match validate_transfer(env, asset_id, from.clone(), to.clone()) {
+        Ok(_) => {}
+        Err(Error::HolderFrozen) => return Ok(TransferDecision::Frozen),
+        Err(_) => return Ok(TransferDecision::Restricted),
+    }
+
+    if amount <= 0 {
+        return Ok(TransferDecision::InsufficientBalance);
+    }
+
+    if tokenization::check_exit_only(env, asset_id, &to).is_err()
+        || tokenization::check_not_burn_address(env, asset_id, &from).is_err()
+    {
+        return Ok(TransferDecision::Restricted);
+    }
+
+    if dividends::is_distribution_frozen(env, asset_id) {
+        return Ok(TransferDecision::Cooldown);
+    }
+
+    if tokenization::check_unlocked(env, asset_id, &from, amount).is_err() {
+        return Ok(TransferDecision::Locked);
+    }
+
+    if tokenization::charge_transfer_window(env, asset_id, &from, amount).is_err() {
+        return Ok(TransferDecision::VelocityExceeded);
+    }
+
+    let balance = tokenization::get_token_balance(env, asset_id, from.clone())?;
+    if balance < amount {
+        return Ok(TransferDecision::InsufficientBalance);
+    }
+
+    if tokenization::would_exceed_max_holders(env, asset_id, &from, &to, amount)? {
+        return Ok(TransferDecision::LimitViolation);
+    }
+
+    Ok(TransferDecision::Allowed)
+}
+
 /// Check if transfer restrictions are enabled for an asset
 #[allow(dead_code)]
 pub fn has_transfer_restrictions(env: &Env, asset_id: u64) -> Result<bool, Error> {
@@ -1,5 +1,6 @@
 #![allow(clippy::upper_case_acronyms)]
-use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+use crate::asset::Asset;
+use soroban_sdk::{contracttype, Address, BytesN, Map, String, Vec};
 
 /// Represents the fundamental type of asset being managed
 /// Distinguishes between physical and digital assets for different handling requirements
@@ -18,6 +19,10 @@ pub enum AssetStatus {
     Active,
     Transferred,
     Retired,
+    /// Ownership is represented by fractional tokens
+    Tokenized,
+    /// Temporarily withdrawn from use; can be reactivated
+    Suspended,
 }
 
 /// Represents different types of actions that can be performed on assets
@@ -103,6 +108,84 @@ pub enum TokenDataKey {
     DetokenizationProposal(u64),
     /// Stores TokenMetadata for asset_id
     TokenMetadata(u64),
+    /// Stores MintRateLimit for asset_id
+    MintRateLimit(u64),
+    /// Stores number of dividend epochs opened (u32) for asset_id
+    DividendEpochCount(u64),
+    /// Stores DividendEpoch for (asset_id, epoch)
+    DividendEpoch(u64, u32),
+    /// Stores appraiser Address for asset_id
+    Appraiser(u64),
+    /// Stores lock authority Address for asset_id
+    LockAuthority(u64),
+    /// Stores Vec<Address> of treasury addresses for asset_id
+    Treasury(u64),
+    /// Stores exit-only flag (bool) for asset_id
+    ExitOnly(u64),
+    /// Stores pending DividendDeclaration for asset_id
+    DividendDeclaration(u64),
+    /// Stores Map<u32, i128> of unclaimed epoch dividends for (asset_id, holder_address)
+    EpochUnclaimed(u64, Address),
+    /// Stores global symbol uniqueness flag (bool)
+    EnforceUniqueSymbols,
+    /// Stores asset_id (u64) that holds a token symbol
+    SymbolIndex(String),
+    /// Stores minimum token balance (i128) required to vote for asset_id
+    MinVotingBalance(u64),
+    /// Stores the tokenizer Address of a detokenized asset_id
+    FormerTokenizer(u64),
+    /// Stores running total value locked (i128) across all assets and pools
+    TotalValueLocked,
+    /// Stores Vec<Address> of pending whitelist requests for asset_id
+    WhitelistRequests(u64),
+    /// Stores reflection tax rate (u32, basis points) for asset_id
+    ReflectionTax(u64),
+    /// Stores i128 tokens withheld as reflection tax for asset_id
+    ReflectionPool(u64),
+    /// Stores Vec<(u64, i128)> of (timestamp, valuation) updates for asset_id
+    ValuationHistory(u64),
+    /// Stores Vec<Address> of burn addresses for asset_id; the last is the active one
+    BurnAddresses(u64),
+    /// Stores total tokens burned (i128) for asset_id
+    BurnedTotal(u64),
+    /// Stores minting paused flag (bool) for asset_id
+    MintingPaused(u64),
+    /// Stores number of completed detokenizations (u32) for asset_id; scopes allowances
+    TokenizationRound(u64),
+    /// Stores holder-imposed SelfLock for (asset_id, holder_address)
+    SelfLock(u64, Address),
+    /// Stores allowance (i128) for (asset_id, tokenization round, owner_address, spender_address)
+    Allowance(u64, u32, Address, Address),
+    /// Stores voting weight (u32, basis points) of self-locked tokens for asset_id
+    LockVoteMultiplier(u64),
+    /// Stores number of balance snapshots taken (u64) for asset_id
+    SnapshotCount(u64),
+    /// Stores Map<Address, i128> of holder balances for (asset_id, snapshot_id)
+    Snapshot(u64, u64),
+    /// Stores VestingSchedule for (asset_id, holder_address)
+    Vesting(u64, Address),
+    /// Stores number of governance proposals created (u64) for asset_id
+    ProposalCount(u64),
+    /// Stores GovernanceProposal for (asset_id, proposal_id)
+    GovernanceProposal(u64, u64),
+    /// Stores VoteReceipt for (asset_id, governance proposal_id, voter_address)
+    VoteReceipt(u64, u64, Address),
+    /// Stores governance quorum in basis points of tokens in circulation (u32) for asset_id
+    ProposalQuorum(u64),
+    /// Stores the delegatee Address that (asset_id, delegator_address) votes through
+    VoteDelegation(u64, Address),
+    /// Stores Vec<Address> of holders delegating to (asset_id, delegatee_address)
+    Delegators(u64, Address),
+    /// Stores Vec<Address> blacklist for asset_id
+    Blacklist(u64),
+    /// Stores TransferLimit for (asset_id, holder_address)
+    TransferLimit(u64, Address),
+    /// Stores true while a holder's tokens are frozen for (asset_id, holder_address)
+    HolderFrozen(u64, Address),
+    /// Stores the registry AssetStatus an asset had before it was tokenized for asset_id
+    PreTokenizationStatus(u64),
+    /// Stores the balance snapshot id (u64) that votes on (asset_id, proposal_id) are weighted by
+    VoteSnapshot(u64, u64),
 }
 
 /// Represents a tokenized asset on-chain
@@ -135,6 +218,147 @@ pub struct TokenizedAsset {
     pub tokenization_timestamp: u64,
     /// Percentage required for detokenization (e.g. 50 = 50%)
     pub detokenize_threshold: u32,
+    /// Cap on total supply enforced by minting (None = unlimited)
+    pub max_supply: Option<i128>,
+    /// Cap on the number of distinct holders (None = unlimited)
+    pub max_holders: Option<u32>,
+}
+
+/// Privileged role holders for a tokenized asset
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetRoles {
+    pub tokenizer: Address,
+    pub appraiser: Option<Address>,
+    /// May lock holders' tokens in addition to the tokenizer
+    pub lock_authority: Option<Address>,
+    pub treasury: Vec<Address>,
+}
+
+/// A holder's ownership record together with any lock, freeze, vesting schedule and
+/// active self-lock on their tokens; a missing schedule or self-lock exports as zeroes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HolderExport {
+    pub record: OwnershipRecord,
+    pub locked_until: Option<u64>,
+    pub frozen: bool,
+    pub vesting: VestingSchedule,
+    pub self_lock: SelfLock,
+}
+
+/// Registry record of a tokenized asset, if one was registered under the same ID
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum RegistryRecord {
+    Unregistered,
+    Registered(Asset),
+}
+
+/// Snapshot of an asset's registry, token and configuration state for backups.
+/// Unset transfer restrictions export as the unrestricted default, and an unset
+/// mint rate limit as all zeroes.
+/// `holders` holds at most the first `MAX_EXPORT_HOLDERS` holders; page through
+/// the rest with `export_asset_holders` when `holder_count` is larger.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetStateExport {
+    pub asset: RegistryRecord,
+    pub tokenized_asset: TokenizedAsset,
+    pub metadata: TokenMetadata,
+    pub holder_count: u32,
+    pub holders: Vec<HolderExport>,
+    pub roles: AssetRoles,
+    pub whitelist: Vec<Address>,
+    pub transfer_restriction: TransferRestriction,
+    pub exit_only: bool,
+    pub mint_rate_limit: MintRateLimit,
+    pub min_voting_balance: i128,
+    pub blacklist: Vec<Address>,
+    pub burn_addresses: Vec<Address>,
+    pub burned_total: i128,
+    pub reflection_tax_bps: u32,
+    pub reflection_pool: i128,
+    pub minting_paused: bool,
+}
+
+/// Linear release of a holder's tokens: nothing before `start + cliff`, then
+/// proportionally until fully released at `start + duration`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    /// Tokens covered by the schedule
+    pub total_amount: i128,
+    /// Timestamp vesting is measured from
+    pub start: u64,
+    /// Seconds after `start` before anything vests
+    pub cliff: u64,
+    /// Seconds after `start` until everything has vested
+    pub duration: u64,
+}
+
+/// Tokens a holder has committed to keep until a timestamp; cannot be lifted early
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SelfLock {
+    /// Tokens that must stay in the holder's balance
+    pub amount: i128,
+    /// Timestamp the commitment ends
+    pub until: u64,
+}
+
+/// Tokenizer-committed cap on minting within a time window
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintRateLimit {
+    /// Maximum tokens that may be minted per window
+    pub max_per_window: i128,
+    /// Window length in seconds
+    pub window_secs: u64,
+    /// Timestamp the current window started
+    pub window_start: u64,
+    /// Tokens minted so far in the current window
+    pub minted_in_window: i128,
+}
+
+/// Per-holder cap on tokens transferred out per time window
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferLimit {
+    /// Maximum tokens the holder may transfer per window
+    pub max_per_window: i128,
+    /// Window length in seconds
+    pub window_secs: u64,
+    /// Timestamp the current window started
+    pub window_start: u64,
+    /// Tokens transferred so far in the current window
+    pub transferred_in_window: i128,
+}
+
+/// A declared dividend awaiting finalization; transfers are frozen until `freeze_until`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DividendDeclaration {
+    pub declarer: Address,
+    pub total_amount: i128,
+    pub declared_at: u64,
+    pub freeze_until: u64,
+}
+
+/// A dividend epoch distributed against a balance snapshot taken when it opened
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DividendEpoch {
+    pub epoch: u32,
+    pub opened_at: u64,
+    /// Holder balances at the time the epoch opened
+    pub snapshot: Map<Address, i128>,
+    /// Sum of snapshot balances
+    pub snapshot_supply: i128,
+    /// Amount funded for distribution
+    pub funded: i128,
+    pub closed: bool,
 }
 
 /// Metadata associated with a tokenized asset
@@ -187,6 +411,30 @@ pub struct TransferRestriction {
     pub geographic_allowed: Vec<String>,
 }
 
+/// Outcome of a transfer dry-run, explaining why a transfer would be rejected
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TransferDecision {
+    /// Transfer would succeed
+    Allowed,
+    /// A blacklist, whitelist, accredited-investor, exit-only or burn-address rule blocks it
+    Restricted,
+    /// Sender or recipient is frozen
+    Frozen,
+    /// The contract is paused
+    Paused,
+    /// Sender's tokens are locked
+    Locked,
+    /// Transfers are held while a dividend declaration is open
+    Cooldown,
+    /// The amount exceeds what is left of the sender's transfer window
+    VelocityExceeded,
+    /// The recipient would push the asset past its holder cap
+    LimitViolation,
+    /// Sender's balance does not cover the amount
+    InsufficientBalance,
+}
+
 // =====================
 // DetokenizationProposal — Option B: wrapper structs preserve named fields
 // while satisfying #[contracttype]'s restriction on enum variant fields.
@@ -217,6 +465,34 @@ pub struct RejectedProposal {
     pub rejected_at: u64,
 }
 
+/// General governance proposal voted on by token holders
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceProposal {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub description: String,
+    pub created_at: u64,
+    /// Votes are accepted up to and including this timestamp
+    pub voting_deadline: u64,
+    pub votes_for: i128,
+    pub votes_against: i128,
+    /// Balance snapshot taken at creation that votes are weighted by
+    pub snapshot_id: u64,
+}
+
+/// A holder's recorded vote on a proposal. The weight is fixed when the vote is
+/// cast, so tokens moved afterwards cannot be counted twice.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteReceipt {
+    pub support: bool,
+    pub weight: i128,
+    pub cast_at: u64,
+    /// Delegators whose power was cast with this vote
+    pub delegators: Vec<Address>,
+}
+
 /// Detokenization proposal — each variant wraps its own named struct
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1,21 +1,51 @@
 use crate::error::Error;
-use crate::types::{OwnershipRecord, TokenDataKey, TokenizedAsset};
-use soroban_sdk::{Address, Env, Vec};
-
-/// Cast a vote on a proposal
-pub fn cast_vote(env: &Env, asset_id: u64, proposal_id: u64, voter: Address) -> Result<(), Error> {
+use crate::tokenization;
+use crate::types::{GovernanceProposal, TokenDataKey, TokenizedAsset, VoteReceipt};
+use soroban_sdk::{Address, Env, Map, String, Vec};
+
+/// Default governance quorum: half of the tokens in circulation must vote
+const DEFAULT_PROPOSAL_QUORUM_BPS: u32 = 5000;
+
+/// Cast a vote on a detokenization or ad-hoc proposal. Weight comes from the
+/// balance snapshot taken when the proposal was created; ad-hoc ids with no
+/// creation step are snapshotted on their first vote. Only supporting votes add
+/// weight to the tally; an opposing vote still uses up the voter's (and their
+/// delegators') vote. Each holder votes once and receives a receipt.
+pub fn cast_vote(
+    env: &Env,
+    asset_id: u64,
+    proposal_id: u64,
+    voter: Address,
+    support: bool,
+) -> Result<(), Error> {
     let store = env.storage().persistent();
 
     // Get tokenized asset
     let key = TokenDataKey::TokenizedAsset(asset_id);
     let tokenized_asset: TokenizedAsset = store.get(&key).ok_or(Error::AssetNotTokenized)?;
 
-    // Get voter's balance
-    let holder_key = TokenDataKey::TokenHolder(asset_id, voter.clone());
-    let ownership: OwnershipRecord = store.get(&holder_key).ok_or(Error::HolderNotFound)?;
+    // Holders who delegated vote through their delegatee
+    if store.has(&TokenDataKey::VoteDelegation(asset_id, voter.clone())) {
+        return Err(Error::VotesDelegated);
+    }
+
+    // Get voter's balance at the proposal snapshot
+    let snapshot_id = match store.get(&TokenDataKey::VoteSnapshot(asset_id, proposal_id)) {
+        Some(snapshot_id) => snapshot_id,
+        None => snapshot_proposal(env, asset_id, proposal_id)?,
+    };
+    let balance = tokenization::get_snapshot_balance(env, asset_id, snapshot_id, voter.clone());
+    if balance == 0 && !store.has(&TokenDataKey::TokenHolder(asset_id, voter.clone())) {
+        return Err(Error::HolderNotFound);
+    }
 
     // Check if voter has sufficient voting power
-    if ownership.balance < tokenized_asset.min_voting_threshold {
+    if balance < tokenized_asset.min_voting_threshold {
+        return Err(Error::InsufficientVotingPower);
+    }
+
+    // Dust holders below the minimum voting balance cannot vote
+    if balance < get_min_voting_balance(env, asset_id) {
         return Err(Error::InsufficientVotingPower);
     }
 
@@ -25,25 +55,301 @@ pub fn cast_vote(env: &Env, asset_id: u64, proposal_id: u64, voter: Address) ->
         return Err(Error::AlreadyVoted);
     }
 
-    // Record vote
-    store.set(&vote_key, &true);
+    // Record vote, including for delegators who have not voted themselves
+    let now = env.ledger().timestamp();
+    let mut weight = snapshot_voting_power(env, asset_id, snapshot_id, voter.clone())?;
+    let mut counted = Vec::new(env);
+    for delegator in get_delegators(env, asset_id, &voter).iter() {
+        let delegator_key = TokenDataKey::VoteRecord(asset_id, proposal_id, delegator.clone());
+        if store.has(&delegator_key) {
+            continue;
+        }
+        store.set(&delegator_key, &delegated_receipt(env, support, now));
+        weight = weight
+            .checked_add(snapshot_voting_power(
+                env,
+                asset_id,
+                snapshot_id,
+                delegator.clone(),
+            )?)
+            .ok_or(Error::ArithmeticOverflow)?;
+        counted.push_back(delegator);
+    }
+    store.set(
+        &vote_key,
+        &VoteReceipt {
+            support,
+            weight,
+            cast_at: now,
+            delegators: counted,
+        },
+    );
+
+    if !support {
+        env.events().publish(
+            ("voting", "vote_against"),
+            (asset_id, proposal_id, voter, weight),
+        );
+        return Ok(());
+    }
 
     // Update vote tally
     let tally_key = TokenDataKey::VoteTally(asset_id, proposal_id);
     let current_tally: i128 = store.get::<_, i128>(&tally_key).unwrap_or(0);
 
-    let new_tally = current_tally + ownership.balance;
+    let new_tally = current_tally
+        .checked_add(weight)
+        .ok_or(Error::ArithmeticOverflow)?;
     store.set(&tally_key, &new_tally);
 
     // Emit event: (asset_id, proposal_id, voter, weight)
     env.events().publish(
         ("voting", "vote_cast"),
-        (asset_id, proposal_id, voter, ownership.balance),
+        (asset_id, proposal_id, voter, weight),
+    );
+
+    Ok(())
+}
+
+/// Snapshot holder balances for a `cast_vote` proposal, replacing any earlier
+/// snapshot under the same id. Returns the snapshot id.
+pub(crate) fn snapshot_proposal(env: &Env, asset_id: u64, proposal_id: u64) -> Result<u64, Error> {
+    let snapshot_id = tokenization::take_balance_snapshot(env, asset_id)?;
+    env.storage().persistent().set(
+        &TokenDataKey::VoteSnapshot(asset_id, proposal_id),
+        &snapshot_id,
+    );
+    Ok(snapshot_id)
+}
+
+/// Receipt for a delegator whose power was cast by their delegatee; the weight
+/// is carried on the delegatee's receipt
+fn delegated_receipt(env: &Env, support: bool, cast_at: u64) -> VoteReceipt {
+    VoteReceipt {
+        support,
+        weight: 0,
+        cast_at,
+        delegators: Vec::new(env),
+    }
+}
+
+/// Set the minimum token balance a holder needs to vote (only tokenizer can call)
+pub fn set_min_voting_balance(
+    env: &Env,
+    asset_id: u64,
+    min_balance: i128,
+    caller: Address,
+) -> Result<(), Error> {
+    if min_balance < 0 {
+        return Err(Error::InvalidAmount);
+    }
+    tokenization::require_tokenizer(env, asset_id, &caller)?;
+
+    env.storage()
+        .persistent()
+        .set(&TokenDataKey::MinVotingBalance(asset_id), &min_balance);
+
+    env.events()
+        .publish(("voting", "min_balance_set"), (asset_id, min_balance));
+
+    Ok(())
+}
+
+/// Get the minimum token balance required to vote (0 when unset)
+pub fn get_min_voting_balance(env: &Env, asset_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::MinVotingBalance(asset_id))
+        .unwrap_or(0)
+}
+
+/// Set the voting weight of self-locked tokens in basis points, where 10000 counts
+/// them like any other token and 15000 gives 1.5x (only tokenizer can call)
+pub fn set_lock_vote_multiplier(
+    env: &Env,
+    asset_id: u64,
+    multiplier_bps: u32,
+    caller: Address,
+) -> Result<(), Error> {
+    if multiplier_bps < 10000 {
+        return Err(Error::InvalidAmount);
+    }
+    tokenization::require_tokenizer(env, asset_id, &caller)?;
+
+    env.storage()
+        .persistent()
+        .set(&TokenDataKey::LockVoteMultiplier(asset_id), &multiplier_bps);
+
+    env.events().publish(
+        ("voting", "lock_multiplier_set"),
+        (asset_id, multiplier_bps),
     );
 
     Ok(())
 }
 
+/// Get the voting weight of self-locked tokens in basis points (10000 when unset)
+pub fn get_lock_vote_multiplier(env: &Env, asset_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::LockVoteMultiplier(asset_id))
+        .unwrap_or(10000)
+}
+
+/// Voting power of a holder: their balance, with tokens under an active self-lock
+/// weighted by the lock multiplier. The bonus ends when the lock expires.
+pub fn effective_voting_power(env: &Env, asset_id: u64, holder: Address) -> Result<i128, Error> {
+    let balance = tokenization::get_token_balance(env, asset_id, holder.clone())?;
+    weighted_power(env, asset_id, holder, balance)
+}
+
+/// Voting power of a holder as of a balance snapshot; the self-lock bonus uses
+/// the lock in force now, capped at the snapshot balance
+fn snapshot_voting_power(
+    env: &Env,
+    asset_id: u64,
+    snapshot_id: u64,
+    holder: Address,
+) -> Result<i128, Error> {
+    let balance = tokenization::get_snapshot_balance(env, asset_id, snapshot_id, holder.clone());
+    weighted_power(env, asset_id, holder, balance)
+}
+
+fn weighted_power(env: &Env, asset_id: u64, holder: Address, balance: i128) -> Result<i128, Error> {
+    // Unvested tokens are locked anyway, so only the self-lock's share of the
+    // vested balance earns the bonus
+    let locked = match tokenization::get_self_lock(env, asset_id, holder.clone()) {
+        Some(lock) => {
+            let unvested = tokenization::unvested_amount(env, asset_id, holder)?;
+            lock.amount.min(balance - unvested).max(0)
+        }
+        None => 0,
+    };
+    let bonus_bps = get_lock_vote_multiplier(env, asset_id) as i128 - 10000;
+    let bonus = locked
+        .checked_mul(bonus_bps)
+        .ok_or(Error::ArithmeticOverflow)?
+        / 10000;
+    balance.checked_add(bonus).ok_or(Error::ArithmeticOverflow)
+}
+
+/// Delegate the holder's voting power to `delegatee`. Replaces any existing
+/// delegation; the delegator cannot vote directly until it is revoked.
+pub fn delegate_votes(
+    env: &Env,
+    asset_id: u64,
+    delegator: Address,
+    delegatee: Address,
+) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    if !store.has(&TokenDataKey::TokenizedAsset(asset_id)) {
+        return Err(Error::AssetNotTokenized);
+    }
+    if !store.has(&TokenDataKey::TokenHolder(asset_id, delegator.clone())) {
+        return Err(Error::HolderNotFound);
+    }
+    // Delegation is one level deep: a delegatee must vote for themselves, and
+    // a holder others delegate to cannot pass that power on
+    if delegator == delegatee
+        || store.has(&TokenDataKey::VoteDelegation(asset_id, delegatee.clone()))
+        || !get_delegators(env, asset_id, &delegator).is_empty()
+    {
+        return Err(Error::Unauthorized);
+    }
+
+    if let Some(previous) = get_delegate(env, asset_id, delegator.clone()) {
+        remove_delegator(env, asset_id, &previous, &delegator);
+    }
+
+    let mut delegators = get_delegators(env, asset_id, &delegatee);
+    delegators.push_back(delegator.clone());
+    store.set(
+        &TokenDataKey::Delegators(asset_id, delegatee.clone()),
+        &delegators,
+    );
+    store.set(
+        &TokenDataKey::VoteDelegation(asset_id, delegator.clone()),
+        &delegatee,
+    );
+
+    env.events()
+        .publish(("voting", "delegated"), (asset_id, delegator, delegatee));
+
+    Ok(())
+}
+
+/// Revoke the holder's delegation so they can vote directly again
+pub fn revoke_delegation(env: &Env, asset_id: u64, delegator: Address) -> Result<(), Error> {
+    let delegatee = get_delegate(env, asset_id, delegator.clone()).ok_or(Error::HolderNotFound)?;
+
+    remove_delegator(env, asset_id, &delegatee, &delegator);
+    env.storage()
+        .persistent()
+        .remove(&TokenDataKey::VoteDelegation(asset_id, delegator.clone()));
+
+    env.events().publish(
+        ("voting", "delegation_revoked"),
+        (asset_id, delegator, delegatee),
+    );
+
+    Ok(())
+}
+
+/// Get the address a holder has delegated their votes to, if any
+pub fn get_delegate(env: &Env, asset_id: u64, delegator: Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::VoteDelegation(asset_id, delegator))
+}
+
+/// Voting power a delegatee would cast: their own plus that of every delegator
+pub fn total_voting_power(env: &Env, asset_id: u64, voter: Address) -> Result<i128, Error> {
+    let mut power = effective_voting_power(env, asset_id, voter.clone())?;
+    for delegator in get_delegators(env, asset_id, &voter).iter() {
+        power = power
+            .checked_add(effective_voting_power(env, asset_id, delegator)?)
+            .ok_or(Error::ArithmeticOverflow)?;
+    }
+    Ok(power)
+}
+
+/// Drop every delegation the holder is party to, as delegator or delegatee
+pub(crate) fn clear_delegations(env: &Env, asset_id: u64, holder: &Address) {
+    let store = env.storage().persistent();
+    if let Some(delegatee) = get_delegate(env, asset_id, holder.clone()) {
+        clear_delegators(env, asset_id, &delegatee);
+    }
+    clear_delegators(env, asset_id, holder);
+    store.remove(&TokenDataKey::VoteDelegation(asset_id, holder.clone()));
+}
+
+/// Remove the delegatee's delegator list along with each delegator's delegation
+fn clear_delegators(env: &Env, asset_id: u64, delegatee: &Address) {
+    let store = env.storage().persistent();
+    for delegator in get_delegators(env, asset_id, delegatee).iter() {
+        store.remove(&TokenDataKey::VoteDelegation(asset_id, delegator));
+    }
+    store.remove(&TokenDataKey::Delegators(asset_id, delegatee.clone()));
+}
+
+fn get_delegators(env: &Env, asset_id: u64, delegatee: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::Delegators(asset_id, delegatee.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn remove_delegator(env: &Env, asset_id: u64, delegatee: &Address, delegator: &Address) {
+    let mut delegators = get_delegators(env, asset_id, delegatee);
+    if let Some(index) = delegators.first_index_of(delegator) {
+        delegators.remove(index);
+    }
+    env.storage().persistent().set(
+        &TokenDataKey::Delegators(asset_id, delegatee.clone()),
+        &delegators,
+    );
+}
+
 /// Get vote tally for a proposal
 pub fn get_vote_tally(env: &Env, asset_id: u64, proposal_id: u64) -> Result<i128, Error> {
     let store = env.storage().persistent();
@@ -75,7 +381,8 @@ pub fn has_voted(
     Ok(store.has(&vote_key))
 }
 
-/// Check if a proposal passed (vote tally > 50% of total supply)
+/// Check if a proposal passed: the vote tally must reach `detokenize_threshold`
+/// percent of the tokens in circulation
 pub fn proposal_passed(env: &Env, asset_id: u64, proposal_id: u64) -> Result<bool, Error> {
     let store = env.storage().persistent();
 
@@ -88,10 +395,13 @@ pub fn proposal_passed(env: &Env, asset_id: u64, proposal_id: u64) -> Result<boo
     let tally: i128 = store.get::<_, i128>(&tally_key).unwrap_or(0);
 
     // Calculate required threshold
-    let threshold =
-        (tokenized_asset.total_supply * tokenized_asset.detokenize_threshold as i128) / 100;
+    let threshold = tokenized_asset
+        .tokens_in_circulation
+        .checked_mul(tokenized_asset.detokenize_threshold as i128)
+        .ok_or(Error::ArithmeticOverflow)?
+        / 100;
 
-    Ok(tally > threshold)
+    Ok(tally > 0 && tally >= threshold)
 }
 
 /// Get list of voters who participated in a proposal
@@ -135,8 +445,22 @@ pub fn clear_proposal_votes(env: &Env, asset_id: u64, proposal_id: u64) -> Resul
     let holders_key = TokenDataKey::TokenHoldersList(asset_id);
     let holders: Vec<Address> = store.get(&holders_key).ok_or(Error::AssetNotTokenized)?;
 
-    // Remove all vote records
-    for holder in holders.iter() {
+    // Remove all vote records, including those of snapshot holders who have
+    // since sold out
+    let snapshot_key = TokenDataKey::VoteSnapshot(asset_id, proposal_id);
+    let mut voters = holders;
+    if let Some(snapshot_id) = store.get::<_, u64>(&snapshot_key) {
+        let snapshot: Map<Address, i128> = store
+            .get(&TokenDataKey::Snapshot(asset_id, snapshot_id))
+            .unwrap_or_else(|| Map::new(env));
+        for holder in snapshot.keys().iter() {
+            if !voters.contains(&holder) {
+                voters.push_back(holder);
+            }
+        }
+        store.remove(&snapshot_key);
+    }
+    for holder in voters.iter() {
         let vote_key = TokenDataKey::VoteRecord(asset_id, proposal_id, holder);
         if store.has(&vote_key) {
             store.remove(&vote_key);
@@ -151,3 +475,284 @@ pub fn clear_proposal_votes(env: &Env, asset_id: u64, proposal_id: u64) -> Resul
 
     Ok(())
 }
+
+/// Create a governance proposal. Only holders at or above the asset's
+/// `min_voting_threshold` may propose; voting stays open until `voting_deadline`.
+pub fn create_proposal(
+    env: &Env,
+    asset_id: u64,
+    proposer: Address,
+    description: String,
+    voting_deadline: u64,
+) -> Result<u64, Error> {
+    let store = env.storage().persistent();
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    let balance = tokenization::get_token_balance(env, asset_id, proposer.clone())?;
+    if balance < tokenized_asset.min_voting_threshold {
+        return Err(Error::InsufficientVotingPower);
+    }
+
+    let now = env.ledger().timestamp();
+    if voting_deadline <= now {
+        return Err(Error::InvalidTimestamps);
+    }
+
+    let count_key = TokenDataKey::ProposalCount(asset_id);
+    let proposal_id: u64 = store.get(&count_key).unwrap_or(0) + 1;
+    store.set(&count_key, &proposal_id);
+    let snapshot_id = tokenization::take_balance_snapshot(env, asset_id)?;
+    store.set(
+        &TokenDataKey::GovernanceProposal(asset_id, proposal_id),
+        &GovernanceProposal {
+            proposal_id,
+            proposer: proposer.clone(),
+            description,
+            created_at: now,
+            voting_deadline,
+            votes_for: 0,
+            votes_against: 0,
+            snapshot_id,
+        },
+    );
+
+    env.events().publish(
+        ("voting", "proposal_created"),
+        (asset_id, proposal_id, proposer, voting_deadline),
+    );
+
+    Ok(proposal_id)
+}
+
+/// Vote for or against a governance proposal, weighted by the voter's
+/// effective voting power at the proposal's creation snapshot. Each holder votes
+/// once, before the deadline; a recorded vote can only be flipped through
+/// `change_vote`.
+pub fn vote_on_proposal(
+    env: &Env,
+    asset_id: u64,
+    proposal_id: u64,
+    voter: Address,
+    support: bool,
+) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let tokenized_asset: TokenizedAsset = store
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+
+    let proposal_key = TokenDataKey::GovernanceProposal(asset_id, proposal_id);
+    let mut proposal: GovernanceProposal =
+        store.get(&proposal_key).ok_or(Error::ProposalNotFound)?;
+
+    let now = env.ledger().timestamp();
+    if now > proposal.voting_deadline {
+        return Err(Error::VotingPeriodEnded);
+    }
+
+    if store.has(&TokenDataKey::VoteDelegation(asset_id, voter.clone())) {
+        return Err(Error::VotesDelegated);
+    }
+    let receipt_key = TokenDataKey::VoteReceipt(asset_id, proposal_id, voter.clone());
+    if store.has(&receipt_key) {
+        return Err(Error::AlreadyVoted);
+    }
+
+    let snapshot_id = proposal.snapshot_id;
+    let balance = tokenization::get_snapshot_balance(env, asset_id, snapshot_id, voter.clone());
+    if balance < tokenized_asset.min_voting_threshold {
+        return Err(Error::InsufficientVotingPower);
+    }
+    if balance < get_min_voting_balance(env, asset_id) {
+        return Err(Error::InsufficientVotingPower);
+    }
+
+    // Delegators who have not voted are counted here and receive a receipt
+    // so their power cannot be cast again after revoking the delegation
+    let mut weight = snapshot_voting_power(env, asset_id, snapshot_id, voter.clone())?;
+    let mut counted = Vec::new(env);
+    for delegator in get_delegators(env, asset_id, &voter).iter() {
+        let delegator_key = TokenDataKey::VoteReceipt(asset_id, proposal_id, delegator.clone());
+        if store.has(&delegator_key) {
+            continue;
+        }
+        store.set(&delegator_key, &delegated_receipt(env, support, now));
+        weight = weight
+            .checked_add(snapshot_voting_power(
+                env,
+                asset_id,
+                snapshot_id,
+                delegator.clone(),
+            )?)
+            .ok_or(Error::ArithmeticOverflow)?;
+        counted.push_back(delegator);
+    }
+    add_proposal_votes(&mut proposal, support, weight)?;
+
+    store.set(
+        &receipt_key,
+        &VoteReceipt {
+            support,
+            weight,
+            cast_at: now,
+            delegators: counted,
+        },
+    );
+    store.set(&proposal_key, &proposal);
+
+    env.events().publish(
+        ("voting", "proposal_vote"),
+        (asset_id, proposal_id, voter, support, weight),
+    );
+
+    Ok(())
+}
+
+/// Flip a recorded vote on a governance proposal before the deadline. The
+/// original weight moves to the other side; it is not recomputed. Receipts of
+/// delegators counted with the vote follow it.
+pub fn change_vote(
+    env: &Env,
+    asset_id: u64,
+    proposal_id: u64,
+    voter: Address,
+    support: bool,
+) -> Result<(), Error> {
+    let store = env.storage().persistent();
+    let proposal_key = TokenDataKey::GovernanceProposal(asset_id, proposal_id);
+    let mut proposal: GovernanceProposal =
+        store.get(&proposal_key).ok_or(Error::ProposalNotFound)?;
+
+    let now = env.ledger().timestamp();
+    if now > proposal.voting_deadline {
+        return Err(Error::VotingPeriodEnded);
+    }
+
+    let receipt_key = TokenDataKey::VoteReceipt(asset_id, proposal_id, voter.clone());
+    let mut receipt: VoteReceipt = store.get(&receipt_key).ok_or(Error::Unauthorized)?;
+    if receipt.support == support {
+        return Err(Error::AlreadyVoted);
+    }
+
+    add_proposal_votes(&mut proposal, receipt.support, -receipt.weight)?;
+    add_proposal_votes(&mut proposal, support, receipt.weight)?;
+
+    receipt.support = support;
+    receipt.cast_at = now;
+    store.set(&receipt_key, &receipt);
+    for delegator in receipt.delegators.iter() {
+        let delegator_key = TokenDataKey::VoteReceipt(asset_id, proposal_id, delegator);
+        store.set(&delegator_key, &delegated_receipt(env, support, now));
+    }
+    store.set(&proposal_key, &proposal);
+
+    env.events().publish(
+        ("voting", "vote_changed"),
+        (asset_id, proposal_id, voter, support, receipt.weight),
+    );
+
+    Ok(())
+}
+
+/// Get a voter's receipt on a governance proposal, if they have voted
+pub fn get_vote_receipt(
+    env: &Env,
+    asset_id: u64,
+    proposal_id: u64,
+    voter: Address,
+) -> Option<VoteReceipt> {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::VoteReceipt(asset_id, proposal_id, voter))
+}
+
+fn add_proposal_votes(
+    proposal: &mut GovernanceProposal,
+    support: bool,
+    weight: i128,
+) -> Result<(), Error> {
+    let side = if support {
+        &mut proposal.votes_for
+    } else {
+        &mut proposal.votes_against
+    };
+    *side = side.checked_add(weight).ok_or(Error::ArithmeticOverflow)?;
+    Ok(())
+}
+
+/// Get a governance proposal
+pub fn get_governance_proposal(
+    env: &Env,
+    asset_id: u64,
+    proposal_id: u64,
+) -> Result<GovernanceProposal, Error> {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::GovernanceProposal(asset_id, proposal_id))
+        .ok_or(Error::ProposalNotFound)
+}
+
+/// Tally a governance proposal as (votes_for, votes_against)
+pub fn tally_proposal(env: &Env, asset_id: u64, proposal_id: u64) -> Result<(i128, i128), Error> {
+    let proposal = get_governance_proposal(env, asset_id, proposal_id)?;
+    Ok((proposal.votes_for, proposal.votes_against))
+}
+
+/// Set the governance quorum in basis points of tokens in circulation
+/// (only tokenizer can call)
+pub fn set_proposal_quorum(
+    env: &Env,
+    asset_id: u64,
+    quorum_bps: u32,
+    caller: Address,
+) -> Result<(), Error> {
+    if quorum_bps == 0 || quorum_bps > 10000 {
+        return Err(Error::InvalidAmount);
+    }
+    tokenization::require_tokenizer(env, asset_id, &caller)?;
+
+    env.storage()
+        .persistent()
+        .set(&TokenDataKey::ProposalQuorum(asset_id), &quorum_bps);
+
+    env.events()
+        .publish(("voting", "quorum_set"), (asset_id, quorum_bps));
+
+    Ok(())
+}
+
+/// Get the governance quorum in basis points (5000 when unset)
+pub fn get_proposal_quorum(env: &Env, asset_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&TokenDataKey::ProposalQuorum(asset_id))
+        .unwrap_or(DEFAULT_PROPOSAL_QUORUM_BPS)
+}
+
+/// A governance proposal passes when total votes reach the quorum share of
+/// tokens in circulation and votes for outnumber votes against
+pub fn governance_proposal_passed(
+    env: &Env,
+    asset_id: u64,
+    proposal_id: u64,
+) -> Result<bool, Error> {
+    let tokenized_asset: TokenizedAsset = env
+        .storage()
+        .persistent()
+        .get(&TokenDataKey::TokenizedAsset(asset_id))
+        .ok_or(Error::AssetNotTokenized)?;
+    let proposal = get_governance_proposal(env, asset_id, proposal_id)?;
+
+    let total_votes = proposal
+        .votes_for
+        .checked_add(proposal.votes_against)
+        .ok_or(Error::ArithmeticOverflow)?;
+    let quorum = tokenized_asset
+        .tokens_in_circulation
+        .checked_mul(get_proposal_quorum(env, asset_id) as i128)
+        .ok_or(Error::ArithmeticOverflow)?
+        / 10000;
+
+    Ok(total_votes > 0 && total_votes >= quorum && proposal.votes_for > proposal.votes_against)
+}